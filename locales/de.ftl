@@ -0,0 +1,607 @@
+# German catalog. Keys and variables must match en.ftl exactly.
+
+language-name = Deutsch
+-app = TidaLuna Installer
+unknown-version = unbekannte Version
+
+## Installer steps
+
+step-tidal-not-found = Tidal ist nicht installiert oder der Pfad wurde nicht gefunden
+step-path-missing = Der Tidal-Pfad existiert nicht
+step-path-unresolved = Der Tidal-Pfad konnte nicht ermittelt werden
+step-invalid-path = Ungültiger Tidal-Pfad
+step-tidal-missing = Tidal ist nicht installiert: { $error }
+step-unsupported-os = Nicht unterstütztes Betriebssystem
+
+step-setup = Vorbereitung
+setup-temp-dir-failed = Temporäres Verzeichnis konnte nicht erstellt werden: { $error }
+setup-temp-dir-created = Temporäres Verzeichnis erstellt: { $path }
+setup-checking-tidal = Prüfe, ob Tidal installiert ist
+setup-tidal-path-missing = Der Tidal-Pfad existiert nicht: { $path }
+setup-app-asar-missing = app.asar nicht gefunden — Tidal ist nicht korrekt installiert
+setup-tidal-valid = Tidal ist installiert und gültig
+setup-done = Vorbereitung erfolgreich abgeschlossen
+
+step-copy-asar-install = ASAR für die Installation kopieren
+copy-asar-using-path = Verwende Tidal-Pfad: { $path }
+copy-asar-no-asar = Keine .asar-Dateien gefunden — Tidal ist nicht korrekt installiert
+copy-asar-refreshing-backup = app.asar wurde von TIDAL aktualisiert; ersetze die veraltete Sicherung original.asar
+copy-asar-refresh-failed = Sicherung original.asar konnte nicht erneuert werden: { $error }
+copy-asar-app-asar-missing = app.asar nicht gefunden. Die Tidal-Installation ist möglicherweise beschädigt.
+copy-asar-creating-backup = Erstelle Sicherung original.asar
+copy-asar-backup-failed = app.asar konnte nicht gesichert werden: { $error }
+copy-asar-delete-failed = Vorhandene app.asar konnte nicht gelöscht werden: { $error }
+copy-asar-copied = app.asar erfolgreich nach original.asar kopiert
+copy-asar-done = ASAR-Kopie abgeschlossen
+
+step-restore-asar = Original-ASAR wiederherstellen
+restore-asar-original-not-found = original.asar nicht gefunden, app.asar kann nicht wiederhergestellt werden
+restore-asar-original-missing = original.asar fehlt
+restore-asar-restoring = Stelle ursprüngliche app.asar wieder her...
+restore-asar-remove-existing-failed = Vorhandene app.asar konnte nicht entfernt werden: { $error }
+restore-asar-remove-failed = app.asar konnte nicht entfernt werden: { $error }
+restore-asar-copy-failed = app.asar konnte nicht wiederhergestellt werden: { $error }
+restore-asar-restored = original.asar erfolgreich als app.asar wiederhergestellt
+restore-asar-done = ASAR erfolgreich wiederhergestellt
+
+step-write-receipt = Installationsbeleg schreiben
+receipt-recorded = { $channel } { $version } in { $path } vermerkt
+receipt-write-failed = Warnung: Installationsbeleg konnte nicht geschrieben werden: { $error }
+receipt-record-failed = Warnung: Die Installation konnte für `watch` nicht vermerkt werden: { $error }
+receipt-done = Installationsbeleg geschrieben
+
+step-restore-ownership = Dateibesitz wiederherstellen
+ownership-not-elevated = Nicht über sudo/pkexec gestartet; Besitz bleibt unverändert
+ownership-not-needed = Wiederherstellen des Besitzes nicht nötig
+ownership-path-unresolved = Besitz wird nicht wiederhergestellt: TIDAL-Pfad konnte nicht ermittelt werden ({ $error })
+ownership-skipped = Wiederherstellen des Besitzes übersprungen
+ownership-foreign-path = { $path } gehört nicht '{ $user }'; installierte Dateien bleiben im Besitz von root
+ownership-system-location = Für einen Systempfad muss der Besitz nicht wiederhergestellt werden
+ownership-changed = { $count ->
+        [one] Besitzer von { $count } Pfad unter { $path } auf '{ $user }' geändert
+       *[other] Besitzer von { $count } Pfaden unter { $path } auf '{ $user }' geändert
+    }
+ownership-change-failed = Warnung: Besitzer von { $path } konnte nicht geändert werden: { $error }
+ownership-done = Installierte Dateien an '{ $user }' zurückgegeben
+
+step-rollback-luna = Vorherige Luna-Version wiederherstellen
+rollback-no-previous = Keine vorherige Luna-Installation in { $path } gefunden
+rollback-swapping = Tausche aktuelles Luna ({ $current }) gegen vorheriges Luna ({ $previous })
+rollback-set-aside-failed = Aktuelle Luna-Installation konnte nicht beiseitegelegt werden: { $error }
+rollback-restore-failed = Vorherige Luna-Installation konnte nicht wiederhergestellt werden: { $error }
+rollback-kept-replaced = Ersetzte Installation als neue vorherige Version behalten
+rollback-keep-failed = Warnung: Ersetzte Installation konnte nicht behalten werden: { $error }
+rollback-done = Auf Luna { $version } zurückgesetzt
+
+step-uninstall = TidaLuna deinstallieren
+uninstall-no-tidal = Tidal ist nicht installiert, Deinstallation wird übersprungen
+uninstall-running = Deinstalliere TidaLuna / Neptune...
+uninstall-no-luna = TidaLuna / Neptune ist nicht installiert, Deinstallation wird übersprungen...
+uninstall-nothing = Nichts zu deinstallieren
+uninstall-removed = TidaLuna / Neptune erfolgreich deinstalliert
+uninstall-done = Deinstallation erfolgreich abgeschlossen
+uninstall-error = Fehler beim Deinstallieren von TidaLuna / Neptune: { $error }
+uninstall-failed = Deinstallation fehlgeschlagen: { $error }
+uninstall-keeping-backup = Behalte TidaLuna ({ $version }) als vorherige Version zum Zurücksetzen
+uninstall-backup-failed = Warnung: Sicherung konnte nicht behalten werden ({ $error }); sie wird stattdessen entfernt
+step-wipe-luna-data = Luna-Benutzerdaten löschen
+wipe-nothing-found = Keine Luna-Benutzerdaten gefunden
+wipe-removed = { $path } gelöscht
+wipe-remove-failed = { $path } konnte nicht gelöscht werden: { $error }
+wipe-failed = { $count ->
+        [one] { $count } Eintrag der Luna-Benutzerdaten konnte nicht gelöscht werden
+       *[other] { $count } Einträge der Luna-Benutzerdaten konnten nicht gelöscht werden
+    }
+wipe-done = Luna-Benutzerdaten gelöscht
+
+step-sign-tidal = TIDAL signieren
+sign-not-needed = Unter { $os } muss TIDAL nicht signiert werden, übersprungen...
+sign-skipped = Signieren unter { $os } übersprungen
+sign-running = Signiere TIDAL unter macOS...
+sign-target = codesign-Ziel: { $path }
+sign-error = Fehler beim Signieren von TIDAL unter macOS
+sign-signed = TIDAL unter macOS erfolgreich signiert
+sign-done = Signieren erfolgreich abgeschlossen
+sign-codesign-failed = codesign konnte nicht ausgeführt werden: { $error }
+
+step-reinstall-cleanup = Aufräumen vor Neuinstallation
+cleanup-path-unresolved = Aufräumen übersprungen: TIDAL-Pfad konnte nicht ermittelt werden ({ $error })
+cleanup-skipped = Aufräumen vor Neuinstallation übersprungen
+cleanup-path-missing = Aufräumen übersprungen: Der TIDAL-Pfad existiert nicht
+cleanup-restoring-asar = Versuche, die ursprüngliche app.asar vor der Neuinstallation wiederherzustellen
+cleanup-remove-asar-failed = Warnung: app.asar konnte vor dem Wiederherstellen nicht entfernt werden: { $error }
+cleanup-restore-asar-failed = Warnung: app.asar konnte nicht aus original.asar wiederhergestellt werden: { $error }
+cleanup-no-original = original.asar nicht gefunden, ASAR-Wiederherstellung wird übersprungen
+cleanup-keeping-previous = Behalte vorhandenes TidaLuna ({ $version }) als vorherige Version zum Zurücksetzen
+cleanup-keep-failed = Warnung: Vorherige Version konnte nicht behalten werden ({ $error }); sie wird stattdessen entfernt
+cleanup-remove-app-failed = Warnung: Vorhandenes app-Verzeichnis konnte nicht entfernt werden: { $error }
+cleanup-not-needed = Kein vorhandenes TidaLuna-app-Verzeichnis gefunden; Aufräumen nicht nötig
+cleanup-done = Aufräumen vor Neuinstallation abgeschlossen
+
+step-insert-luna = Luna einfügen
+insert-temp-dir = Verwende temporäres Verzeichnis: { $path }
+insert-temp-dir-missing = Temporäres Luna-Verzeichnis existiert nicht
+insert-copying = Kopiere Luna-Dateien in das Tidal-app-Verzeichnis
+insert-create-dir-failed = Zielverzeichnis konnte nicht erstellt werden: { $error }
+insert-copy-failed = Luna-Dateien konnten nicht kopiert werden: { $error }
+insert-copied = Luna-Dateien erfolgreich kopiert
+insert-done = Luna erfolgreich eingefügt
+
+step-extract-luna = Luna entpacken
+extract-cleaning = Leere vorhandenen Entpackpfad: { $path }
+extract-clean-failed = Entpackverzeichnis konnte nicht geleert werden: { $error }
+extract-ensuring-dir = Stelle sicher, dass der Entpackpfad existiert: { $path }
+extract-create-dir-failed = Entpackverzeichnis konnte nicht erstellt werden: { $error }
+extract-running = Entpacke Luna...
+extract-read-failed = ZIP-Datei konnte vor dem Entpacken nicht gelesen werden: { $error }
+extract-open-validation-failed = ZIP-Datei konnte zur Prüfung nicht geöffnet werden: { $error }
+extract-too-small = ZIP-Datei ist unerwartet klein: { $size } Bytes
+extract-invalid-zip = ZIP-Prüfung vor dem Entpacken fehlgeschlagen: { $error } (Größe: { $size } Bytes)
+extract-open-failed = ZIP-Datei konnte nicht geöffnet werden: { $error }
+extract-archive-failed = ZIP-Archiv konnte nicht gelesen werden: { $error }
+extract-entry-failed = Auf ZIP-Eintrag konnte nicht zugegriffen werden: { $error }
+extract-entry-too-large = ZIP-Eintrag ist zu groß (>{ $limit } Bytes): { $name }
+extract-unsafe-entry = Unsicherer ZIP-Eintragspfad abgelehnt: { $name }
+extract-mkdir-failed = Verzeichnis konnte nicht erstellt werden: { $error }
+extract-parent-dir-failed = Übergeordnetes Verzeichnis konnte nicht erstellt werden: { $error }
+extract-create-file-failed = Datei konnte nicht erstellt werden: { $error }
+extract-write-failed = Datei konnte nicht geschrieben werden: { $error }
+extract-extracted = Luna erfolgreich entpackt
+extract-done = Entpacken abgeschlossen
+
+step-kill-tidal = TIDAL beenden
+kill-detected-os = Erkanntes Betriebssystem: { $os }
+kill-running = Beende TIDAL-Prozess(e) ({ $os })
+kill-stopped-image = Prozessabbild beendet: { $image }
+kill-stopped-pattern = Prozesse mit Muster beendet: { $pattern }
+kill-no-command = Warnung: Auf diesem System konnte kein Befehl zum Beenden ausgeführt werden
+kill-nothing-running = Kein laufender TIDAL-Prozess gefunden
+kill-completed = TIDAL beenden abgeschlossen
+kill-done = TIDAL beenden abgeschlossen (nicht kritisch)
+
+step-download-luna = Luna herunterladen
+zip-missing-signature = ZIP-Signatur fehlt
+zip-missing-central-directory = ZIP-Zentralverzeichnis fehlt
+zip-parse-failed = ZIP-Prüfung fehlgeschlagen: { $error }
+download-client-failed = HTTP-Client konnte nicht erstellt werden: { $error }
+download-request-failed = Download-Anfrage konnte nicht gesendet werden: { $error }
+download-http-status = Luna konnte nicht heruntergeladen werden, HTTP-Status: { $status }
+download-read-failed = Download-Antwort konnte nicht gelesen werden: { $error }
+download-cancelled = Download abgebrochen
+download-empty = Download abgeschlossen, aber die Datei ist leer
+download-invalid-zip = Heruntergeladene Datei hat die ZIP-Prüfung nicht bestanden ({ $error }). URL: { $url }. Antwortvorschau: { $preview }
+download-temp-dir = Verwende temporäres Verzeichnis: { $path }
+download-using-cache = Verwende zwischengespeichertes Archiv { $path }
+download-cache-outdated = Das Release hinter diesem Download hat sich geändert, seit { $path } zwischengespeichert wurde; lade es erneut herunter
+download-cache-unverified = Zwischengespeichertes Archiv konnte nicht mit dem Server abgeglichen werden, verwende es trotzdem
+download-running = Lade Luna herunter...
+download-cached = Archiv zwischengespeichert unter { $path }
+download-cache-failed = Warnung: Archiv konnte nicht zwischengespeichert werden: { $error }
+download-part-write-failed = Luna.zip.part konnte nicht geschrieben werden: { $error }
+download-part-flush-failed = Luna.zip.part konnte nicht geleert werden: { $error }
+download-part-sync-failed = Luna.zip.part konnte nicht synchronisiert werden: { $error }
+download-part-create-failed = Luna.zip.part konnte nicht erstellt werden: { $error }
+download-finalize-failed = Luna.zip konnte nicht fertiggestellt werden: { $error }
+download-metadata-failed = Metadaten von Luna.zip konnten nicht geprüft werden: { $error }
+download-size-mismatch = Größe der geschriebenen Luna.zip stimmt nicht: { $written } Bytes geschrieben, { $expected } erwartet
+download-saved = Luna erfolgreich nach { $path } heruntergeladen ({ $size } Bytes)
+download-done = Download erfolgreich abgeschlossen
+
+step-local-archive = Lokales Archiv verwenden
+local-archive-reading = Lese { $path }
+local-archive-read-failed = Archiv konnte nicht gelesen werden: { $error }
+local-archive-invalid = { $path } ist kein verwendbares Luna-Archiv: { $error }
+local-archive-summary = { $files ->
+        [one] { $files } Datei, { $size } MB entpackt
+       *[other] { $files } Dateien, { $size } MB entpackt
+    }
+local-archive-copied = Archiv nach { $path } kopiert ({ $size } Bytes)
+local-archive-done = Lokales Archiv bereit
+
+step-launch-tidal = TIDAL starten
+launch-command = Befehl '{ $command }'
+launch-launched = TIDAL losgelöst gestartet mit { $candidate }
+launch-failed = Start von { $candidate } fehlgeschlagen: { $error }
+launch-as-user = Läuft als root; starte TIDAL als aufrufender Benutzer '{ $user }'
+launch-root-skipped = Automatischer Start übersprungen: Electron unterstützt unter Linux keinen Start als root (Sandbox-Einschränkung) und es wurde kein sudo/pkexec-Benutzer erkannt. Starte TIDAL selbst als normaler Benutzer.
+launch-root-done = Installation abgeschlossen; automatischer Start von TIDAL für root-Sitzung unter Linux übersprungen
+launch-path-unresolved = Start übersprungen: TIDAL-Pfad konnte nicht ermittelt werden ({ $error })
+launch-skipped = Installation abgeschlossen; automatischer Start von TIDAL übersprungen
+launch-path-unavailable = Start übersprungen: TIDAL-resources-Pfad ist nicht verfügbar
+launch-trying = Versuche, TIDAL aus { $path } neu zu starten
+launch-done = TIDAL erfolgreich neu gestartet
+launch-no-binary = Installation abgeschlossen; keine startbare TIDAL-Datei für den automatischen Start gefunden
+
+step-verify-luna = Luna prüfen
+verify-startup-error = TIDAL hat einen Startfehler gemeldet ({ $marker }): { $line }
+verify-exited = TIDAL wurde nach { $seconds } s beendet mit { $status }
+verify-poll-failed = TIDAL-Prozess konnte nicht abgefragt werden: { $error }
+verify-no-backup = Sicherung original.asar nicht gefunden
+verify-rollback-asar = Zurücksetzen: stelle ursprüngliche app.asar wieder her
+verify-remove-asar-failed = app.asar konnte nicht entfernt werden: { $error }
+verify-restore-asar-failed = app.asar konnte nicht wiederhergestellt werden: { $error }
+verify-rollback-app = Zurücksetzen: entferne Luna-app-Verzeichnis
+verify-remove-app-failed = Luna-app-Verzeichnis konnte nicht entfernt werden: { $error }
+verify-as-user = Läuft als root; prüfe als aufrufender Benutzer '{ $user }'
+verify-root-skipped = Prüfung übersprungen: TIDAL kann unter Linux nicht als root gestartet werden und es wurde kein sudo/pkexec-Benutzer erkannt. Starte TIDAL selbst als normaler Benutzer.
+verify-root-done = Installation abgeschlossen; Prüfung für root-Sitzung unter Linux übersprungen
+verify-path-unresolved = TIDAL-Pfad für die Prüfung konnte nicht ermittelt werden: { $error }
+verify-log-dir-failed = Protokollverzeichnis für die Prüfung konnte nicht erstellt werden: { $error }
+verify-log-file-failed = Protokolldatei für die Prüfung konnte nicht erstellt werden: { $error }
+verify-launched = TIDAL mit Protokollierung gestartet von { $candidate }
+verify-no-binary = Keine startbare TIDAL-Datei gefunden, um die Installation zu prüfen
+verify-watching = Beobachte TIDAL bis zu { $seconds } Sekunden (Protokoll: { $path })
+verify-marker-found = Luna-Bereitschaftsmeldung in der TIDAL-Ausgabe gefunden
+verify-done = Luna erfolgreich geladen
+verify-no-marker = TIDAL lief { $seconds } Sekunden lang, aber Luna hat nie gemeldet, dass es geladen wurde
+verify-failed = Prüfung fehlgeschlagen: { $reason }. Deinstalliere, um die ursprüngliche app.asar wiederherzustellen
+verify-rollback-failed = Prüfung fehlgeschlagen: { $reason }. Zurücksetzen fehlgeschlagen: { $error }
+verify-rolled-back = Prüfung fehlgeschlagen: { $reason }. Auf die ursprüngliche app.asar zurückgesetzt
+
+## Pipeline
+
+run-cancelled-before-step = Abgebrochen, bevor dieser Schritt begann
+run-cancel-deferred = Abbruch angefordert; dieser Schritt wird noch abgeschlossen, damit TIDAL nicht ohne App zurückbleibt. Danach endet der Vorgang.
+
+## Command line
+
+cli-runtime-failed = Async-Laufzeit konnte nicht gestartet werden: { $error }
+cli-legacy-with-subcommand = Veraltete Aktions-Flags können nicht mit einem Unterbefehl kombiniert werden
+cli-legacy-flags-combined = Warnung: Die Kombination { $flags } ist veraltet; führe wie bisher { $used } aus. Nutze stattdessen einen Unterbefehl, siehe --help
+cli-gui-failed = Die Oberfläche konnte nicht gestartet werden: { $error }
+cli-banner = { -app } CLI
+cli-installer-update-available = Installer-Update verfügbar: v{ $current } -> v{ $latest }
+cli-installer-up-to-date = Der Installer ist auf dem neuesten Stand (v{ $current }).
+cli-installer-releases = Installer-Versionen für diese Plattform:
+cli-note-prerelease = Vorabversion
+cli-note-current = aktuell
+cli-available-releases = Verfügbare Versionen:
+cli-channel = Kanal: { $channel }
+cli-detected-installations = Erkannte TIDAL-Installationen:
+cli-status-client = Client:
+cli-status-luna = TidaLuna:
+cli-status-installed-version = installiert ({ $version })
+cli-status-installed = installiert
+cli-status-not-installed = nicht installiert
+cli-status-rollback = Zurück zu:
+cli-status-present = vorhanden
+cli-status-backup = vorhanden (Sicherung)
+cli-status-missing = fehlt
+cli-service-unit = Dienst-Unit:
+cli-timer-unit = Timer-Unit:
+cli-timer = Timer:
+cli-timer-enabled = aktiviert
+cli-timer-disabled = deaktiviert
+cli-timer-active = aktiv
+cli-timer-inactive = inaktiv
+cli-last-run = Letzter Lauf:
+cli-run-install = Installiere { $channel } Version { $version } nach { $path }
+cli-run-rollback = Setze Luna in { $path } zurück
+cli-run-uninstall = Deinstalliere aus { $path }
+cli-step-starting = Starte Schritt: { $name }
+cli-step-finished = Schritt erfolgreich abgeschlossen: { $name } - { $message }
+cli-step-failed-title = Schritt fehlgeschlagen
+cli-see-above = Details siehe oben.
+cli-step-failed = Schritt '{ $step }' fehlgeschlagen: { $message }
+cli-step-failed-banner = SCHRITT FEHLGESCHLAGEN: { $name }
+cli-summary = Zusammenfassung
+cli-summary-ok = OK
+cli-summary-failed = FEHLER
+cli-choose-installation = Mehrere TIDAL-Installationen gefunden. Bitte wähle einen Pfad:
+cli-enter-selection = Auswahl eingeben (1-{ $count }, q zum Abbrechen):
+cli-selection-cancelled = Auswahl abgebrochen
+cli-invalid-selection = Ungültige Auswahl. Bitte gib eine Zahl zwischen 1 und { $count } ein.
+cli-path-missing = TIDAL-resources-Verzeichnis nicht gefunden: { $path } existiert nicht
+cli-no-flavor-installation = keine { $flavor }-Installation erkannt
+cli-no-installation = TIDAL-resources-Verzeichnis nicht gefunden
+cli-find-failed = TIDAL-resources-Verzeichnis nicht gefunden: { $error }
+cli-target-out-of-range = { $count ->
+        [one] --target { $index } liegt außerhalb des Bereichs; { $count } Installation erkannt
+       *[other] --target { $index } liegt außerhalb des Bereichs; { $count } Installationen erkannt
+    }
+cli-ambiguous-target = Mehrere TIDAL-Installationen gefunden; wähle eine mit --target <number|path|all> oder --flavor: { $candidates }
+cli-read-selection-failed = Auswahl konnte nicht gelesen werden: { $error }
+cli-update-hint = Möchtest du aktualisieren? Führe aus: tidaluna-installer self-update
+cli-update-check-failed = Suche nach Installer-Updates fehlgeschlagen: { $error }
+cli-installer-version-not-found = Installer-Version '{ $version }' nicht gefunden oder ohne Download für diese Plattform
+cli-installer-already-running = Installer v{ $version } läuft bereits.
+cli-installer-switching = Wechsle Installer v{ $current } -> v{ $target }
+cli-installer-applying = Installiere Installer-Update auf v{ $version }...
+cli-installer-apply-failed = Installer-Update konnte nicht installiert werden: { $error }
+cli-status-none = Keine TIDAL-Installation gefunden: { $error }
+cli-already-installed = TidaLuna / Neptune ist bereits installiert. Es wird neu installiert.
+cli-no-command = Kein gültiger Befehl angegeben. Hilfe gibt es mit --help.
+config-using-defaults = { $error }; verwende Standardeinstellungen
+config-not-bool = { $key } muss true oder false sein, war '{ $value }'
+config-bad-launch = launch muss always, never oder verify sein, war '{ $value }'
+config-bad-timeout = verify_timeout muss eine Anzahl Sekunden sein, war '{ $value }'
+config-bad-proxy = ungültiger Proxy '{ $proxy }': { $error }
+config-bad-locale = locale muss eines von { $locales } sein, war '{ $value }'
+config-bad-theme = theme muss system, dark, light oder high-contrast sein, war '{ $value }'
+config-unknown-key = Unbekannter Einstellungsschlüssel '{ $key }'. Bekannte Schlüssel: { $keys }
+config-invalid-file = Ungültige Konfigurationsdatei { $path }: { $error }
+config-read-failed = Konfigurationsdatei { $path } konnte nicht gelesen werden: { $error }
+config-no-dir = Das Konfigurationsverzeichnis konnte nicht ermittelt werden
+config-serialize-failed = Einstellungen konnten nicht serialisiert werden: { $error }
+config-create-dir-failed = Konfigurationsverzeichnis { $path } konnte nicht erstellt werden: { $error }
+config-write-failed = Konfigurationsdatei { $path } konnte nicht geschrieben werden: { $error }
+http-invalid-proxy = Ungültiger Proxy '{ $proxy }' wird ignoriert: { $error }
+
+## Releases
+
+release-channel-not-found = Versionskanal '{ $channel }' nicht gefunden
+release-version-not-found = Version '{ $version }' nicht gefunden
+release-none = Keine Version zum Installieren gefunden!
+release-channel-empty = Der gewählte Versionskanal '{ $channel }' enthält keine Versionen
+release-version-not-in-channel = Version '{ $version }' im Kanal '{ $channel }' nicht gefunden
+releases-load-failed = Versionen konnten nicht geladen werden: { $error }
+
+## Installer updates
+
+updater-exe-unresolved = Pfad der laufenden Installer-Datei konnte nicht ermittelt werden: { $error }
+updater-dpkg-guidance = Der Installer wurde aus dem Paket '{ $package }' installiert und wird daher nicht direkt ersetzt. Aktualisiere ihn mit deinem Paketmanager:
+updater-no-checksums = Release v{ $version } veröffentlicht keine { $file }-Datei, daher kann der Download nicht geprüft werden
+updater-checksums-download-failed = { $file } konnte nicht heruntergeladen werden: { $error }
+updater-checksums-status = Download von { $file } lieferte { $status }
+updater-checksums-read-failed = { $file } konnte nicht gelesen werden: { $error }
+updater-checksum-missing = { $file } enthält keinen Eintrag für { $asset }
+updater-checksum-mismatch = Prüfsumme für { $asset } stimmt nicht: erwartet { $expected }, erhalten { $actual } (abgebrochener oder beschädigter Download?)
+updater-binary-start-failed = Die neue Installer-Datei startet nicht: { $error }
+updater-binary-timeout = Die neue Installer-Datei hat sich nicht innerhalb von { $seconds } Sekunden beendet
+updater-binary-wait-failed = Warten auf die neue Installer-Datei fehlgeschlagen: { $error }
+updater-smoke-test-failed = Die neue Installer-Datei hat den Starttest nicht bestanden ({ $status })
+updater-wrong-version = Die neue Installer-Datei meldet Version '{ $reported }' statt { $expected }
+updater-write-failed = Update-Datei konnte nicht geschrieben werden: { $error }
+updater-permissions-failed = Ausführungsrechte konnten nicht gesetzt werden: { $error }
+updater-backup-failed = Die aktuelle Installer-Datei konnte nicht gesichert werden: { $error }
+updater-replace-failed = Die Installer-Datei konnte nicht ersetzt werden: { $error }
+updater-restore-failed = { $error }; auch die Wiederherstellung des vorherigen Installers schlug fehl: { $restore_error }. Er liegt weiterhin unter { $path }
+updater-restored = { $error }; der vorherige Installer wurde wiederhergestellt
+updater-script-create-failed = Update-Skript konnte nicht erstellt werden: { $error }
+updater-script-write-failed = Update-Skript konnte nicht geschrieben werden: { $error }
+updater-script-start-failed = Update-Skript konnte nicht gestartet werden: { $error }
+updater-relaunching = Update heruntergeladen. Der Installer wird jetzt geschlossen und aktualisiert neu gestartet.
+updater-reopen = Update heruntergeladen. Beende den Installer und öffne ihn erneut, um die neue Version zu nutzen.
+updater-prerelease = v{ $version } (Vorabversion)
+updater-client-failed = HTTP-Client für Updates konnte nicht erstellt werden: { $error }
+updater-list-failed = Installer-Versionen konnten nicht abgerufen werden: { $error }
+updater-list-status = Abfrage der Installer-Versionen lieferte { $status }
+updater-list-parse-failed = Liste der Installer-Versionen konnte nicht gelesen werden: { $error }
+updater-no-build = Für { $os } { $arch } wurde noch kein Installer veröffentlicht
+updater-lookup-failed = Installer-Version v{ $version } konnte nicht abgefragt werden: { $error }
+updater-release-status = Abfrage der Installer-Version lieferte { $status }
+updater-release-parse-failed = Installer-Version konnte nicht gelesen werden: { $error }
+updater-invalid-version = Ungültige aktuelle Installer-Version: { $version }
+updater-download-failed = Installer-Update konnte nicht heruntergeladen werden: { $error }
+updater-download-status = Download des Installer-Updates lieferte { $status }
+updater-download-read-failed = Installer-Update konnte nicht gelesen werden: { $error }
+updater-download-empty = Der Download des Installer-Updates ist leer
+updater-updated = Installer erfolgreich aktualisiert. Bitte starte den Installer neu. Die vorherige Version wurde als { $path } behalten.
+
+## watch
+
+watch-repair-skipped = Kein Beleg und keine Versionsliste; unklar, welche Luna-Version wiederhergestellt werden soll
+watch-client-removed = Installation wurde entfernt; wird nicht mehr beobachtet
+watch-client-missing = Installationsverzeichnis fehlt, vermutlich während eines Updates; warte, bis es zurückkommt
+watch-client-returned = Installationsverzeichnis ist zurück; wird wieder überwacht
+watch-new-client = Neue TIDAL-Version erkannt
+watch-injection-lost = Luna ist nicht mehr eingebunden
+watch-update-available = Luna { $version } ist verfügbar (installiert: { $installed })
+watch-not-installed = Luna ist in { $path } nicht installiert; zuerst install ausführen. Wird übersprungen.
+watch-nothing = Keine TIDAL-Installation mit Luna zum Beobachten
+watch-start-failed = Dateiüberwachung konnte nicht gestartet werden: { $error }
+watch-dir-failed = { $path } kann nicht beobachtet werden: { $error }
+watch-started = Beobachte Änderungen
+watch-stop-hint = Zum Beenden Strg+C drücken.
+watch-error = Fehler der Dateiüberwachung: { $error }
+watch-stopped = Beobachtung beendet
+
+## service
+
+service-binary-missing = Installer-Datei { $path } existiert nicht
+service-reload-failed = systemctl --user daemon-reload fehlgeschlagen: { $error }
+service-enable-failed = Units geschrieben, aber { $unit } konnte nicht aktiviert werden: { $error }
+service-disable-failed = { $unit } konnte nicht deaktiviert werden: { $error }
+service-linux-only = `service` nutzt systemd und ist nur unter Linux verfügbar
+service-not-as-root = Führe `service` als normaler Benutzer aus, ohne sudo oder pkexec
+service-no-unit-dir = Das systemd-Verzeichnis für Benutzer-Units konnte nicht ermittelt werden
+service-exe-unknown = Die Installer-Datei konnte nicht gefunden werden: { $error }
+service-exe-unresolved = Installer-Datei { $path } konnte nicht aufgelöst werden: { $error }
+service-bad-interval = Ungültiges Intervall '{ $interval }'; nutze eine systemd-Zeitspanne wie 30min, 6h oder 1d
+service-foreign-unit = { $path } existiert und wurde nicht vom Installer erstellt; wird nicht ersetzt
+service-foreign-unit-kept = { $path } wurde nicht vom Installer erstellt; wird nicht entfernt
+service-create-dir-failed = { $path } konnte nicht erstellt werden: { $error }
+service-write-failed = { $path } konnte nicht geschrieben werden: { $error }
+service-remove-failed = { $path } konnte nicht entfernt werden: { $error }
+service-systemctl-failed = systemctl konnte nicht ausgeführt werden: { $error }
+
+## diagnose
+diagnose-write-failed = Das Diagnosepaket konnte nicht geschrieben werden: { $error }
+diagnose-bundle-written = Diagnosepaket gespeichert unter { $path }
+diagnose-bundle-hint = Home-Pfade und Benutzernamen sind unkenntlich gemacht. Hänge die Datei an deinen Fehlerbericht an.
+
+## Graphical interface
+
+gui-update-check-cancelled = Suche nach Installer-Updates fehlgeschlagen: Aufgabe abgebrochen
+gui-update-apply-cancelled = Installer-Update fehlgeschlagen: Aufgabe abgebrochen
+gui-releases-cancelled = Versionen konnten nicht geladen werden: Aufgabe abgebrochen
+gui-stargazers-cancelled = Stargazers konnten nicht geladen werden: Aufgabe abgebrochen
+gui-detect-cancelled = TIDAL-Pfade konnten nicht erkannt werden: Aufgabe abgebrochen
+gui-archive-cancelled = Archiv konnte nicht geprüft werden: Aufgabe abgebrochen
+gui-step-failed = Schritt fehlgeschlagen: { $name } - { $message }
+gui-no-path-selected = Kein TIDAL-Pfad ausgewählt
+gui-summary-ok = OK: { $path }
+gui-summary-failed = FEHLER: { $path } ({ $step }: { $message })
+gui-task-stopped = Der Vorgang wurde unerwartet beendet
+gui-window-title = { -app } v{ $version }
+gui-log-window-title = { -app } v{ $version } - Protokoll
+gui-title = { -app }
+gui-subtitle = TidaLuna in einer TIDAL-Installation installieren oder entfernen.
+gui-subsubtitle = Funktioniert mit der offiziellen TIDAL-App und tidal-hifi. Hinweis: Wenn du die TIDAL-Version aus dem Windows Store nutzt, deinstalliere sie bitte und installiere die Version von der offiziellen Website.
+gui-installer-version = Installer-Version: v{ $version }
+gui-status = Status
+gui-status-installed = Installiert
+gui-status-not-installed = Nicht installiert
+gui-release-channel = Versionskanal
+gui-loading = Lädt...
+gui-select-channel = Versionskanal wählen...
+gui-version = Version
+gui-select-channel-first = Zuerst einen Kanal wählen...
+gui-select-version = Version wählen...
+gui-release-title = Luna { $version }
+gui-release-prerelease = Vorabversion
+gui-release-published = Veröffentlicht am { $date }
+gui-release-upgrade = Aktualisierung von der installierten { $installed }
+gui-release-same = Entspricht der installierten Version ({ $installed })
+gui-release-downgrade = Herabstufung: { $version } ist älter als die installierte { $installed }. Plugins und Einstellungen der neueren Version funktionieren eventuell nicht.
+gui-release-different = Installiert: { $installed }
+gui-release-installed-unknown = Luna ist installiert, die Version ist aber unbekannt
+gui-release-no-notes = Für diese Version wurden keine Versionshinweise veröffentlicht.
+gui-custom-path = Eigener Pfad (optional)
+gui-custom-path-placeholder = Leer lassen für das Standard-Tidal-Verzeichnis
+gui-detected-path = Erkannter TIDAL-Installationspfad
+gui-detected-path-description = Wird standardmäßig verwendet. Ein unten eingetragener eigener Pfad (zuerst erweiterte Optionen öffnen) hat Vorrang.
+gui-no-detected-paths = Keine TIDAL-Installationspfade erkannt
+gui-apply-to-all = Auf alle { $count } erkannten Installationen anwenden
+gui-show-advanced = Erweiterte Optionen anzeigen
+gui-verify-toggle = Nach dem Start von TIDAL prüfen, ob Luna lädt
+gui-rollback-toggle = Ursprüngliches TIDAL automatisch wiederherstellen, wenn die Prüfung fehlschlägt
+gui-language = Sprache
+gui-language-system = Systemsprache
+gui-theme = Design
+gui-theme-system = Wie das System
+gui-theme-dark = Dunkel
+gui-theme-light = Hell
+gui-theme-high-contrast = Hoher Kontrast
+gui-install = Installieren
+gui-reinstall = Neu installieren
+gui-uninstall = Deinstallieren
+gui-repair = Reparieren
+gui-update-to = Auf { $version } aktualisieren
+gui-roll-back = Zurücksetzen
+gui-roll-back-to = Auf { $version } zurücksetzen
+gui-cancel = Abbrechen
+gui-retry-from = Ab „{ $step }“ wiederholen
+gui-retry-many = { $count } fehlgeschlagene Installationen wiederholen
+gui-usage-warning = Nutzungshinweis
+gui-usage-warning-piracy = Mit TidaLuna kannst du keine Medien raubkopieren oder die Grenzen des kostenlosen TIDAL-Tarifs umgehen.
+gui-usage-warning-account = Nur mit einem rechtmäßigen Konto verwenden. Bei Problemen im Zusammenhang mit Piraterie helfen wir nicht.
+gui-installation-log = Installationsprotokoll
+gui-clear-log = Protokoll leeren
+gui-drop-hint = Offline? Ziehe eine Luna-.zip auf dieses Fenster, um sie ohne Download zu installieren.
+gui-drop-not-zip = Nur .zip-Archive können installiert werden; { $name } wurde ignoriert
+gui-drop-checking = Prüfe abgelegtes Archiv { $path }...
+gui-archive-ready = { $name } ist ein gültiges Luna-Archiv
+gui-archive-title = Lokales Archiv: { $name }
+gui-archive-version = Luna-Version: { $version }
+gui-archive-version-unknown = Luna-Version: im Archiv nicht angegeben
+gui-archive-contents = { $files } Dateien, { $size } MB entpackt ({ $archive_size } MB Archiv)
+gui-archive-entries = Enthält: { $entries }
+gui-archive-entries-more = Enthält: { $entries } und { $count } weitere
+gui-archive-install = Aus diesem Archiv installieren
+gui-archive-discard = Verwerfen
+gui-archive-installing = Installiere Luna aus { $name }...
+gui-archive-bad-path = { $path } kann nicht als Archivpfad verwendet werden
+gui-uninstall-preview-failed = Installation konnte nicht geprüft werden: Aufgabe abgebrochen
+gui-uninstall-preview-title = Luna deinstallieren?
+gui-uninstall-preview-intro = Bis zur Bestätigung wird nichts verändert.
+gui-uninstall-preview-no-luna = Luna ist hier nicht installiert; nichts zu entfernen
+gui-uninstall-preview-removes = Entfernt Luna { $version } (app/, { $files } Dateien, { $size } MB)
+gui-uninstall-preview-restores = Stellt original.asar als app.asar wieder her (TIDAL { $version })
+gui-uninstall-preview-restores-unknown = Stellt original.asar als app.asar wieder her (TIDAL-Version unbekannt)
+gui-uninstall-preview-no-original = Keine Sicherung original.asar gefunden; app.asar bleibt unverändert
+gui-uninstall-preview-replaces-previous = Die behaltene Version { $version } wird durch die Sicherung ersetzt
+gui-uninstall-preview-keeps-previous = Die behaltene Version { $version } bleibt zum Zurücksetzen verfügbar
+gui-uninstall-preview-tidal-running = TIDAL läuft und wird beendet
+gui-uninstall-preview-tidal-stopped = TIDAL läuft nicht
+gui-uninstall-preview-tidal-unknown = TIDAL wird beendet, falls es läuft
+gui-uninstall-preview-relaunch = TIDAL wird danach wieder gestartet
+gui-uninstall-keep-backup = Sicherung behalten, damit Luna zurückgesetzt werden kann
+gui-uninstall-wipe-data = Auch Luna-Benutzerdaten löschen (Einstellungen und Plugins)
+gui-uninstall-no-user-data = Keine Luna-Benutzerdaten gefunden
+gui-uninstall-wipe-data-shared = Browser-Datenbanken mit Luna-Daten werden vollständig gelöscht, auch was TIDAL darin gespeichert hat. Cookies und Local Storage bleiben erhalten.
+gui-releases-from-cache = Zeige die am { $date } UTC gespeicherte Versionsliste, während sie aktualisiert wird
+gui-offline-banner = Offline — Daten vom { $date } UTC
+gui-offline-hint = Versionsliste und Stargazer stammen vom letzten Mal, als der Installer online war. Zum Installieren wird eine Verbindung benötigt, außer das Archiv liegt im Cache.
+gui-offline-retry = Erneut versuchen
+gui-open-log-window = Protokollfenster öffnen
+gui-log-cleared = Protokoll geleert
+gui-loading-releases = Lade Versionen...
+gui-releases-loaded = Versionen erfolgreich geladen
+gui-downloading-update = Lade Installer-Update v{ $version } herunter...
+gui-update-failed = Installer-Update fehlgeschlagen: { $error }
+gui-stargazers-failed = Stargazers konnten nicht geladen werden: { $error }
+gui-selected-channel = Gewählter Kanal: { $channel }
+gui-selected-saved-version = Gespeicherte Version gewählt: { $version }
+gui-auto-selected-version = Automatisch gewählte Version: { $version }
+gui-selected-version = Gewählte Version: { $version }
+gui-detected-one-path = Ein TIDAL-Installationspfad wurde automatisch erkannt
+gui-detected-many-paths = Mehrere TIDAL-Installationen erkannt. Der neueste Pfad wurde gewählt; bei Bedarf in den erweiterten Optionen ändern.
+gui-detect-failed = TIDAL-Pfade konnten nicht automatisch erkannt werden: { $error }
+gui-no-path-hint = Kein TIDAL-Pfad ausgewählt. Wähle einen aus der Liste oder trage in den erweiterten Optionen einen eigenen Pfad ein.
+gui-starting-install = Starte Installation...
+gui-starting-uninstall = Starte Deinstallation...
+gui-card-installing = Installiere Luna { $version } nach { $path }...
+gui-card-updating = Aktualisiere Luna in { $path } auf { $version }...
+gui-card-repairing = Repariere Luna { $version } in { $path }...
+gui-rolling-back-log = Setze auf die vorherige Luna-Version zurück...
+gui-cancelling-log = Breche nach dem aktuellen Schritt ab...
+gui-retrying-log = Wiederhole { $path } ab Schritt: { $step }
+gui-already-installed = TidaLuna ist bereits installiert
+gui-operation-succeeded = Vorgang erfolgreich abgeschlossen!
+gui-operation-cancelled = Vorgang abgebrochen
+gui-operation-steps-failed = Vorgang fehlgeschlagen: Ein oder mehrere Schritte sind fehlgeschlagen
+gui-operation-failed = Vorgang fehlgeschlagen: { $error }
+gui-settings-save-failed = Einstellungen konnten nicht gespeichert werden: { $error }
+gui-progress-cancelling = Breche ab...
+gui-progress-retrying = Wiederhole...
+gui-progress-installing = Installiere...
+gui-progress-rolling-back = Setze zurück...
+gui-progress-uninstalling = Deinstalliere...
+gui-progress-target = Installation { $current }/{ $total }
+gui-progress-step = Schritt { $current }/{ $total }: { $name }
+gui-update-available = Installer-Update verfügbar
+gui-update-available-body = Eine neuere Installer-Version ist verfügbar (aktuell v{ $version }). Vor dem Fortfahren aktualisieren?
+gui-updating = Aktualisiere...
+gui-install-update = v{ $version } installieren
+gui-skip = Überspringen
+gui-skip-version = Diese Version überspringen
+gui-show-prereleases = Vorabversionen anzeigen
+gui-installations = Installationen
+gui-no-installations = Keine TIDAL-Installation erkannt.
+gui-card-needs-repair = Luna: Reparatur nötig, ein TIDAL-Update hat es ersetzt
+gui-card-luna-version = Luna: { $version } ({ $channel })
+gui-card-luna-unknown = Luna: installiert, Version unbekannt
+gui-card-luna-missing = Luna: nicht installiert
+gui-card-backup = Sicherung: original.asar
+gui-card-no-backup = Sicherung: keine
+gui-card-previous = , vorheriges Luna { $version }
+gui-stargazers = Stargazers <3
+gui-stargazers-thanks = Danke an alle, die diesem Projekt einen Stern gegeben haben.
+gui-stargazers-loading = Lade Stargazers...
+gui-stargazers-error = Stargazers konnten nicht geladen werden: { $error }
+gui-stargazers-empty = Noch keine Stargazers.
+gui-stargazers-page = { $first }-{ $last } von { $total } • Seite { $page }/{ $pages }
+gui-previous = Zurück
+gui-next = Weiter
+
+## Log window
+
+gui-log-search = Protokoll durchsuchen...
+gui-log-save-path = Datei, in die das Protokoll gespeichert wird
+gui-log-save = Speichern
+gui-log-copy = In die Zwischenablage kopieren
+gui-log-count = { $shown } von { $total } Zeilen
+gui-log-show-lines = { $count } Zeilen anzeigen
+gui-log-hide-details = Details ausblenden
+gui-log-saved = Gespeichert unter { $path }
+gui-log-save-failed = Speichern unter { $path } fehlgeschlagen: { $error }
+gui-log-copied = In die Zwischenablage kopiert
+gui-diagnostics-export = Diagnose exportieren
+gui-diagnostics-collecting = Diagnosedaten werden gesammelt...
+gui-diagnostics-saved = Diagnosepaket gespeichert unter { $path }
+gui-level-info = Info
+gui-level-success = Erfolg
+gui-level-error = Fehler
+gui-level-step = Schritt
+gui-level-substep = Teilschritt
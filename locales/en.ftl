@@ -0,0 +1,608 @@
+# English catalog. It is the last link of every fallback chain, so it has to
+# define every message; the other catalogs must define exactly the same keys.
+
+language-name = English
+-app = TidaLuna Installer
+unknown-version = unknown version
+
+## Installer steps
+
+step-tidal-not-found = Tidal is not installed or path could not be found
+step-path-missing = Tidal path does not exist
+step-path-unresolved = Tidal path could not be resolved
+step-invalid-path = Invalid Tidal path
+step-tidal-missing = Tidal is not installed: { $error }
+step-unsupported-os = Unsupported operating system
+
+step-setup = Setup
+setup-temp-dir-failed = Failed to create temporary directory: { $error }
+setup-temp-dir-created = Temporary directory created: { $path }
+setup-checking-tidal = Checking if Tidal is installed
+setup-tidal-path-missing = Tidal path does not exist: { $path }
+setup-app-asar-missing = app.asar not found — Tidal is not installed correctly
+setup-tidal-valid = Tidal is installed and valid
+setup-done = Setup step completed successfully
+
+step-copy-asar-install = Copy ASAR for Installation
+copy-asar-using-path = Using Tidal path: { $path }
+copy-asar-no-asar = No .asar files found — Tidal is not installed correctly
+copy-asar-refreshing-backup = app.asar was updated by TIDAL; replacing the outdated original.asar backup
+copy-asar-refresh-failed = Failed to refresh original.asar backup: { $error }
+copy-asar-app-asar-missing = app.asar not found. Tidal installation may be corrupt.
+copy-asar-creating-backup = Creating original.asar backup
+copy-asar-backup-failed = Failed to backup app.asar: { $error }
+copy-asar-delete-failed = Failed to delete existing app.asar: { $error }
+copy-asar-copied = app.asar copied to original.asar successfully
+copy-asar-done = Copy ASAR step completed
+
+step-restore-asar = Restore original ASAR
+restore-asar-original-not-found = original.asar not found, cannot restore app.asar
+restore-asar-original-missing = original.asar missing
+restore-asar-restoring = Restoring original app.asar...
+restore-asar-remove-existing-failed = Failed to remove existing app.asar: { $error }
+restore-asar-remove-failed = Failed to remove app.asar: { $error }
+restore-asar-copy-failed = Failed to restore app.asar: { $error }
+restore-asar-restored = original.asar restored to app.asar successfully
+restore-asar-done = ASAR restored successfully
+
+step-write-receipt = Write Install Receipt
+receipt-recorded = Recorded { $channel } { $version } in { $path }
+receipt-write-failed = Warning: failed to write install receipt: { $error }
+receipt-record-failed = Warning: failed to record the install for `watch`: { $error }
+receipt-done = Install receipt written
+
+step-restore-ownership = Restore File Ownership
+ownership-not-elevated = Not running through sudo/pkexec; ownership unchanged
+ownership-not-needed = Ownership restore not needed
+ownership-path-unresolved = Skipping ownership restore: could not resolve TIDAL path ({ $error })
+ownership-skipped = Ownership restore skipped
+ownership-foreign-path = { $path } is not owned by '{ $user }'; leaving installed files owned by root
+ownership-system-location = Ownership restore not needed for system location
+ownership-changed = { $count ->
+        [one] Changed owner of { $count } path under { $path } to '{ $user }'
+       *[other] Changed owner of { $count } paths under { $path } to '{ $user }'
+    }
+ownership-change-failed = Warning: failed to change owner of { $path }: { $error }
+ownership-done = Installed files handed back to '{ $user }'
+
+step-rollback-luna = Restore Previous Luna
+rollback-no-previous = No previous Luna install found in { $path }
+rollback-swapping = Swapping current Luna ({ $current }) with previous Luna ({ $previous })
+rollback-set-aside-failed = Failed to set aside the current Luna install: { $error }
+rollback-restore-failed = Failed to restore the previous Luna install: { $error }
+rollback-kept-replaced = Kept the replaced install as the new previous version
+rollback-keep-failed = Warning: could not keep the replaced install: { $error }
+rollback-done = Rolled back to Luna { $version }
+
+step-uninstall = Uninstall TidaLuna
+uninstall-no-tidal = Tidal is not installed, skipping uninstallation
+uninstall-running = Uninstalling TidaLuna / Neptune...
+uninstall-no-luna = TidaLuna / Neptune is not installed, skipping uninstallation...
+uninstall-nothing = Nothing to uninstall
+uninstall-removed = TidaLuna / Neptune uninstalled successfully
+uninstall-done = Uninstallation completed successfully
+uninstall-error = Error uninstalling TidaLuna / Neptune: { $error }
+uninstall-failed = Failed to uninstall: { $error }
+uninstall-keeping-backup = Keeping TidaLuna ({ $version }) as the previous version for rollback
+uninstall-backup-failed = Warning: could not keep a backup ({ $error }); removing it instead
+step-wipe-luna-data = Delete Luna User Data
+wipe-nothing-found = No Luna user data found
+wipe-removed = Deleted { $path }
+wipe-remove-failed = Failed to delete { $path }: { $error }
+wipe-failed = { $count ->
+        [one] { $count } Luna user data entry could not be deleted
+       *[other] { $count } Luna user data entries could not be deleted
+    }
+wipe-done = Luna user data deleted
+
+step-sign-tidal = Sign TIDAL
+sign-not-needed = No need to sign TIDAL on { $os }, skipping...
+sign-skipped = Signing skipped on { $os }
+sign-running = Signing TIDAL on macOS...
+sign-target = Using codesign target: { $path }
+sign-error = Error signing TIDAL on macOS
+sign-signed = TIDAL signed successfully on macOS
+sign-done = Signing completed successfully
+sign-codesign-failed = Failed to execute codesign: { $error }
+
+step-reinstall-cleanup = Reinstall Cleanup
+cleanup-path-unresolved = Skipping reinstall cleanup: could not resolve TIDAL path ({ $error })
+cleanup-skipped = Reinstall cleanup skipped
+cleanup-path-missing = Skipping reinstall cleanup: TIDAL path does not exist
+cleanup-restoring-asar = Attempting to restore original app.asar before reinstall
+cleanup-remove-asar-failed = Warning: failed removing app.asar before restore: { $error }
+cleanup-restore-asar-failed = Warning: failed restoring app.asar from original.asar: { $error }
+cleanup-no-original = original.asar not found, skipping ASAR restore for reinstall
+cleanup-keeping-previous = Keeping existing TidaLuna ({ $version }) as the previous version for rollback
+cleanup-keep-failed = Warning: could not keep previous version ({ $error }); removing it instead
+cleanup-remove-app-failed = Warning: failed removing existing app directory: { $error }
+cleanup-not-needed = No existing TidaLuna app directory found; cleanup not needed
+cleanup-done = Reinstall cleanup completed
+
+step-insert-luna = Insert Luna
+insert-temp-dir = Using temp directory: { $path }
+insert-temp-dir-missing = Temporary Luna directory does not exist
+insert-copying = Copying Luna files into Tidal app directory
+insert-create-dir-failed = Failed to create destination directory: { $error }
+insert-copy-failed = Failed to copy Luna files: { $error }
+insert-copied = Luna files copied successfully
+insert-done = Insert Luna step completed successfully
+
+step-extract-luna = Extract Luna
+extract-cleaning = Cleaning existing extract path: { $path }
+extract-clean-failed = Failed to clean extract dir: { $error }
+extract-ensuring-dir = Ensuring extract path exists: { $path }
+extract-create-dir-failed = Failed to create extract dir: { $error }
+extract-running = Extracting Luna...
+extract-read-failed = Failed to read zip bytes before extraction: { $error }
+extract-open-validation-failed = Failed to open zip for validation: { $error }
+extract-too-small = Zip file is unexpectedly small: { $size } bytes
+extract-invalid-zip = Failed ZIP validation before extraction: { $error } (size: { $size } bytes)
+extract-open-failed = Failed to open zip: { $error }
+extract-archive-failed = Failed to read zip archive: { $error }
+extract-entry-failed = Failed to access zip entry: { $error }
+extract-entry-too-large = Zip entry is too large (>{ $limit } bytes): { $name }
+extract-unsafe-entry = Unsafe zip entry path rejected: { $name }
+extract-mkdir-failed = Failed to create dir: { $error }
+extract-parent-dir-failed = Failed to create parent dir: { $error }
+extract-create-file-failed = Failed to create file: { $error }
+extract-write-failed = Failed to write file: { $error }
+extract-extracted = Luna extracted successfully
+extract-done = Extraction completed
+
+step-kill-tidal = Kill TIDAL
+kill-detected-os = Detected OS: { $os }
+kill-running = Killing TIDAL process(es) ({ $os })
+kill-stopped-image = Stopped process image: { $image }
+kill-stopped-pattern = Stopped process pattern: { $pattern }
+kill-no-command = Warning: no kill command could be executed on this system
+kill-nothing-running = No running TIDAL process found to kill
+kill-completed = Kill TIDAL step completed
+kill-done = Kill TIDAL completed (non-fatal)
+
+step-download-luna = Download Luna
+zip-missing-signature = missing ZIP signature
+zip-missing-central-directory = missing ZIP central directory
+zip-parse-failed = ZIP parse validation failed: { $error }
+download-client-failed = Failed to build HTTP client: { $error }
+download-request-failed = Failed to send download request: { $error }
+download-http-status = Failed to download Luna, HTTP status: { $status }
+download-read-failed = Failed to read download response: { $error }
+download-cancelled = Download cancelled
+download-empty = Download completed but returned an empty file
+download-invalid-zip = Downloaded file failed ZIP validation ({ $error }). URL: { $url }. Response preview: { $preview }
+download-temp-dir = Using temporary directory: { $path }
+download-using-cache = Using cached archive { $path }
+download-cache-outdated = The release behind this download changed since { $path } was cached, downloading it again
+download-cache-unverified = Could not check the cached archive against the server, using it anyway
+download-running = Downloading Luna...
+download-cached = Cached archive at { $path }
+download-cache-failed = Warning: could not cache archive: { $error }
+download-part-write-failed = Failed to write Luna.zip.part: { $error }
+download-part-flush-failed = Failed to flush Luna.zip.part: { $error }
+download-part-sync-failed = Failed to sync Luna.zip.part: { $error }
+download-part-create-failed = Failed to create Luna.zip.part: { $error }
+download-finalize-failed = Failed to finalize Luna.zip: { $error }
+download-metadata-failed = Failed to verify Luna.zip metadata: { $error }
+download-size-mismatch = Written Luna.zip size mismatch: wrote { $written } bytes but expected { $expected }
+download-saved = Luna downloaded successfully to { $path } ({ $size } bytes)
+download-done = Download completed successfully
+
+step-local-archive = Use local archive
+local-archive-reading = Reading { $path }
+local-archive-read-failed = Failed to read the archive: { $error }
+local-archive-invalid = { $path } is not a usable Luna archive: { $error }
+local-archive-summary = { $files ->
+        [one] { $files } file, { $size } MB unpacked
+       *[other] { $files } files, { $size } MB unpacked
+    }
+local-archive-copied = Archive copied to { $path } ({ $size } bytes)
+local-archive-done = Local archive ready
+
+step-launch-tidal = Launch TIDAL
+launch-command = command '{ $command }'
+launch-launched = TIDAL launched detached with { $candidate }
+launch-failed = Failed launching { $candidate }: { $error }
+launch-as-user = Running as root; launching TIDAL as invoking user '{ $user }'
+launch-root-skipped = Skipping auto-launch: running as root on Linux is not supported by Electron (sandbox restriction) and no sudo/pkexec user was detected. Launch TIDAL manually as your normal user.
+launch-root-done = Installation finished; TIDAL auto-launch skipped for root Linux session
+launch-path-unresolved = Skipping launch: could not resolve TIDAL path ({ $error })
+launch-skipped = Installation finished; TIDAL auto-launch skipped
+launch-path-unavailable = Skipping launch: TIDAL resources path is not available
+launch-trying = Trying to relaunch TIDAL from { $path }
+launch-done = TIDAL relaunched successfully
+launch-no-binary = Installation finished; no runnable TIDAL binary found for auto-launch
+
+step-verify-luna = Verify Luna
+verify-startup-error = TIDAL reported a startup error ({ $marker }): { $line }
+verify-exited = TIDAL exited after { $seconds }s with { $status }
+verify-poll-failed = Failed to poll TIDAL process: { $error }
+verify-no-backup = original.asar backup not found
+verify-rollback-asar = Rolling back: restoring original app.asar
+verify-remove-asar-failed = failed to remove app.asar: { $error }
+verify-restore-asar-failed = failed to restore app.asar: { $error }
+verify-rollback-app = Rolling back: removing Luna app directory
+verify-remove-app-failed = failed to remove Luna app directory: { $error }
+verify-as-user = Running as root; verifying as invoking user '{ $user }'
+verify-root-skipped = Skipping verification: TIDAL cannot be launched as root on Linux and no sudo/pkexec user was detected. Launch TIDAL manually as your normal user.
+verify-root-done = Installation finished; verification skipped for root Linux session
+verify-path-unresolved = Could not resolve TIDAL path for verification: { $error }
+verify-log-dir-failed = Failed to create verification log directory: { $error }
+verify-log-file-failed = Failed to create verification log file: { $error }
+verify-launched = TIDAL launched with logging from { $candidate }
+verify-no-binary = No runnable TIDAL binary found to verify the installation
+verify-watching = Watching TIDAL for up to { $seconds } seconds (log: { $path })
+verify-marker-found = Luna readiness marker found in TIDAL output
+verify-done = Luna loaded successfully
+verify-no-marker = TIDAL stayed up for { $seconds } seconds, but Luna never reported that it loaded
+verify-failed = Verification failed: { $reason }. Uninstall to restore the original app.asar
+verify-rollback-failed = Verification failed: { $reason }. Rollback failed: { $error }
+verify-rolled-back = Verification failed: { $reason }. Rolled back to the original app.asar
+
+## Pipeline
+
+run-cancelled-before-step = Cancelled before this step started
+run-cancel-deferred = Cancel requested; finishing this step first so TIDAL is not left without its app. The run stops right after it.
+
+## Command line
+
+cli-runtime-failed = Failed to initialize async runtime: { $error }
+cli-legacy-with-subcommand = legacy action flags cannot be combined with a subcommand
+cli-legacy-flags-combined = Warning: combining { $flags } is deprecated; running { $used } as before. Use a subcommand instead, see --help
+cli-gui-failed = Failed to launch GUI: { $error }
+cli-banner = { -app } CLI
+cli-installer-update-available = Installer update available: v{ $current } -> v{ $latest }
+cli-installer-up-to-date = Installer is already up to date (v{ $current }).
+cli-installer-releases = Installer releases for this platform:
+cli-note-prerelease = prerelease
+cli-note-current = current
+cli-available-releases = Available releases:
+cli-channel = Channel: { $channel }
+cli-detected-installations = Detected TIDAL installations:
+cli-status-client = Client:
+cli-status-luna = TidaLuna:
+cli-status-installed-version = installed ({ $version })
+cli-status-installed = installed
+cli-status-not-installed = not installed
+cli-status-rollback = Rollback to:
+cli-status-present = present
+cli-status-backup = present (backup)
+cli-status-missing = missing
+cli-service-unit = Service unit:
+cli-timer-unit = Timer unit:
+cli-timer = Timer:
+cli-timer-enabled = enabled
+cli-timer-disabled = disabled
+cli-timer-active = active
+cli-timer-inactive = inactive
+cli-last-run = Last run:
+cli-run-install = Installing { $channel } version { $version } to { $path }
+cli-run-rollback = Rolling back Luna in { $path }
+cli-run-uninstall = Uninstalling from { $path }
+cli-step-starting = Starting step: { $name }
+cli-step-finished = Step finished successfully: { $name } - { $message }
+cli-step-failed-title = Step Failed
+cli-see-above = See above for details.
+cli-step-failed = Step '{ $step }' failed: { $message }
+cli-step-failed-banner = STEP FAILED: { $name }
+cli-summary = Summary
+cli-summary-ok = OK
+cli-summary-failed = FAILED
+cli-choose-installation = Multiple TIDAL installations were found. Please choose one path:
+cli-enter-selection = Enter selection (1-{ $count }, q to cancel):
+cli-selection-cancelled = Selection cancelled
+cli-invalid-selection = Invalid selection. Please enter a number between 1 and { $count }.
+cli-path-missing = Failed to find TIDAL resources directory: { $path } does not exist
+cli-no-flavor-installation = no { $flavor } installation detected
+cli-no-installation = Failed to find TIDAL resources directory
+cli-find-failed = Failed to find TIDAL resources directory: { $error }
+cli-target-out-of-range = { $count ->
+        [one] --target { $index } is out of range; { $count } installation detected
+       *[other] --target { $index } is out of range; { $count } installations detected
+    }
+cli-ambiguous-target = Multiple TIDAL installations found; choose one with --target <number|path|all> or --flavor: { $candidates }
+cli-read-selection-failed = Failed to read selection: { $error }
+cli-update-hint = Do you want to update? Run: tidaluna-installer self-update
+cli-update-check-failed = Installer update check failed: { $error }
+cli-installer-version-not-found = Installer version '{ $version }' not found, or it has no download for this platform
+cli-installer-already-running = Installer v{ $version } is already running.
+cli-installer-switching = Switching installer v{ $current } -> v{ $target }
+cli-installer-applying = Applying installer update to v{ $version }...
+cli-installer-apply-failed = Failed to apply installer update: { $error }
+cli-status-none = No TIDAL installation found: { $error }
+cli-already-installed = TidaLuna / Neptune is already installed. Continuing with reinstall.
+cli-no-command = No valid command provided. Use --help for usage information.
+config-using-defaults = { $error }; using default settings
+config-not-bool = { $key } must be true or false, got '{ $value }'
+config-bad-launch = launch must be always, never or verify, got '{ $value }'
+config-bad-timeout = verify_timeout must be a number of seconds, got '{ $value }'
+config-bad-proxy = invalid proxy '{ $proxy }': { $error }
+config-bad-locale = locale must be one of { $locales }, got '{ $value }'
+config-bad-theme = theme must be system, dark, light or high-contrast, got '{ $value }'
+config-unknown-key = Unknown config key '{ $key }'. Known keys: { $keys }
+config-invalid-file = Invalid config file { $path }: { $error }
+config-read-failed = Failed to read config file { $path }: { $error }
+config-no-dir = Could not determine the config directory
+config-serialize-failed = Failed to serialize config: { $error }
+config-create-dir-failed = Failed to create config directory { $path }: { $error }
+config-write-failed = Failed to write config file { $path }: { $error }
+http-invalid-proxy = Ignoring invalid proxy '{ $proxy }': { $error }
+
+## Releases
+
+release-channel-not-found = Release channel '{ $channel }' not found
+release-version-not-found = Version '{ $version }' not found
+release-none = No release found to install!
+release-channel-empty = Selected release channel '{ $channel }' has no versions
+release-version-not-in-channel = Version '{ $version }' not found in channel '{ $channel }'
+releases-load-failed = Failed to load releases: { $error }
+
+## Installer updates
+
+updater-exe-unresolved = failed to resolve current executable path: { $error }
+updater-dpkg-guidance = The installer was installed from the '{ $package }' package, so it is not replaced in place. Update it with your package manager:
+updater-no-checksums = release v{ $version } publishes no { $file } file, so the download cannot be verified
+updater-checksums-download-failed = failed to download { $file }: { $error }
+updater-checksums-status = { $file } download returned { $status }
+updater-checksums-read-failed = failed reading { $file }: { $error }
+updater-checksum-missing = { $file } has no entry for { $asset }
+updater-checksum-mismatch = checksum mismatch for { $asset }: expected { $expected }, got { $actual } (truncated or corrupted download?)
+updater-binary-start-failed = new installer binary does not start: { $error }
+updater-binary-timeout = new installer binary did not exit within { $seconds } seconds
+updater-binary-wait-failed = failed to wait for new installer binary: { $error }
+updater-smoke-test-failed = new installer binary failed its smoke test ({ $status })
+updater-wrong-version = new installer binary reports version '{ $reported }' instead of { $expected }
+updater-write-failed = failed to write update file: { $error }
+updater-permissions-failed = failed to set executable permissions: { $error }
+updater-backup-failed = failed to back up current executable: { $error }
+updater-replace-failed = failed to replace executable: { $error }
+updater-restore-failed = { $error }; restoring the previous installer also failed: { $restore_error }. It is still available at { $path }
+updater-restored = { $error }; the previous installer was restored
+updater-script-create-failed = failed to create updater script: { $error }
+updater-script-write-failed = failed to write updater script: { $error }
+updater-script-start-failed = failed to start updater script: { $error }
+updater-relaunching = Update downloaded. The installer will now close and relaunch updated.
+updater-reopen = Update downloaded. Exit and reopen the installer to use the new version.
+updater-prerelease = v{ $version } (prerelease)
+updater-client-failed = failed to create updater http client: { $error }
+updater-list-failed = failed to list installer releases: { $error }
+updater-list-status = installer release list request returned { $status }
+updater-list-parse-failed = failed to parse installer release list: { $error }
+updater-no-build = no installer build for { $os } { $arch } has been published
+updater-lookup-failed = failed to look up installer release v{ $version }: { $error }
+updater-release-status = installer release request returned { $status }
+updater-release-parse-failed = failed to parse installer release: { $error }
+updater-invalid-version = invalid current installer version: { $version }
+updater-download-failed = failed to download installer update: { $error }
+updater-download-status = installer update download returned { $status }
+updater-download-read-failed = failed reading installer update bytes: { $error }
+updater-download-empty = installer update download is empty
+updater-updated = Installer updated successfully. Please restart the installer. The previous version was kept as { $path }.
+
+## watch
+
+watch-repair-skipped = No receipt and no release list; cannot tell which Luna version to restore
+watch-client-removed = Installation was removed; no longer watching it
+watch-client-missing = Installation directory is gone, probably mid-update; waiting for it to come back
+watch-client-returned = Installation directory is back; watching it again
+watch-new-client = New TIDAL version detected
+watch-injection-lost = Luna is no longer injected
+watch-update-available = Luna { $version } is available (installed: { $installed })
+watch-not-installed = Luna is not installed in { $path }; run install first. Skipping it.
+watch-nothing = No TIDAL installation with Luna installed to watch
+watch-start-failed = Failed to start file watcher: { $error }
+watch-dir-failed = Failed to watch { $path }: { $error }
+watch-started = Watching for changes
+watch-stop-hint = Press Ctrl+C to stop.
+watch-error = File watcher error: { $error }
+watch-stopped = Stopped watching
+
+## service
+
+service-binary-missing = Installer binary { $path } does not exist
+service-reload-failed = systemctl --user daemon-reload failed: { $error }
+service-enable-failed = Units written, but enabling { $unit } failed: { $error }
+service-disable-failed = Disabling { $unit } failed: { $error }
+service-linux-only = `service` uses systemd and is only available on Linux
+service-not-as-root = Run `service` as your normal user, without sudo or pkexec
+service-no-unit-dir = Could not determine the systemd user unit directory
+service-exe-unknown = Failed to locate the installer binary: { $error }
+service-exe-unresolved = Failed to resolve installer binary { $path }: { $error }
+service-bad-interval = Invalid interval '{ $interval }'; use a systemd time span such as 30min, 6h or 1d
+service-foreign-unit = { $path } exists and was not created by the installer; not replacing it
+service-foreign-unit-kept = { $path } was not created by the installer; not removing it
+service-create-dir-failed = Failed to create { $path }: { $error }
+service-write-failed = Failed to write { $path }: { $error }
+service-remove-failed = Failed to remove { $path }: { $error }
+service-systemctl-failed = Failed to run systemctl: { $error }
+
+## diagnose
+diagnose-write-failed = Could not write the diagnostic bundle: { $error }
+diagnose-bundle-written = Diagnostic bundle written to { $path }
+diagnose-bundle-hint = Home paths and user names are redacted. Attach the file to your bug report.
+
+## Graphical interface
+
+gui-update-check-cancelled = Failed to check for installer updates: task cancelled
+gui-update-apply-cancelled = Failed to apply installer update: task cancelled
+gui-releases-cancelled = Failed to load releases: task cancelled
+gui-stargazers-cancelled = Failed to load stargazers: task cancelled
+gui-detect-cancelled = Failed to detect TIDAL paths: task cancelled
+gui-archive-cancelled = Failed to check the archive: task cancelled
+gui-step-failed = Step failed: { $name } - { $message }
+gui-no-path-selected = No TIDAL path selected
+gui-summary-ok = OK: { $path }
+gui-summary-failed = FAILED: { $path } ({ $step }: { $message })
+gui-task-stopped = Operation task stopped unexpectedly
+gui-window-title = { -app } v{ $version }
+gui-log-window-title = { -app } v{ $version } - Log
+gui-title = { -app }
+gui-subtitle = Install or remove TidaLuna from a TIDAL installation.
+gui-subsubtitle = Works for the official TIDAL app and tidal-hifi. Note: If you are using the windows store version of TIDAL, please uninstall it and install the version from the official website.
+gui-installer-version = Installer version: v{ $version }
+gui-status = Status
+gui-status-installed = Installed
+gui-status-not-installed = Not installed
+gui-release-channel = Release Channel
+gui-loading = Loading...
+gui-select-channel = Select a release channel...
+gui-version = Version
+gui-select-channel-first = Select a channel first...
+gui-select-version = Select a version...
+gui-release-title = Luna { $version }
+gui-release-prerelease = Pre-release
+gui-release-published = Published { $date }
+gui-release-upgrade = Upgrade from the installed { $installed }
+gui-release-same = Same as the installed version ({ $installed })
+gui-release-downgrade = Downgrade: { $version } is older than the installed { $installed }. Plugins and settings from the newer version may not work.
+gui-release-different = Installed: { $installed }
+gui-release-installed-unknown = Luna is installed, but its version is unknown
+gui-release-no-notes = No release notes were published for this version.
+gui-custom-path = Custom Path (optional)
+gui-custom-path-placeholder = Leave empty for default Tidal directory
+gui-detected-path = Detected TIDAL installation path
+gui-detected-path-description = Used by default. If you enter a custom path below (click advanced options first), it overrides this selection.
+gui-no-detected-paths = No detected TIDAL installation paths
+gui-apply-to-all = Apply to all { $count } detected installations
+gui-show-advanced = Show advanced options
+gui-verify-toggle = Verify Luna loads after launching TIDAL
+gui-rollback-toggle = Restore the original TIDAL automatically if verification fails
+gui-language = Language
+gui-language-system = System language
+gui-theme = Theme
+gui-theme-system = Follow the system
+gui-theme-dark = Dark
+gui-theme-light = Light
+gui-theme-high-contrast = High contrast
+gui-install = Install
+gui-reinstall = Reinstall
+gui-uninstall = Uninstall
+gui-repair = Repair
+gui-update-to = Update to { $version }
+gui-roll-back = Roll back
+gui-roll-back-to = Roll back to { $version }
+gui-cancel = Cancel
+gui-retry-from = Retry from "{ $step }"
+gui-retry-many = Retry { $count } failed installations
+gui-usage-warning = Usage Warning
+gui-usage-warning-piracy = You cannot pirate media with TidaLuna or bypass TIDAL's free-tier limitations.
+gui-usage-warning-account = Use only with a legitimate account. We will not help with issues related to piracy.
+gui-installation-log = Installation Log
+gui-clear-log = Clear Log
+gui-drop-hint = Offline? Drop a Luna .zip onto this window to install it without downloading.
+gui-drop-not-zip = Only .zip archives can be installed; ignored { $name }
+gui-drop-checking = Checking dropped archive { $path }...
+gui-archive-ready = { $name } is a valid Luna archive
+gui-archive-title = Local archive: { $name }
+gui-archive-version = Luna version: { $version }
+gui-archive-version-unknown = Luna version: not stated in the archive
+gui-archive-contents = { $files } files, { $size } MB unpacked ({ $archive_size } MB archive)
+gui-archive-entries = Contains: { $entries }
+gui-archive-entries-more = Contains: { $entries } and { $count } more
+gui-archive-install = Install from this archive
+gui-archive-discard = Discard
+gui-archive-installing = Installing Luna from { $name }...
+gui-archive-bad-path = { $path } cannot be used as an archive location
+gui-uninstall-preview-failed = Failed to check the installation: task cancelled
+gui-uninstall-preview-title = Uninstall Luna?
+gui-uninstall-preview-intro = Nothing is changed until you confirm.
+gui-uninstall-preview-no-luna = Luna is not installed here; nothing to remove
+gui-uninstall-preview-removes = Removes Luna { $version } (app/, { $files } files, { $size } MB)
+gui-uninstall-preview-restores = Restores original.asar as app.asar (TIDAL { $version })
+gui-uninstall-preview-restores-unknown = Restores original.asar as app.asar (TIDAL version unknown)
+gui-uninstall-preview-no-original = No original.asar backup found; app.asar stays as it is
+gui-uninstall-preview-replaces-previous = The kept version { $version } is replaced by the backup
+gui-uninstall-preview-keeps-previous = The kept version { $version } stays available for rollback
+gui-uninstall-preview-tidal-running = TIDAL is running and will be closed
+gui-uninstall-preview-tidal-stopped = TIDAL is not running
+gui-uninstall-preview-tidal-unknown = TIDAL will be closed if it is running
+gui-uninstall-preview-relaunch = TIDAL is started again afterwards
+gui-uninstall-keep-backup = Keep a backup so Luna can be rolled back
+gui-uninstall-wipe-data = Also delete Luna user data (settings and plugins)
+gui-uninstall-no-user-data = No Luna user data found
+gui-uninstall-wipe-data-shared = Browser databases holding Luna data are deleted whole, including what TIDAL stored in them. Cookies and Local Storage are kept.
+gui-releases-from-cache = Showing the release list saved { $date } UTC while it is refreshed
+gui-offline-banner = Offline — data from { $date } UTC
+gui-offline-hint = The release list and stargazers were saved the last time the installer was online. Installing needs a connection unless the archive is cached.
+gui-offline-retry = Retry
+gui-open-log-window = Open log window
+gui-log-cleared = Log cleared
+gui-loading-releases = Loading releases...
+gui-releases-loaded = Releases loaded successfully
+gui-downloading-update = Downloading installer update v{ $version }...
+gui-update-failed = Installer update failed: { $error }
+gui-stargazers-failed = Failed to load stargazers: { $error }
+gui-selected-channel = Selected channel: { $channel }
+gui-selected-saved-version = Selected saved version: { $version }
+gui-auto-selected-version = Auto-selected version: { $version }
+gui-selected-version = Selected version: { $version }
+gui-detected-one-path = Detected one TIDAL installation path automatically
+gui-detected-many-paths = Multiple TIDAL installations detected. Auto-selected the latest path; change it in Advanced Options if needed.
+gui-detect-failed = Could not auto-detect TIDAL paths: { $error }
+gui-no-path-hint = No TIDAL path selected. Choose one from the dropdown or enter a custom path in Advanced Options.
+gui-starting-install = Starting installation...
+gui-starting-uninstall = Starting uninstallation...
+gui-card-installing = Installing Luna { $version } into { $path }...
+gui-card-updating = Updating Luna in { $path } to { $version }...
+gui-card-repairing = Repairing Luna { $version } in { $path }...
+gui-rolling-back-log = Rolling back to the previous Luna version...
+gui-cancelling-log = Cancelling after the current step...
+gui-retrying-log = Retrying { $path } from step: { $step }
+gui-already-installed = TidaLuna is already installed
+gui-operation-succeeded = Operation completed successfully!
+gui-operation-cancelled = Operation cancelled
+gui-operation-steps-failed = Operation failed: one or more steps failed
+gui-operation-failed = Operation failed: { $error }
+gui-settings-save-failed = Could not save settings: { $error }
+gui-progress-cancelling = Cancelling...
+gui-progress-retrying = Retrying...
+gui-progress-installing = Installing...
+gui-progress-rolling-back = Rolling back...
+gui-progress-uninstalling = Uninstalling...
+gui-progress-target = Installation { $current }/{ $total }
+gui-progress-step = Step { $current }/{ $total }: { $name }
+gui-update-available = Installer Update Available
+gui-update-available-body = A newer installer version is available (running v{ $version }). Update before continuing?
+gui-updating = Updating...
+gui-install-update = Install v{ $version }
+gui-skip = Skip
+gui-skip-version = Skip this version
+gui-show-prereleases = Show prereleases
+gui-installations = Installations
+gui-no-installations = No TIDAL installation detected.
+gui-card-needs-repair = Luna: needs repair, a TIDAL update replaced it
+gui-card-luna-version = Luna: { $version } ({ $channel })
+gui-card-luna-unknown = Luna: installed, unknown version
+gui-card-luna-missing = Luna: not installed
+gui-card-backup = Backup: original.asar
+gui-card-no-backup = Backup: none
+gui-card-previous = , previous Luna { $version }
+gui-stargazers = Stargazers <3
+gui-stargazers-thanks = Thanks to everyone who starred this project.
+gui-stargazers-loading = Loading stargazers...
+gui-stargazers-error = Could not load stargazers: { $error }
+gui-stargazers-empty = No stargazers found yet.
+gui-stargazers-page = Showing { $first }-{ $last } of { $total } • Page { $page }/{ $pages }
+gui-previous = Previous
+gui-next = Next
+
+## Log window
+
+gui-log-search = Search the log...
+gui-log-save-path = File to save the log to
+gui-log-save = Save
+gui-log-copy = Copy to clipboard
+gui-log-count = { $shown } of { $total } lines
+gui-log-show-lines = Show { $count } lines
+gui-log-hide-details = Hide details
+gui-log-saved = Saved to { $path }
+gui-log-save-failed = Could not save to { $path }: { $error }
+gui-log-copied = Copied to the clipboard
+gui-diagnostics-export = Export diagnostics
+gui-diagnostics-collecting = Collecting diagnostics...
+gui-diagnostics-saved = Diagnostic bundle saved to { $path }
+gui-level-info = Info
+gui-level-success = Success
+gui-level-error = Error
+gui-level-step = Step
+gui-level-substep = Sub-step
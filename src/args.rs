@@ -1,38 +1,346 @@
-use clap::Parser;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap::error::ErrorKind;
+use crate::t;
+use crate::utils::fs_helpers::TidalFlavor;
+use crate::utils::updater::UpdateChannel;
 
 /// TidaLuna Installer CLI
 #[derive(Parser, Debug)]
 #[command(author, version, about, disable_version_flag = true)]
 pub struct Args {
-    /// Run in headless CLI mode
-    #[arg(long)]
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Output format for headless commands
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
+    /// Never prompt; fail if a choice would be needed (implied when stdin is not a terminal)
+    #[arg(short = 'y', long, visible_alias = "yes", global = true)]
+    pub non_interactive: bool,
+
+    /// Print the installer version (also `--version` without a value)
+    #[arg(short = 'V')]
+    pub print_version: bool,
+
+    /// Run in headless CLI mode (implied by any subcommand); the legacy flags need it
+    #[arg(long, hide = true)]
     pub headless: bool,
 
-    /// Install action
-    #[arg(short, long)]
+    /// Legacy alias for `install`
+    #[arg(short, long, hide = true)]
     pub install: bool,
 
-    /// Reinstall action (tries uninstall first, then installs)
-    #[arg(long)]
+    /// Legacy alias for `reinstall`
+    #[arg(long, hide = true)]
     pub reinstall: bool,
 
-    /// Uninstall action
-    #[arg(short, long)]
+    /// Legacy alias for `uninstall`
+    #[arg(short, long, hide = true)]
     pub uninstall: bool,
 
-    /// Version (optional, used with install)
-    #[arg(short, long)]
+    /// Legacy release channel for `--install` (historically named version).
+    /// Without a value it prints the installer version, which is why clap's own flag is off.
+    #[arg(short, long, hide = true, num_args = 0..=1, default_missing_value = "")]
     pub version: Option<String>,
 
-    /// Path (optional, used with install or uninstall)
-    #[arg(short, long)]
+    /// Legacy path for `--install` / `--uninstall`
+    #[arg(short, long, hide = true)]
     pub path: Option<String>,
 
-    /// List available versions
-    #[arg(short = 'l', long)]
+    /// Legacy alias for `list`
+    #[arg(short = 'l', long, hide = true)]
     pub list_versions: bool,
 
+    /// Legacy alias for `self-update`
+    #[arg(long, hide = true)]
+    pub update: bool,
+
+    /// Legacy alias for `install --verify`
+    #[arg(long, hide = true)]
+    pub verify: bool,
+
+    /// Legacy alias for `install --verify-timeout`
+    #[arg(long, hide = true, default_value_t = 20, requires = "verify")]
+    pub verify_timeout: u64,
+
+    /// Legacy alias for `install --rollback-on-failure`
+    #[arg(long, hide = true, requires = "verify")]
+    pub rollback_on_failure: bool,
+}
+
+/// `json` writes one JSON object per line, see docs/cli-json-output.md
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Install TidaLuna into a TIDAL installation (reinstalls if already present)
+    Install(InstallArgs),
+    /// Remove TidaLuna and reinstall it from scratch
+    Reinstall(InstallArgs),
+    /// Remove TidaLuna and restore the original TIDAL app.asar
+    Uninstall(TargetArgs),
+    /// Swap the previously installed Luna version back in (no download needed)
+    Rollback(TargetArgs),
+    /// List available release channels and versions
+    List(ListArgs),
+    /// Show detected TIDAL installations and whether TidaLuna is installed
+    Status,
+    /// Keep Luna installed: repair it when a TIDAL update removes it
+    Watch(WatchArgs),
+    /// Manage the systemd user timer that keeps Luna installed (Linux)
+    Service(ServiceArgs),
     /// Update the installer binary to the latest release
+    SelfUpdate(SelfUpdateArgs),
+    /// Show or change saved preferences
+    Config(ConfigArgs),
+    /// Collect installations, install history and the last run's log for a bug report
+    Diagnose(DiagnoseArgs),
+    /// Open the graphical installer
+    Gui,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct TargetArgs {
+    /// TIDAL installation or resources directory (auto-detected if omitted)
+    #[arg(short, long, conflicts_with = "target")]
+    pub path: Option<String>,
+
+    /// Installation to use: its number as listed by `status`, a path, or `all`
+    #[arg(short, long)]
+    pub target: Option<String>,
+
+    /// Act on every detected installation (same as `--target all`)
+    #[arg(long, conflicts_with_all = ["path", "target"])]
+    pub all_targets: bool,
+
+    /// Only consider detected installations of this client
+    #[arg(long, value_enum)]
+    pub flavor: Option<TidalFlavor>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct InstallArgs {
+    /// Release channel to install from, e.g. stable, beta or alpha
+    #[arg(short, long)]
+    pub channel: Option<String>,
+
+    /// Exact TidaLuna version to install (defaults to the newest in the channel)
+    #[arg(short, long)]
+    pub version: Option<String>,
+
+    #[command(flatten)]
+    pub target: TargetArgs,
+
+    /// After installing, launch TIDAL with logging and check that Luna loaded
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Seconds to watch TIDAL during verification
+    #[arg(long, default_value_t = 20, requires = "verify")]
+    pub verify_timeout: u64,
+
+    /// Restore the original app.asar automatically if verification fails
+    #[arg(long, requires = "verify")]
+    pub rollback_on_failure: bool,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct WatchArgs {
+    #[command(flatten)]
+    pub target: TargetArgs,
+
+    /// Also check for newer Luna releases every MINUTES and install them
+    #[arg(long, value_name = "MINUTES")]
+    pub check_interval: Option<u64>,
+
+    /// Seconds without filesystem changes before acting, so TIDAL updates can finish
+    #[arg(long, default_value_t = 10)]
+    pub settle_secs: u64,
+
+    /// Check and repair once, then exit (used by the systemd timer)
+    #[arg(long, conflicts_with = "check_interval")]
+    pub once: bool,
+
+    /// With --once, also install a newer Luna release if there is one
+    #[arg(long, requires = "once")]
+    pub update: bool,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct ServiceArgs {
+    #[command(subcommand)]
+    pub action: ServiceAction,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ServiceAction {
+    /// Write and enable a systemd user timer that runs `watch --once` periodically
+    Install(ServiceInstallArgs),
+    /// Disable the timer and remove the unit files
+    Uninstall,
+    /// Show whether the unit files exist and the timer is enabled
+    Status,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct ServiceInstallArgs {
+    /// How often to check, as a systemd time span such as 30min, 6h or 1d
+    #[arg(long, default_value = "6h")]
+    pub interval: String,
+
+    /// Also install newer Luna releases, not just repair the current one
     #[arg(long)]
     pub update: bool,
+
+    /// Only maintain this TIDAL installation (default: every one that has Luna)
+    #[arg(short, long)]
+    pub path: Option<String>,
+
+    /// Only maintain installations of this client
+    #[arg(long, value_enum, conflicts_with = "path")]
+    pub flavor: Option<TidalFlavor>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct ListArgs {
+    /// Only show versions from this release channel
+    #[arg(short, long)]
+    pub channel: Option<String>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct SelfUpdateArgs {
+    /// Only report whether an update is available
+    #[arg(long)]
+    pub check: bool,
+
+    /// Which installer releases to consider (default: prerelease when running one, else stable)
+    #[arg(long, value_enum)]
+    pub channel: Option<UpdateChannel>,
+
+    /// Install this exact installer version, older ones included
+    #[arg(long, value_name = "VERSION", conflicts_with = "channel")]
+    pub to: Option<String>,
+
+    /// List installer releases available for this platform
+    #[arg(long, conflicts_with_all = ["check", "channel", "to"])]
+    pub list: bool,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct DiagnoseArgs {
+    /// Write the report into this zip file instead of printing it
+    #[arg(long, value_name = "FILE")]
+    pub bundle: Option<String>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigAction {
+    /// Print every setting and the config file location
+    List,
+    /// Print one setting
+    Get { key: String },
+    /// Change one setting; an empty value resets it
+    Set { key: String, value: String },
+}
+
+impl Args {
+    /// `-V`, or `--version` without the legacy channel value
+    pub fn wants_version(&self) -> bool {
+        self.print_version || self.version.as_deref() == Some("")
+    }
+
+    /// The legacy action flags that were given, in the order the old CLI checked them
+    fn legacy_actions(&self) -> Vec<&'static str> {
+        [
+            (self.update, "--update"),
+            (self.list_versions, "--list-versions"),
+            (self.reinstall, "--reinstall"),
+            (self.install, "--install"),
+            (self.uninstall, "--uninstall"),
+        ]
+        .into_iter()
+        .filter_map(|(set, flag)| set.then_some(flag))
+        .collect()
+    }
+
+    fn legacy_target_args(&self) -> TargetArgs {
+        TargetArgs {
+            path: self.path.clone(),
+            target: None,
+            all_targets: false,
+            flavor: None,
+        }
+    }
+
+    fn legacy_install_args(&self) -> InstallArgs {
+        InstallArgs {
+            channel: self.version.clone(),
+            version: None,
+            target: self.legacy_target_args(),
+            verify: self.verify,
+            verify_timeout: self.verify_timeout,
+            rollback_on_failure: self.rollback_on_failure,
+        }
+    }
+
+    /// Resolves the subcommand to run, translating the legacy flags. `None` means open the GUI.
+    /// Like before subcommands existed, the legacy flags do nothing without `--headless`, and
+    /// of several the first one the old CLI checked wins.
+    pub fn resolve_command(&self) -> Option<Command> {
+        let legacy_actions = self.legacy_actions();
+
+        if let Some(command) = &self.command {
+            if !legacy_actions.is_empty() {
+                Self::command()
+                    .error(ErrorKind::ArgumentConflict, t!("cli-legacy-with-subcommand"))
+                    .exit();
+            }
+            return Some(command.clone());
+        }
+
+        if !self.headless {
+            return None;
+        }
+        if legacy_actions.len() > 1 {
+            eprintln!(
+                "{}",
+                t!(
+                    "cli-legacy-flags-combined",
+                    flags = legacy_actions.join(" "),
+                    used = legacy_actions[0]
+                )
+            );
+        }
+
+        if self.update {
+            Some(Command::SelfUpdate(SelfUpdateArgs {
+                check: false,
+                channel: None,
+                to: None,
+                list: false,
+            }))
+        } else if self.list_versions {
+            Some(Command::List(ListArgs { channel: None }))
+        } else if self.reinstall {
+            Some(Command::Reinstall(self.legacy_install_args()))
+        } else if self.install {
+            Some(Command::Install(self.legacy_install_args()))
+        } else if self.uninstall {
+            Some(Command::Uninstall(self.legacy_target_args()))
+        } else {
+            None
+        }
+    }
 }
@@ -1,286 +1,624 @@
-use crate::args::Args;
+mod exit_code;
+mod output;
+mod service;
+mod watch;
+
+use crate::args::{
+    Command, ConfigAction, ConfigArgs, DiagnoseArgs, InstallArgs, ListArgs, OutputFormat, SelfUpdateArgs,
+    TargetArgs,
+};
+use crate::t;
+use crate::types::types::{Release, ReleaseVersion};
 use crate::utils::{
+    config::{self, Config, LaunchPolicy, CONFIG_KEYS},
+    diagnostics,
+    receipt::{previous_install, read_receipt},
     release_loader::ReleaseLoader,
-    fs_helpers::{find_tidal_directories, is_luna_installed, normalize_tidal_resources_path},
-    updater,
+    fs_helpers::{
+        detect_tidal_flavor, find_tidal_directories, has_tidal_app_asar,
+        normalize_tidal_resources_path, TidalFlavor,
+    },
+    updater::{self, UpdateChannel},
 };
-use std::io::{self, Write};
-use std::path::PathBuf;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use semver::Version;
 
+pub use exit_code::CliExit;
+use output::{ErrorCode, InstallationStatus, Output};
+
 use crate::installer::{
-    steps::setup::SetupStep,
-    steps::download_luna::DownloadLunaStep,
-    steps::extract_luna::ExtractLunaStep,
-    steps::copy_asar_install::CopyAsarInstallStep,
-    steps::insert_luna::InsertLunaStep,
-    steps::kill_tidal::KillTidalStep,
-    steps::sign_tidal::SignTidalStep,
-    steps::launch_tidal::LaunchTidalStep,
-    steps::reinstall_cleanup::ReinstallCleanupStep,
-    steps::uninstall::UninstallStep,
-    steps::copy_asar_uninstall::CopyAsarUninstallStep,
-    manager::InstallManager,
+    manager::{InstallManager, RunOutcome},
+    pipeline::{
+        install_pipeline, kill_pipeline, rollback_pipeline, uninstall_pipeline, InstallOptions, TargetOutcome,
+        TargetOptions, VerifyOptions,
+    },
 };
 
-fn print_step_separator(step_name: &str) {
-    println!("\n{}", "=".repeat(60));
-    println!("== {} ", step_name);
-    println!("{}", "=".repeat(60));
-}
-
-fn print_failure_banner(step_name: &str, message: &str) {
-    println!("\n{}", "!".repeat(60));
-    println!("!! STEP FAILED: {} !!", step_name);
-    println!("!! {} !!", message);
-    println!("{}", "!".repeat(60));
-}
-
 fn prompt_user_for_tidal_path(paths: &[PathBuf]) -> io::Result<PathBuf> {
-    println!("Multiple TIDAL installations were found. Please choose one path:\n");
+    println!("{}\n", t!("cli-choose-installation"));
     for (index, path) in paths.iter().enumerate() {
         println!("  [{}] {}", index + 1, path.to_string_lossy());
     }
 
     loop {
-        print!("\nEnter selection (1-{}): ", paths.len());
+        print!("\n{} ", t!("cli-enter-selection", count = paths.len()));
         io::stdout().flush()?;
 
         let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
+        let read = io::stdin().read_line(&mut input)?;
 
         let trimmed = input.trim();
-        if let Ok(choice) = trimmed.parse::<usize>() {
-            if (1..=paths.len()).contains(&choice) {
-                return Ok(paths[choice - 1].clone());
-            }
+        if read == 0 || trimmed.eq_ignore_ascii_case("q") {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, t!("cli-selection-cancelled")));
+        }
+        if let Ok(choice) = trimmed.parse::<usize>()
+            && (1..=paths.len()).contains(&choice)
+        {
+            return Ok(paths[choice - 1].clone());
         }
 
-        println!("Invalid selection. Please enter a number between 1 and {}.", paths.len());
+        println!("{}", t!("cli-invalid-selection", count = paths.len()));
     }
 }
 
-async fn resolve_cli_tidal_path(user_path: &Option<String>) -> io::Result<PathBuf> {
-    if let Some(path) = user_path {
-        return Ok(normalize_tidal_resources_path(PathBuf::from(path)));
+fn explicit_tidal_path(out: &Output, path: &str) -> Result<PathBuf, CliExit> {
+    let path = normalize_tidal_resources_path(PathBuf::from(path));
+    if !path.is_dir() {
+        return Err(out.error(
+            ErrorCode::TidalNotFound,
+            &t!("cli-path-missing", path = format!("{:?}", path)),
+        ));
     }
+    Ok(path)
+}
 
-    let found_paths = find_tidal_directories().await?;
-    if found_paths.is_empty() {
-        return Err(io::Error::new(
-            io::ErrorKind::NotFound,
-            "Failed to find TIDAL resources directory",
-        ));
+/// Detected installations, in the order `status` lists them, narrowed to `flavor`
+async fn detected_tidal_paths(flavor: Option<TidalFlavor>) -> io::Result<Vec<PathBuf>> {
+    let paths: Vec<PathBuf> = find_tidal_directories()
+        .await?
+        .into_iter()
+        .filter(|path| flavor.is_none_or(|flavor| detect_tidal_flavor(path) == flavor))
+        .collect();
+
+    if paths.is_empty() {
+        if let Some(flavor) = flavor {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                t!("cli-no-flavor-installation", flavor = flavor.label()),
+            ));
+        }
+        return Err(io::Error::new(io::ErrorKind::NotFound, t!("cli-no-installation")));
+    }
+
+    Ok(paths)
+}
+
+/// Resolves which TIDAL installations a command acts on, reporting failures on `out`.
+/// Only prompts when `out` allows it; otherwise an ambiguous choice is an error.
+async fn resolve_cli_targets(
+    out: &Output,
+    args: &TargetArgs,
+    config: &Config,
+) -> Result<Vec<PathBuf>, CliExit> {
+    if let Some(path) = &args.path {
+        return explicit_tidal_path(out, path).map(|path| vec![path]);
+    }
+
+    // Saved target paths stand in for an explicit selection
+    if args.target.is_none() && !args.all_targets && args.flavor.is_none() && !config.target_paths.is_empty() {
+        return config
+            .target_paths
+            .iter()
+            .map(|path| explicit_tidal_path(out, path))
+            .collect();
     }
 
-    if found_paths.len() == 1 {
-        return Ok(found_paths[0].clone());
+    let index = match args.target.as_deref() {
+        _ if args.all_targets => None,
+        Some(target) if target.eq_ignore_ascii_case("all") => None,
+        Some(target) => match target.parse::<usize>() {
+            Ok(index) => Some(index),
+            Err(_) => return explicit_tidal_path(out, target).map(|path| vec![path]),
+        },
+        None => None,
+    };
+
+    let found_paths = match detected_tidal_paths(args.flavor).await {
+        Ok(paths) => paths,
+        Err(e) => {
+            return Err(out.error(
+                ErrorCode::TidalNotFound,
+                &t!("cli-find-failed", error = e),
+            ));
+        }
+    };
+
+    if let Some(index) = index {
+        return match found_paths.get(index.wrapping_sub(1)) {
+            Some(path) => Ok(vec![path.clone()]),
+            None => Err(out.error(
+                ErrorCode::TidalNotFound,
+                &t!("cli-target-out-of-range", index = index, count = found_paths.len()),
+            )),
+        };
     }
 
-    prompt_user_for_tidal_path(&found_paths)
+    if args.all_targets || args.target.is_some() || found_paths.len() == 1 {
+        return Ok(found_paths);
+    }
+
+    if !out.is_interactive() {
+        let candidates: Vec<String> = found_paths
+            .iter()
+            .enumerate()
+            .map(|(index, path)| format!("[{}] {}", index + 1, path.to_string_lossy()))
+            .collect();
+        return Err(out.error(
+            ErrorCode::AmbiguousTarget,
+            &t!("cli-ambiguous-target", candidates = candidates.join(", ")),
+        ));
+    }
+
+    match prompt_user_for_tidal_path(&found_paths) {
+        Ok(path) => Ok(vec![path]),
+        Err(e) if e.kind() == io::ErrorKind::Interrupted => {
+            Err(out.error(ErrorCode::Cancelled, &e.to_string()))
+        }
+        Err(e) => Err(out.error(
+            ErrorCode::TidalNotFound,
+            &t!("cli-read-selection-failed", error = e),
+        )),
+    }
 }
 
-pub async fn run_cli(args: Args) {
-    println!("TidaLuna Installer CLI\n");
+fn parse_release_version(value: &str) -> Version {
+    Version::parse(value.trim_start_matches('v')).unwrap_or_else(|_| Version::new(0, 0, 0))
+}
 
-    let current_version = updater::current_installer_version();
-    let available_update = match updater::check_for_update(&current_version).await {
-        Ok(update) => update,
-        Err(error) => {
-            eprintln!("Installer update check failed: {}", error);
-            None
+/// Picks the release to install: an exact version if given, otherwise the newest
+/// version of the requested channel, falling back to stable > beta > alpha
+fn select_release<'a>(
+    releases: &'a [Release],
+    channel: Option<&str>,
+    version: Option<&str>,
+) -> Result<(&'a Release, &'a ReleaseVersion), String> {
+    let channels: Vec<&Release> = match channel {
+        Some(name) => {
+            let release = releases
+                .iter()
+                .find(|r| r.name.eq_ignore_ascii_case(name))
+                .ok_or_else(|| t!("release-channel-not-found", channel = name))?;
+            vec![release]
         }
+        None => releases.iter().collect(),
     };
 
-    if let Some(update) = &available_update {
-        println!(
-            "Installer update available: v{} -> v{}",
-            current_version, update.version
-        );
-        println!("Do you want to update? Run with --headless --update\n");
+    if let Some(wanted) = version {
+        let wanted = wanted.trim_start_matches('v');
+        return channels
+            .iter()
+            .find_map(|release| {
+                release
+                    .versions
+                    .iter()
+                    .find(|v| v.version.trim_start_matches('v') == wanted)
+                    .map(|v| (*release, v))
+            })
+            .ok_or_else(|| t!("release-version-not-found", version = wanted));
     }
 
-    if args.update {
-        match available_update {
-            Some(update) => {
-                println!("Applying installer update to v{}...", update.version);
-                match updater::apply_update(&update.download_url, false).await {
-                    Ok(result) => {
-                        println!("{}", result.message);
-                        if result.should_exit {
-                            return;
-                        }
-                    }
-                    Err(error) => {
-                        eprintln!("Failed to apply installer update: {}", error);
-                    }
-                }
+    let selected_release = if channel.is_some() {
+        channels.first().copied()
+    } else {
+        ["stable", "beta", "alpha"]
+            .iter()
+            .find_map(|name| releases.iter().find(|r| r.name == *name))
+    }
+    .ok_or_else(|| t!("release-none"))?;
+
+    // Pick the newest version using semver
+    let latest_version = selected_release
+        .versions
+        .iter()
+        .max_by(|a, b| parse_release_version(&a.version).cmp(&parse_release_version(&b.version)))
+        .ok_or_else(|| t!("release-channel-empty", channel = selected_release.name))?;
+
+    Ok((selected_release, latest_version))
+}
+
+async fn load_releases(out: &Output, config: &Config) -> Result<Vec<Release>, CliExit> {
+    let mut loader = ReleaseLoader::new(config.sources_url());
+
+    match loader.load_releases().await {
+        Ok(releases) => Ok(releases.clone()),
+        Err(e) => Err(out.error(
+            ErrorCode::ReleasesUnavailable,
+            &t!("releases-load-failed", error = e),
+        )),
+    }
+}
+
+/// Verification as configured by the `launch` setting
+fn configured_verify(config: &Config) -> Option<VerifyOptions> {
+    (config.launch == LaunchPolicy::Verify).then_some(VerifyOptions {
+        timeout_secs: config.verify_timeout,
+        rollback_on_failure: config.rollback_on_verify_failure,
+    })
+}
+
+async fn run_pipeline(out: &Output, manager: InstallManager) -> (RunOutcome, CliExit) {
+    let outcome = manager.run(|event| out.event(&event)).await;
+    let exit = out.result(&outcome);
+    (outcome, exit)
+}
+
+/// Runs `build(path, kill_tidal)` for every target. With several targets TIDAL is stopped
+/// once up front, every target is attempted, and a summary follows. Returns the first failure.
+async fn run_targets(
+    out: &Output,
+    paths: Vec<PathBuf>,
+    build: impl Fn(&Output, &Path, bool) -> InstallManager,
+) -> CliExit {
+    if let [path] = paths.as_slice() {
+        return run_pipeline(out, build(out, path, true)).await.1;
+    }
+
+    let (_, mut exit) = run_pipeline(out, kill_pipeline()).await;
+    let mut outcomes = Vec::new();
+    for path in paths {
+        let (outcome, result) = run_pipeline(out, build(out, &path, false)).await;
+        if exit == CliExit::Success {
+            exit = result;
+        }
+        outcomes.push(TargetOutcome { path, outcome });
+    }
+
+    out.summary(&outcomes);
+    exit
+}
+
+async fn notify_installer_update(out: &Output, current_version: &str) {
+    match updater::check_for_update(current_version, UpdateChannel::for_version(current_version)).await {
+        Ok(Some(update)) => {
+            out.installer_update(current_version, Some(&update.version));
+            if !out.is_json() {
+                println!("{}\n", t!("cli-update-hint"));
             }
-            None => {
-                println!("Installer is already up to date (v{}).", current_version);
+        }
+        Ok(None) => {
+            if out.is_json() {
+                out.installer_update(current_version, None);
             }
         }
-        return;
+        Err(error) => out.warn(&t!("cli-update-check-failed", error = error)),
     }
+}
 
-    // Initialize release loader
-    let mut loader = ReleaseLoader::new(
-        "https://raw.githubusercontent.com/jxnxsdev/TidaLuna-Installer/main/resources/sources.json",
-    );
-
-    // Load releases from sources
-    let releases = match loader.load_releases().await {
-        Ok(r) => r,
-        Err(e) => {
-            eprintln!("Failed to load releases: {}", e);
-            return;
+async fn run_self_update(out: &Output, args: SelfUpdateArgs) -> CliExit {
+    let current_version = updater::current_installer_version();
+    let releases = match updater::list_releases().await {
+        Ok(releases) => releases,
+        Err(error) => {
+            return out.error(
+                ErrorCode::UpdateCheckFailed,
+                &t!("cli-update-check-failed", error = error),
+            );
         }
     };
 
-    // LIST VERSIONS
-    if args.list_versions {
-        println!("Available releases:\n");
-        for release in releases.iter() {
-            println!("Channel: {}", release.name);
-            for version in &release.versions {
-                println!("  - {} ({})", version.version, version.download);
+    if args.list {
+        out.installer_releases(&current_version, &releases);
+        return CliExit::Success;
+    }
+
+    // Versions older than the release list reaches are looked up by tag
+    let older_release = match &args.to {
+        Some(version) if updater::find_release(&releases, version).is_none() => match updater::fetch_release(version).await {
+            Ok(release) => release,
+            Err(error) => {
+                return out.error(
+                    ErrorCode::UpdateCheckFailed,
+                    &t!("cli-update-check-failed", error = error),
+                );
+            }
+        },
+        _ => None,
+    };
+
+    let update = match &args.to {
+        Some(version) => {
+            let Some(release) = updater::find_release(&releases, version).or(older_release.as_ref()) else {
+                return out.error(
+                    ErrorCode::ReleaseNotFound,
+                    &t!("cli-installer-version-not-found", version = version),
+                );
+            };
+            if release.version == current_version.trim_start_matches('v') {
+                out.info(&t!("cli-installer-already-running", version = current_version));
+                return CliExit::Success;
             }
+            out.info(&t!("cli-installer-switching", current = current_version, target = release.version));
+            release
+        }
+        None => {
+            let channel = args
+                .channel
+                .unwrap_or_else(|| UpdateChannel::for_version(&current_version));
+            let update = updater::newest_update(&releases, &current_version, channel);
+            out.installer_update(&current_version, update.map(|u| u.version.as_str()));
+            let Some(update) = update else {
+                return CliExit::Success;
+            };
+            update
         }
-        return;
+    };
+
+    if args.check {
+        return CliExit::Success;
     }
 
-    // INSTALL
-    if args.install || args.reinstall {
-        let mut reinstall_mode = args.reinstall;
+    out.info(&t!("cli-installer-applying", version = update.version));
+    match updater::apply_update(update, false).await {
+        Ok(result) => {
+            out.info(&result.message);
+            CliExit::Success
+        }
+        Err(error) => out.error(
+            ErrorCode::UpdateFailed,
+            &t!("cli-installer-apply-failed", error = error),
+        ),
+    }
+}
+
+async fn run_list(out: &Output, args: ListArgs, config: &Config) -> CliExit {
+    let releases = match load_releases(out, config).await {
+        Ok(releases) => releases,
+        Err(exit) => return exit,
+    };
+
+    let releases: Vec<&Release> = releases
+        .iter()
+        .filter(|release| {
+            args.channel
+                .as_ref()
+                .is_none_or(|channel| release.name.eq_ignore_ascii_case(channel))
+        })
+        .collect();
+
+    out.releases(&releases);
+    CliExit::Success
+}
 
-        if is_luna_installed().await.unwrap_or(false) {
-            println!("TidaLuna / Neptune is already installed. Continuing with reinstall.");
-            reinstall_mode = true;
+async fn run_status(out: &Output) -> CliExit {
+    let paths = match detected_tidal_paths(None).await {
+        Ok(paths) => paths,
+        Err(e) => {
+            return out.error(ErrorCode::TidalNotFound, &t!("cli-status-none", error = e));
         }
+    };
+
+    let installations: Vec<InstallationStatus> = paths
+        .iter()
+        .enumerate()
+        .map(|(index, path)| InstallationStatus {
+            index: index + 1,
+            path,
+            flavor: detect_tidal_flavor(path),
+            luna_installed: path.join("app").is_dir(),
+            luna_version: read_receipt(path).map(|receipt| receipt.version),
+            previous_version: previous_install(path).map(|previous| previous.version_label()),
+            app_asar: has_tidal_app_asar(path),
+            original_asar: path.join("original.asar").is_file(),
+        })
+        .collect();
+
+    out.installations(&installations);
+    CliExit::Success
+}
 
-        let version_to_install = args.version.clone();
+async fn run_install(out: &Output, args: InstallArgs, force_reinstall: bool, config: &Config) -> CliExit {
+    let releases = match load_releases(out, config).await {
+        Ok(releases) => releases,
+        Err(exit) => return exit,
+    };
 
-        // Determine release channel if no version provided: stable > beta > alpha
-        let selected_release = if let Some(ver) = &version_to_install {
-            releases.iter().find(|r| r.name == *ver)
-        } else {
-            releases.iter().find(|r| r.name == "stable")
-                .or_else(|| releases.iter().find(|r| r.name == "beta"))
-                .or_else(|| releases.iter().find(|r| r.name == "alpha"))
-        };
+    // A saved version only applies together with the saved channel
+    let (channel, version) = match &args.channel {
+        Some(channel) => (Some(channel), args.version.as_ref()),
+        None => (config.channel.as_ref(), args.version.as_ref().or(config.version.as_ref())),
+    };
 
-        let selected_release = match selected_release {
-            Some(r) => r,
-            None => {
-                eprintln!("No release found to install!");
-                return;
-            }
+    let (selected_release, selected_version) =
+        match select_release(&releases, channel.map(String::as_str), version.map(String::as_str)) {
+            Ok(selection) => selection,
+            Err(e) => return out.error(ErrorCode::ReleaseNotFound, &e),
         };
 
-        // Pick the newest version using semver
-        let latest_version = selected_release
-            .versions
-            .iter()
-            .max_by(|a, b| {
-                let va = Version::parse(&a.version).unwrap_or_else(|_| Version::new(0, 0, 0));
-                let vb = Version::parse(&b.version).unwrap_or_else(|_| Version::new(0, 0, 0));
-                va.cmp(&vb)
-            });
-
-        let latest_version = match latest_version {
-            Some(version) => version,
-            None => {
-                eprintln!("Selected release channel '{}' has no versions", selected_release.name);
-                return;
-            }
-        };
+    // Determine install paths
+    let paths = match resolve_cli_targets(out, &args.target, config).await {
+        Ok(paths) => paths,
+        Err(exit) => return exit,
+    };
 
-        // Determine install path
-        let path: PathBuf = match resolve_cli_tidal_path(&args.path).await {
-            Ok(path) => path,
-            Err(e) => {
-                eprintln!("Failed to find TIDAL resources directory: {}", e);
-                return;
-            }
-        };
+    let download_url = selected_version.download.clone();
+    let verify = if args.verify {
+        Some(VerifyOptions {
+            timeout_secs: args.verify_timeout,
+            rollback_on_failure: args.rollback_on_failure,
+        })
+    } else {
+        configured_verify(config)
+    };
+
+    run_targets(out, paths, |out, path, kill_tidal| {
+        let already_installed = path.join("app").exists();
+        if already_installed {
+            out.info(&t!("cli-already-installed"));
+        }
+        let reinstall = force_reinstall || already_installed;
 
-        println!(
-            "\nInstalling {} version {} to {:?}\n",
-            selected_release.name, latest_version.version, path
+        out.run_started(
+            "install",
+            path,
+            Some((&selected_release.name, &selected_version.version)),
+            reinstall,
         );
 
-        let mut manager = InstallManager::new();
-        manager.add_step(Box::new(KillTidalStep));
-        if reinstall_mode {
-            manager.add_step(Box::new(ReinstallCleanupStep { overwrite_path: Some(path.clone()) }));
-        }
-        manager.add_step(Box::new(SetupStep { overwrite_path: Some(path.clone()) }));
-        manager.add_step(Box::new(DownloadLunaStep { download_url: latest_version.download.clone() }));
-        manager.add_step(Box::new(ExtractLunaStep));
-        manager.add_step(Box::new(CopyAsarInstallStep { overwrite_path: Some(path.clone()) }));
-        manager.add_step(Box::new(InsertLunaStep { overwrite_path: Some(path.clone()) }));
-        manager.add_step(Box::new(SignTidalStep));
-        manager.add_step(Box::new(LaunchTidalStep {
-            overwrite_path: Some(path.clone()),
+        install_pipeline(&InstallOptions {
+            path: path.to_path_buf(),
+            channel: selected_release.name.clone(),
+            version: selected_version.version.clone(),
+            download_url: download_url.clone(),
+            reinstall,
+            refresh_asar_backup: false,
             suppress_console_window: false,
-        }));
-
-        // Run steps with nice console output
-        manager.run(
-            |sublog| println!("    {}", sublog),
-            |steplog| println!("{}", steplog),
-            |step_name| print_step_separator(&step_name),
-            |success| {
-                if !success {
-                    print_failure_banner("Step Failed", "See above for details.");
-                }
-            },
-        ).await;
-
-        return;
-    }
+            verify: verify.clone(),
+            launch_tidal: config.launch != LaunchPolicy::Never,
+            kill_tidal,
+            cache_dir: config.archive_cache_dir(),
+            download_progress: None,
+            cancel: None,
+        })
+    })
+    .await
+}
 
-    // UNINSTALL
-    if args.uninstall {
-        let path: PathBuf = match resolve_cli_tidal_path(&args.path).await {
-            Ok(path) => path,
-            Err(e) => {
-                eprintln!("Failed to find TIDAL resources directory: {}", e);
-                return;
-            }
-        };
+async fn run_uninstall(out: &Output, args: TargetArgs, config: &Config) -> CliExit {
+    let paths = match resolve_cli_targets(out, &args, config).await {
+        Ok(paths) => paths,
+        Err(exit) => return exit,
+    };
 
-        println!("\nUninstalling from {:?}\n", path);
+    run_targets(out, paths, |out, path, kill_tidal| {
+        out.run_started("uninstall", path, None, false);
+        uninstall_pipeline(&TargetOptions {
+            path: path.to_path_buf(),
+            suppress_console_window: false,
+            launch_tidal: config.launch != LaunchPolicy::Never,
+            kill_tidal,
+            keep_backup: false,
+            wipe_user_data: false,
+        })
+    })
+    .await
+}
 
-        let mut manager = InstallManager::new();
-        manager.add_step(Box::new(KillTidalStep));
-        manager.add_step(Box::new(CopyAsarUninstallStep { overwrite_path: Some(path.clone()) }));
-        manager.add_step(Box::new(UninstallStep { overwrite_path: Some(path.clone()) }));
-        manager.add_step(Box::new(SignTidalStep));
-        manager.add_step(Box::new(LaunchTidalStep {
-            overwrite_path: Some(path.clone()),
+async fn run_rollback(out: &Output, args: TargetArgs, config: &Config) -> CliExit {
+    let paths = match resolve_cli_targets(out, &args, config).await {
+        Ok(paths) => paths,
+        Err(exit) => return exit,
+    };
+
+    run_targets(out, paths, |out, path, kill_tidal| {
+        out.run_started("rollback", path, None, false);
+        rollback_pipeline(&TargetOptions {
+            path: path.to_path_buf(),
             suppress_console_window: false,
-        }));
-
-        manager.run(
-            |sublog| println!("    {}", sublog),
-            |steplog| {
-                if steplog.contains("failed") || steplog.contains("Failed") {
-                    print_failure_banner("Step Failed", &steplog);
-                } else {
-                    println!("{}", steplog);
-                }
-            },
-            |step_name| print_step_separator(&step_name),
-            |success| {
-                if !success {
-                    print_failure_banner("Step Failed", "See above for details.");
-                }
-            },
-        ).await;
-
-        return;
+            launch_tidal: config.launch != LaunchPolicy::Never,
+            kill_tidal,
+            keep_backup: false,
+            wipe_user_data: false,
+        })
+    })
+    .await
+}
+
+fn run_config(out: &Output, args: ConfigArgs) -> CliExit {
+    // Unlike other commands a broken file is an error here, so `set` never overwrites it
+    let mut config = match config::load() {
+        Ok(config) => config,
+        Err(e) => return out.error(ErrorCode::InvalidConfig, &e),
+    };
+    let path = config::config_path();
+
+    match args.action {
+        ConfigAction::List => {
+            let values: Vec<(&str, String)> = CONFIG_KEYS
+                .iter()
+                .map(|key| (*key, config.get(key).unwrap_or_default()))
+                .collect();
+            out.config_values(path.as_deref(), &values);
+            CliExit::Success
+        }
+        ConfigAction::Get { key } => match config.get(&key) {
+            Ok(value) => {
+                out.config_values(None, &[(key.as_str(), value)]);
+                CliExit::Success
+            }
+            Err(e) => out.error(ErrorCode::InvalidConfig, &e),
+        },
+        ConfigAction::Set { key, value } => {
+            if let Err(e) = config.set(&key, &value) {
+                return out.error(ErrorCode::InvalidConfig, &e);
+            }
+            if let Err(e) = config::save(&config) {
+                return out.error(ErrorCode::InvalidConfig, &e);
+            }
+            out.config_values(None, &[(key.as_str(), config.get(&key).unwrap_or_default())]);
+            CliExit::Success
+        }
     }
+}
 
-    println!("No valid command provided. Use --help for usage information.");
+async fn run_diagnose(out: &Output, args: DiagnoseArgs) -> CliExit {
+    let files = diagnostics::collect().await;
+    let bundle = args.bundle.map(PathBuf::from);
+    if let Some(bundle) = &bundle
+        && let Err(err) = diagnostics::write_bundle(bundle, &files)
+    {
+        return out.error(ErrorCode::DiagnoseFailed, &err);
+    }
+    out.diagnostics(bundle.as_deref(), &files);
+    CliExit::Success
+}
+
+pub async fn run_cli(command: Option<Command>, format: OutputFormat, non_interactive: bool) -> CliExit {
+    // Prompts need a person at a terminal; JSON consumers and pipes never get one
+    let interactive = !non_interactive && format == OutputFormat::Text && io::stdin().is_terminal();
+    let out = Output::new(format, interactive);
+    // `config get` output is meant to be captured by scripts
+    if !matches!(command, Some(Command::Config(_))) {
+        out.banner();
+    }
+
+    let Some(command) = command else {
+        out.info(&t!("cli-no-command"));
+        return CliExit::Failure;
+    };
+
+    match command {
+        Command::SelfUpdate(args) => return run_self_update(&out, args).await,
+        Command::Config(args) => return run_config(&out, args),
+        Command::Service(args) => return service::run_service(&out, args),
+        // Works offline and with a broken config, which is when it is needed most
+        Command::Diagnose(args) => return run_diagnose(&out, args).await,
+        _ => {}
+    }
+
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            out.warn(&t!("config-using-defaults", error = e));
+            Config::default()
+        }
+    };
+
+    // Rollback is meant to work offline, so it skips the update check
+    if !matches!(command, Command::Rollback(_)) {
+        notify_installer_update(&out, &updater::current_installer_version()).await;
+    }
+
+    match command {
+        Command::Install(args) => run_install(&out, args, false, &config).await,
+        Command::Reinstall(args) => run_install(&out, args, true, &config).await,
+        Command::Uninstall(args) => run_uninstall(&out, args, &config).await,
+        Command::Rollback(args) => run_rollback(&out, args, &config).await,
+        Command::List(args) => run_list(&out, args, &config).await,
+        Command::Status => run_status(&out).await,
+        Command::Watch(args) => watch::run_watch(&out, args, &config).await,
+        Command::SelfUpdate(_) | Command::Config(_) | Command::Service(_) | Command::Diagnose(_) | Command::Gui => {
+            CliExit::Success
+        }
+    }
 }
@@ -0,0 +1,68 @@
+use std::process::ExitCode;
+
+/// Process exit status of a headless run. The numbers are part of the CLI
+/// contract, see docs/cli-exit-codes.md.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliExit {
+    Success,
+    Failure,
+    Network,
+    TidalNotFound,
+    ReleaseNotFound,
+    AmbiguousTarget,
+    InvalidConfig,
+    StepFailed(String),
+    VerificationFailed,
+    Cancelled,
+}
+
+/// Exit codes for failed pipeline steps, keyed by step id
+const STEP_EXIT_CODES: &[(&str, u8)] = &[
+    ("kill_tidal", 20),
+    ("reinstall_cleanup", 21),
+    ("setup", 22),
+    ("download_luna", 23),
+    ("extract_luna", 24),
+    ("copy_asar_install", 25),
+    ("insert_luna", 26),
+    ("restore_ownership", 27),
+    ("sign_tidal", 28),
+    ("launch_tidal", 29),
+    ("restore_original_asar", 30),
+    ("uninstall_luna", 31),
+    ("write_receipt", 32),
+    ("rollback_luna", 33),
+    ("local_archive", 34),
+    ("wipe_luna_data", 35),
+];
+
+/// Used for a failed step that has no entry in `STEP_EXIT_CODES`
+const UNKNOWN_STEP_EXIT_CODE: u8 = 39;
+
+impl CliExit {
+    pub fn code(&self) -> u8 {
+        match self {
+            CliExit::Success => 0,
+            CliExit::Failure => 1,
+            // 2 is reserved for usage errors reported by clap
+            CliExit::Network => 3,
+            CliExit::TidalNotFound => 4,
+            CliExit::ReleaseNotFound => 5,
+            CliExit::AmbiguousTarget => 6,
+            CliExit::InvalidConfig => 7,
+            CliExit::StepFailed(step_id) => STEP_EXIT_CODES
+                .iter()
+                .find(|(id, _)| id == step_id)
+                .map(|(_, code)| *code)
+                .unwrap_or(UNKNOWN_STEP_EXIT_CODE),
+            CliExit::VerificationFailed => 40,
+            CliExit::Cancelled => 130,
+        }
+    }
+}
+
+impl From<CliExit> for ExitCode {
+    fn from(exit: CliExit) -> Self {
+        ExitCode::from(exit.code())
+    }
+}
@@ -0,0 +1,526 @@
+use super::exit_code::CliExit;
+use crate::args::OutputFormat;
+use crate::installer::manager::{InstallEvent, RunOutcome, StepInfo};
+use crate::installer::pipeline::TargetOutcome;
+use crate::t;
+use crate::types::types::Release;
+use crate::utils::fs_helpers::TidalFlavor;
+use crate::utils::diagnostics::ReportFile;
+use crate::utils::history::RunRecorder;
+use crate::utils::updater::UpdateInfo;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// Bumped whenever a JSON line changes in a way existing consumers could notice.
+/// See docs/cli-json-output.md.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Stable error codes emitted in `error` lines
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    ReleasesUnavailable,
+    ReleaseNotFound,
+    TidalNotFound,
+    AmbiguousTarget,
+    UpdateCheckFailed,
+    UpdateFailed,
+    StepFailed,
+    VerificationFailed,
+    Cancelled,
+    InvalidConfig,
+    WatchFailed,
+    ServiceFailed,
+    DiagnoseFailed,
+}
+
+impl ErrorCode {
+    fn exit(self) -> CliExit {
+        match self {
+            ErrorCode::ReleasesUnavailable | ErrorCode::UpdateCheckFailed => CliExit::Network,
+            ErrorCode::ReleaseNotFound => CliExit::ReleaseNotFound,
+            ErrorCode::TidalNotFound => CliExit::TidalNotFound,
+            ErrorCode::AmbiguousTarget => CliExit::AmbiguousTarget,
+            ErrorCode::Cancelled => CliExit::Cancelled,
+            ErrorCode::InvalidConfig => CliExit::InvalidConfig,
+            ErrorCode::VerificationFailed => CliExit::VerificationFailed,
+            ErrorCode::UpdateFailed
+            | ErrorCode::StepFailed
+            | ErrorCode::WatchFailed
+            | ErrorCode::ServiceFailed
+            | ErrorCode::DiagnoseFailed => CliExit::Failure,
+        }
+    }
+}
+
+/// Detected TIDAL installation as reported by `status`
+pub struct InstallationStatus<'a> {
+    /// One-based, matches `--target <number>`
+    pub index: usize,
+    pub path: &'a Path,
+    pub flavor: TidalFlavor,
+    pub luna_installed: bool,
+    /// From the install receipt
+    pub luna_version: Option<String>,
+    /// Version in the rollback slot, if any
+    pub previous_version: Option<String>,
+    pub app_asar: bool,
+    pub original_asar: bool,
+}
+
+/// Unit files and timer state reported by `service`
+pub struct ServiceStatus<'a> {
+    pub action: &'a str,
+    pub unit_dir: &'a Path,
+    /// Path and what happened to it (`created`, `unchanged`, `removed`, `installed`, ...)
+    pub service: (&'a Path, &'a str),
+    pub timer: (&'a Path, &'a str),
+    pub enabled: bool,
+    pub active: bool,
+    /// systemd's `Result` of the last run, e.g. `success` or `exit-code`
+    pub last_result: Option<&'a str>,
+}
+
+/// Writes CLI output either as human-readable text or as NDJSON on stdout
+pub struct Output {
+    format: OutputFormat,
+    interactive: bool,
+    /// Pipeline runs also go into the install history and last-run.log
+    recorder: RunRecorder,
+}
+
+impl Output {
+    pub fn new(format: OutputFormat, interactive: bool) -> Self {
+        Self {
+            format,
+            interactive,
+            recorder: RunRecorder::new("cli"),
+        }
+    }
+
+    /// Whether the user may be asked to choose between options
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.format == OutputFormat::Json
+    }
+
+    fn emit(&self, kind: &str, fields: Value) {
+        let mut line = json!({
+            "schema_version": JSON_SCHEMA_VERSION,
+            "type": kind,
+        });
+        if let (Some(line), Value::Object(fields)) = (line.as_object_mut(), fields) {
+            line.extend(fields);
+        }
+        println!("{}", line);
+    }
+
+    pub fn banner(&self) {
+        if !self.is_json() {
+            println!("{}\n", t!("cli-banner"));
+        }
+    }
+
+    pub fn info(&self, message: &str) {
+        if self.is_json() {
+            self.emit("message", json!({ "level": "info", "message": message }));
+        } else {
+            println!("{}", message);
+        }
+    }
+
+    pub fn warn(&self, message: &str) {
+        if self.is_json() {
+            self.emit("message", json!({ "level": "warning", "message": message }));
+        } else {
+            eprintln!("{}", message);
+        }
+    }
+
+    /// Reports a failure and returns the exit status it maps to
+    pub fn error(&self, code: ErrorCode, message: &str) -> CliExit {
+        self.report_error(code, code.exit(), message)
+    }
+
+    fn report_error(&self, code: ErrorCode, exit: CliExit, message: &str) -> CliExit {
+        if self.is_json() {
+            self.emit(
+                "error",
+                json!({ "code": code, "exit_code": exit.code(), "message": message }),
+            );
+        } else {
+            eprintln!("{}", message);
+        }
+        exit
+    }
+
+    pub fn installer_update(&self, current_version: &str, latest_version: Option<&str>) {
+        if self.is_json() {
+            self.emit(
+                "installer_update",
+                json!({
+                    "current_version": current_version,
+                    "latest_version": latest_version,
+                    "update_available": latest_version.is_some(),
+                }),
+            );
+            return;
+        }
+
+        match latest_version {
+            Some(latest) => println!("{}", t!("cli-installer-update-available", current = current_version, latest = latest)),
+            None => println!("{}", t!("cli-installer-up-to-date", current = current_version)),
+        }
+    }
+
+    pub fn installer_releases(&self, current_version: &str, releases: &[UpdateInfo]) {
+        if self.is_json() {
+            let releases: Vec<Value> = releases
+                .iter()
+                .map(|release| json!({ "version": release.version, "prerelease": release.prerelease }))
+                .collect();
+            self.emit(
+                "installer_releases",
+                json!({ "current_version": current_version, "releases": releases }),
+            );
+            return;
+        }
+
+        println!("{}\n", t!("cli-installer-releases"));
+        for release in releases {
+            let mut notes = Vec::new();
+            if release.prerelease {
+                notes.push(t!("cli-note-prerelease"));
+            }
+            if release.version == current_version.trim_start_matches('v') {
+                notes.push(t!("cli-note-current"));
+            }
+            if notes.is_empty() {
+                println!("  v{}", release.version);
+            } else {
+                println!("  v{} ({})", release.version, notes.join(", "));
+            }
+        }
+    }
+
+    pub fn releases(&self, releases: &[&Release]) {
+        if self.is_json() {
+            self.emit("releases", json!({ "releases": releases }));
+            return;
+        }
+
+        println!("{}\n", t!("cli-available-releases"));
+        for release in releases {
+            println!("{}", t!("cli-channel", channel = release.name));
+            for version in &release.versions {
+                println!("  - {} ({})", version.version, version.download);
+            }
+        }
+    }
+
+    pub fn installations(&self, installations: &[InstallationStatus]) {
+        if self.is_json() {
+            let installations: Vec<Value> = installations
+                .iter()
+                .map(|installation| {
+                    json!({
+                        "index": installation.index,
+                        "path": installation.path,
+                        "flavor": installation.flavor,
+                        "luna_installed": installation.luna_installed,
+                        "luna_version": installation.luna_version,
+                        "previous_version": installation.previous_version,
+                        "app_asar": installation.app_asar,
+                        "original_asar": installation.original_asar,
+                    })
+                })
+                .collect();
+            self.emit("installations", json!({ "installations": installations }));
+            return;
+        }
+
+        println!("{}\n", t!("cli-detected-installations"));
+        for installation in installations {
+            println!("[{}] {}", installation.index, installation.path.to_string_lossy());
+            println!("  {:<17}{}", t!("cli-status-client"), installation.flavor.label());
+            match (&installation.luna_version, installation.luna_installed) {
+                (Some(version), true) => println!(
+                    "  {:<17}{}",
+                    t!("cli-status-luna"),
+                    t!("cli-status-installed-version", version = version)
+                ),
+                (None, true) => println!("  {:<17}{}", t!("cli-status-luna"), t!("cli-status-installed")),
+                (_, false) => println!("  {:<17}{}", t!("cli-status-luna"), t!("cli-status-not-installed")),
+            }
+            if let Some(previous) = &installation.previous_version {
+                println!("  {:<17}{}", t!("cli-status-rollback"), previous);
+            }
+            println!(
+                "  {:<17}{}",
+                "app.asar:",
+                if installation.app_asar { t!("cli-status-present") } else { t!("cli-status-missing") }
+            );
+            println!(
+                "  {:<17}{}",
+                "original.asar:",
+                if installation.original_asar { t!("cli-status-backup") } else { t!("cli-status-missing") }
+            );
+        }
+    }
+
+    /// Reports what `watch` noticed; `event` is a stable identifier for scripts
+    pub fn watch(&self, event: &str, path: Option<&Path>, message: &str) {
+        if self.is_json() {
+            self.emit("watch", json!({ "event": event, "path": path, "message": message }));
+            return;
+        }
+
+        match path {
+            Some(path) => println!("[watch] {}: {:?}", message, path),
+            None => println!("[watch] {}", message),
+        }
+    }
+
+    pub fn service(&self, status: &ServiceStatus) {
+        if self.is_json() {
+            self.emit(
+                "service",
+                json!({
+                    "action": status.action,
+                    "unit_dir": status.unit_dir,
+                    "units": [
+                        { "path": status.service.0, "state": status.service.1 },
+                        { "path": status.timer.0, "state": status.timer.1 },
+                    ],
+                    "enabled": status.enabled,
+                    "active": status.active,
+                    "last_result": status.last_result,
+                }),
+            );
+            return;
+        }
+
+        println!("{:<14}{:?} ({})", t!("cli-service-unit"), status.service.0, status.service.1);
+        println!("{:<14}{:?} ({})", t!("cli-timer-unit"), status.timer.0, status.timer.1);
+        if status.action != "uninstall" {
+            println!(
+                "{:<14}{}, {}",
+                t!("cli-timer"),
+                if status.enabled { t!("cli-timer-enabled") } else { t!("cli-timer-disabled") },
+                if status.active { t!("cli-timer-active") } else { t!("cli-timer-inactive") }
+            );
+        }
+        if let Some(result) = status.last_result {
+            println!("{:<14}{}", t!("cli-last-run"), result);
+        }
+    }
+
+    /// Announces a pipeline run; `release` is `(channel, version)` for installs
+    pub fn run_started(&self, action: &str, path: &Path, release: Option<(&str, &str)>, reinstall: bool) {
+        self.recorder.start(action, path, release);
+        if self.is_json() {
+            self.emit(
+                "run_started",
+                json!({
+                    "action": action,
+                    "path": path,
+                    "channel": release.map(|(channel, _)| channel),
+                    "version": release.map(|(_, version)| version),
+                    "reinstall": reinstall,
+                }),
+            );
+            return;
+        }
+
+        match release {
+            Some((channel, version)) => {
+                println!(
+                    "\n{}\n",
+                    t!("cli-run-install", channel = channel, version = version, path = format!("{:?}", path))
+                )
+            }
+            None if action == "rollback" => {
+                println!("\n{}\n", t!("cli-run-rollback", path = format!("{:?}", path)))
+            }
+            None => println!("\n{}\n", t!("cli-run-uninstall", path = format!("{:?}", path))),
+        }
+    }
+
+    pub fn event(&self, event: &InstallEvent) {
+        self.recorder.event(event);
+        if self.is_json() {
+            match event {
+                InstallEvent::StepStarted(step) => self.emit("step_started", step_json(step)),
+                InstallEvent::SubLog { step, message } => {
+                    let mut fields = step_json(step);
+                    fields["message"] = json!(message);
+                    self.emit("step_log", fields);
+                }
+                InstallEvent::StepFinished { step, success, message } => {
+                    let mut fields = step_json(step);
+                    fields["success"] = json!(success);
+                    fields["message"] = json!(message);
+                    self.emit("step_finished", fields);
+                }
+            }
+            return;
+        }
+
+        match event {
+            InstallEvent::StepStarted(step) => {
+                println!("\n{}", "=".repeat(60));
+                println!("== {} ", step.name);
+                println!("{}", "=".repeat(60));
+                println!("{}", t!("cli-step-starting", name = step.name));
+            }
+            InstallEvent::SubLog { message, .. } => println!("    {}", message),
+            InstallEvent::StepFinished { step, success: true, message } => {
+                println!("{}", t!("cli-step-finished", name = step.name, message = message))
+            }
+            InstallEvent::StepFinished { step, success: false, message } => {
+                print_failure_banner(&step.name, message)
+            }
+        }
+    }
+
+    pub fn result(&self, outcome: &RunOutcome) -> CliExit {
+        self.recorder.finish(outcome);
+        if self.is_json() {
+            let failed_step = outcome.failed_step.as_ref().map(|failed| {
+                let mut fields = step_json(&failed.step);
+                fields["message"] = json!(failed.message);
+                fields
+            });
+            self.emit(
+                "result",
+                json!({ "success": outcome.success(), "failed_step": failed_step }),
+            );
+        }
+
+        let Some(failed) = &outcome.failed_step else {
+            return CliExit::Success;
+        };
+
+        if !self.is_json() {
+            print_failure_banner(&t!("cli-step-failed-title"), &t!("cli-see-above"));
+        }
+
+        let (code, exit) = if failed.step.id == "verify_luna" {
+            (ErrorCode::VerificationFailed, CliExit::VerificationFailed)
+        } else {
+            (ErrorCode::StepFailed, CliExit::StepFailed(failed.step.id.clone()))
+        };
+
+        if self.is_json() {
+            self.report_error(
+                code,
+                exit,
+                &t!("cli-step-failed", step = failed.step.id, message = failed.message),
+            )
+        } else {
+            exit
+        }
+    }
+
+    /// Config entries; `path` is only given for `config list`
+    pub fn config_values(&self, path: Option<&Path>, values: &[(&str, String)]) {
+        if self.is_json() {
+            let values: serde_json::Map<String, Value> = values
+                .iter()
+                .map(|(key, value)| (key.to_string(), json!(value)))
+                .collect();
+            self.emit("config", json!({ "path": path, "values": values }));
+            return;
+        }
+
+        if let Some(path) = path {
+            println!("# {}", path.to_string_lossy());
+        }
+        for (key, value) in values {
+            println!("{} = {}", key, value);
+        }
+    }
+
+    /// The report of `diagnose`: where the bundle went, or every file printed in full
+    pub fn diagnostics(&self, bundle: Option<&Path>, files: &[ReportFile]) {
+        if self.is_json() {
+            let contents: serde_json::Map<String, Value> = files
+                .iter()
+                .map(|file| (file.name.to_string(), json!(file.contents)))
+                .collect();
+            self.emit("diagnostics", json!({ "bundle": bundle, "files": contents }));
+            return;
+        }
+
+        match bundle {
+            Some(bundle) => {
+                println!("{}", t!("diagnose-bundle-written", path = format!("{:?}", bundle)));
+                for file in files {
+                    println!("    {}", file.name);
+                }
+                println!("{}", t!("diagnose-bundle-hint"));
+            }
+            None => {
+                for file in files {
+                    println!("===== {} =====\n{}", file.name, file.contents);
+                }
+            }
+        }
+    }
+
+    /// Per-target results at the end of a multi-target run
+    pub fn summary(&self, targets: &[TargetOutcome]) {
+        if self.is_json() {
+            let targets: Vec<Value> = targets
+                .iter()
+                .map(|target| {
+                    json!({
+                        "path": target.path,
+                        "success": target.outcome.success(),
+                        "failed_step": target.outcome.failed_step.as_ref().map(|failed| failed.step.id.clone()),
+                    })
+                })
+                .collect();
+            self.emit("summary", json!({ "targets": targets }));
+            return;
+        }
+
+        println!("\n{}", "=".repeat(60));
+        println!("== {}", t!("cli-summary"));
+        println!("{}", "=".repeat(60));
+        for target in targets {
+            match &target.outcome.failed_step {
+                None => println!("  {:<8}{}", t!("cli-summary-ok"), target.path.to_string_lossy()),
+                Some(failed) => println!(
+                    "  {:<8}{} ({}: {})",
+                    t!("cli-summary-failed"),
+                    target.path.to_string_lossy(),
+                    failed.step.name,
+                    failed.message
+                ),
+            }
+        }
+    }
+}
+
+fn step_json(step: &StepInfo) -> Value {
+    json!({
+        "step": {
+            "index": step.index,
+            "total": step.total,
+            "id": step.id,
+            "name": step.name,
+        }
+    })
+}
+
+fn print_failure_banner(step_name: &str, message: &str) {
+    println!("\n{}", "!".repeat(60));
+    println!("!! {} !!", t!("cli-step-failed-banner", name = step_name));
+    println!("!! {} !!", message);
+    println!("{}", "!".repeat(60));
+}
@@ -0,0 +1,178 @@
+use super::output::{ErrorCode, Output, ServiceStatus};
+use super::{explicit_tidal_path, CliExit};
+use crate::args::{ServiceAction, ServiceArgs, ServiceInstallArgs};
+use crate::t;
+use crate::utils::{
+    invoking_user::invoking_user,
+    systemd::{self, ServiceOptions, UnitFileState, SERVICE_UNIT, TIMER_UNIT},
+};
+use clap::ValueEnum;
+use std::path::{Path, PathBuf};
+
+struct UnitPaths {
+    dir: PathBuf,
+    service: PathBuf,
+    timer: PathBuf,
+}
+
+/// `enabled` and `active` as reported by systemd; both false when it cannot be asked
+fn timer_state() -> (bool, bool) {
+    let enabled = systemd::systemctl(&["is-enabled", TIMER_UNIT]).is_ok_and(|state| state == "enabled");
+    let active = systemd::systemctl(&["is-active", TIMER_UNIT]).is_ok_and(|state| state == "active");
+    (enabled, active)
+}
+
+fn install(out: &Output, units: &UnitPaths, args: ServiceInstallArgs) -> CliExit {
+    if let Err(e) = systemd::validate_interval(&args.interval) {
+        return out.error(ErrorCode::ServiceFailed, &e);
+    }
+    let exe = match systemd::installer_exe() {
+        Ok(exe) => exe,
+        Err(e) => return out.error(ErrorCode::ServiceFailed, &e),
+    };
+    if !exe.is_file() {
+        return out.error(ErrorCode::ServiceFailed, &t!("service-binary-missing", path = format!("{:?}", exe)));
+    }
+
+    let mut target_args = Vec::new();
+    if let Some(path) = &args.path {
+        let path = match explicit_tidal_path(out, path) {
+            Ok(path) => path,
+            Err(exit) => return exit,
+        };
+        target_args.push("--path".to_string());
+        target_args.push(path.to_string_lossy().into_owned());
+    }
+    if let Some(value) = args.flavor.and_then(|flavor| flavor.to_possible_value()) {
+        target_args.push("--flavor".to_string());
+        target_args.push(value.get_name().to_string());
+    }
+
+    let options = ServiceOptions {
+        exe,
+        interval: args.interval.trim().to_string(),
+        update: args.update,
+        target_args,
+    };
+
+    let mut states = Vec::new();
+    for (path, contents) in [
+        (&units.service, systemd::service_unit(&options)),
+        (&units.timer, systemd::timer_unit(&options)),
+    ] {
+        match systemd::write_unit(path, &contents) {
+            Ok(state) => states.push(state),
+            Err(e) => return out.error(ErrorCode::ServiceFailed, &e),
+        }
+    }
+
+    let changed = states.iter().any(|state| *state != UnitFileState::Unchanged);
+    if changed && let Err(e) = systemd::systemctl(&["daemon-reload"]) {
+        return out.error(ErrorCode::ServiceFailed, &t!("service-reload-failed", error = e));
+    }
+    if let Err(e) = systemd::systemctl(&["enable", "--now", TIMER_UNIT]) {
+        return out.error(
+            ErrorCode::ServiceFailed,
+            &t!("service-enable-failed", unit = TIMER_UNIT, error = e),
+        );
+    }
+
+    let (enabled, active) = timer_state();
+    out.service(&ServiceStatus {
+        action: "install",
+        unit_dir: &units.dir,
+        service: (&units.service, states[0].as_str()),
+        timer: (&units.timer, states[1].as_str()),
+        enabled,
+        active,
+        last_result: None,
+    });
+    CliExit::Success
+}
+
+fn uninstall(out: &Output, units: &UnitPaths) -> CliExit {
+    if units.timer.exists() {
+        // Fails harmlessly when the timer was never loaded
+        if let Err(e) = systemd::systemctl(&["disable", "--now", TIMER_UNIT]) {
+            out.warn(&t!("service-disable-failed", unit = TIMER_UNIT, error = e));
+        }
+    }
+
+    let mut states = Vec::new();
+    for path in [&units.timer, &units.service] {
+        match systemd::remove_unit(path) {
+            Ok(state) => states.push(state),
+            Err(e) => return out.error(ErrorCode::ServiceFailed, &e),
+        }
+    }
+
+    if states.contains(&UnitFileState::Removed)
+        && let Err(e) = systemd::systemctl(&["daemon-reload"])
+    {
+        out.warn(&t!("service-reload-failed", error = e));
+    }
+
+    out.service(&ServiceStatus {
+        action: "uninstall",
+        unit_dir: &units.dir,
+        service: (&units.service, states[1].as_str()),
+        timer: (&units.timer, states[0].as_str()),
+        enabled: false,
+        active: false,
+        last_result: None,
+    });
+    CliExit::Success
+}
+
+fn file_state(path: &Path) -> &'static str {
+    if path.exists() { "installed" } else { "missing" }
+}
+
+fn status(out: &Output, units: &UnitPaths) -> CliExit {
+    let (enabled, active) = timer_state();
+    let last_result = units
+        .service
+        .exists()
+        .then(|| systemd::systemctl(&["show", SERVICE_UNIT, "--property=Result", "--value"]).ok())
+        .flatten()
+        .filter(|result| !result.is_empty());
+
+    out.service(&ServiceStatus {
+        action: "status",
+        unit_dir: &units.dir,
+        service: (&units.service, file_state(&units.service)),
+        timer: (&units.timer, file_state(&units.timer)),
+        enabled,
+        active,
+        last_result: last_result.as_deref(),
+    });
+    CliExit::Success
+}
+
+pub(super) fn run_service(out: &Output, args: ServiceArgs) -> CliExit {
+    if std::env::consts::OS != "linux" {
+        return out.error(ErrorCode::ServiceFailed, &t!("service-linux-only"));
+    }
+    // Under sudo the units would land in root's systemd instance instead of the user's
+    if invoking_user().is_some() {
+        return out.error(
+            ErrorCode::ServiceFailed,
+            &t!("service-not-as-root"),
+        );
+    }
+
+    let Some(dir) = systemd::unit_dir() else {
+        return out.error(ErrorCode::ServiceFailed, &t!("service-no-unit-dir"));
+    };
+    let units = UnitPaths {
+        service: dir.join(SERVICE_UNIT),
+        timer: dir.join(TIMER_UNIT),
+        dir,
+    };
+
+    match args.action {
+        ServiceAction::Install(install_args) => install(out, &units, install_args),
+        ServiceAction::Uninstall => uninstall(out, &units),
+        ServiceAction::Status => status(out, &units),
+    }
+}
@@ -0,0 +1,441 @@
+use super::output::{ErrorCode, Output};
+use super::{
+    configured_verify, detected_tidal_paths, load_releases, parse_release_version, resolve_cli_targets,
+    run_pipeline, select_release, CliExit,
+};
+use crate::args::WatchArgs;
+use crate::installer::pipeline::{install_pipeline, InstallOptions};
+use crate::t;
+use crate::utils::{
+    config::{Config, LaunchPolicy},
+    fs_helpers::detect_tidal_flavor,
+    receipt::{patched_install, patched_installs, read_receipt, record_patched, InstallReceipt},
+};
+use notify::{RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Interval;
+
+/// A resources directory that had Luna when it was first seen
+struct WatchedTarget {
+    path: PathBuf,
+    /// Missing for installs made before receipts existed
+    receipt: Option<InstallReceipt>,
+    /// Selected, configured or recorded rather than auto-detected: kept while its directory
+    /// is gone, since client updates remove it for a moment
+    pinned: bool,
+    /// The directory does not exist right now
+    missing: bool,
+}
+
+/// Luna loads only while `app/` exists and TIDAL's own app.asar is out of the way
+fn luna_active(path: &Path) -> bool {
+    path.join("app").is_dir() && !path.join("app.asar").exists()
+}
+
+/// `<base>/app-x.y.z/resources` -> `<base>`, for clients that install each version side by side
+fn versioned_base(resources: &Path) -> Option<&Path> {
+    let version_dir = resources.parent()?;
+    let name = version_dir.file_name()?.to_str()?;
+    if name.starts_with("app-") {
+        version_dir.parent()
+    } else {
+        None
+    }
+}
+
+/// Directories whose changes can mean the injection is gone or a new client version appeared.
+/// For a missing target that is the closest directory that still exists, to see it come back.
+fn watch_dirs(targets: &[WatchedTarget]) -> HashSet<PathBuf> {
+    let mut dirs = HashSet::new();
+    for target in targets {
+        if !target.missing {
+            dirs.insert(target.path.clone());
+        } else if let Some(dir) = target.path.ancestors().skip(1).find(|dir| dir.is_dir()) {
+            dirs.insert(dir.to_path_buf());
+        }
+        if let Some(base) = versioned_base(&target.path) {
+            dirs.insert(base.to_path_buf());
+        }
+    }
+    dirs
+}
+
+/// The paths to watch and whether each is pinned, see `WatchedTarget::pinned`
+async fn initial_targets(out: &Output, args: &WatchArgs, config: &Config) -> Result<Vec<(PathBuf, bool)>, CliExit> {
+    let selection = &args.target;
+    // Without a selection every installation that has Luna is watched, untouched clients are left alone
+    if selection.path.is_none() && selection.target.is_none() && !selection.all_targets && config.target_paths.is_empty() {
+        // Recorded installs count even when a client update replaced their resources directory
+        // and took `app/` with it, which is exactly when they need a repair
+        let recorded: Vec<PathBuf> = patched_installs()
+            .into_iter()
+            .map(|install| install.path)
+            .filter(|path| path.is_dir() && selection.flavor.is_none_or(|flavor| detect_tidal_flavor(path) == flavor))
+            .collect();
+
+        let mut paths: Vec<(PathBuf, bool)> = match detected_tidal_paths(selection.flavor).await {
+            Ok(paths) => paths.into_iter().map(|path| (path, false)).collect(),
+            Err(_) if !recorded.is_empty() => Vec::new(),
+            Err(e) => return Err(out.error(ErrorCode::TidalNotFound, &t!("cli-find-failed", error = e))),
+        };
+        for path in recorded {
+            match paths.iter_mut().find(|(detected, _)| *detected == path) {
+                Some((_, pinned)) => *pinned = true,
+                None => paths.push((path, true)),
+            }
+        }
+        return Ok(paths);
+    }
+    let paths = resolve_cli_targets(out, selection, config).await?;
+    Ok(paths.into_iter().map(|path| (path, true)).collect())
+}
+
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Waits until the filesystem has been quiet for `settle`, dropping the queued events
+async fn settle<T>(rx: &mut mpsc::UnboundedReceiver<T>, settle: Duration) {
+    loop {
+        tokio::time::sleep(settle).await;
+        let mut drained = false;
+        while rx.try_recv().is_ok() {
+            drained = true;
+        }
+        if !drained {
+            return;
+        }
+    }
+}
+
+struct Watch<'a> {
+    out: &'a Output,
+    config: &'a Config,
+    targets: Vec<WatchedTarget>,
+    /// Whether any repair or update failed, for `--once`
+    failed: bool,
+    /// Directories that lost Luna, possibly by being replaced; their watches must be renewed
+    rearm: Vec<PathBuf>,
+}
+
+impl Watch<'_> {
+    /// Reinstalls Luna from the receipt's archive, which the download cache usually still has
+    async fn repair(&mut self, index: usize) {
+        let out = self.out;
+        let config = self.config;
+        let path = self.targets[index].path.clone();
+
+        let receipt = match self.targets[index].receipt.clone() {
+            Some(receipt) => receipt,
+            None => match self.latest_receipt().await {
+                Some(receipt) => receipt,
+                None => {
+                    out.watch("repair_skipped", Some(&path), &t!("watch-repair-skipped"));
+                    self.failed = true;
+                    return;
+                }
+            },
+        };
+
+        out.run_started("repair", &path, Some((&receipt.channel, &receipt.version)), false);
+        let manager = install_pipeline(&InstallOptions {
+            path: path.clone(),
+            channel: receipt.channel.clone(),
+            version: receipt.version.clone(),
+            download_url: receipt.download_url.clone(),
+            reinstall: false,
+            refresh_asar_backup: true,
+            suppress_console_window: false,
+            verify: configured_verify(config),
+            launch_tidal: config.launch != LaunchPolicy::Never,
+            kill_tidal: true,
+            cache_dir: config.archive_cache_dir(),
+            download_progress: None,
+            cancel: None,
+        });
+        let (outcome, _) = run_pipeline(out, manager).await;
+        if outcome.success() {
+            self.targets[index].receipt = read_receipt(&path).or(Some(receipt));
+        } else {
+            self.failed = true;
+        }
+    }
+
+    /// The newest release of the configured channel, for installs without a receipt
+    async fn latest_receipt(&self) -> Option<InstallReceipt> {
+        let releases = load_releases(self.out, self.config).await.ok()?;
+        let (release, version) = select_release(
+            &releases,
+            self.config.channel.as_deref(),
+            self.config.version.as_deref(),
+        )
+        .ok()?;
+        Some(InstallReceipt::new(&release.name, &version.version, &version.download))
+    }
+
+    /// Follows clients that install each update into a new `app-x.y.z` directory, and
+    /// pinned targets whose directory an update removed and put back
+    async fn refresh_targets(&mut self) {
+        let out = self.out;
+        let rearm = &mut self.rearm;
+        self.targets.retain_mut(|target| {
+            let exists = target.path.is_dir();
+            if exists && target.missing {
+                out.watch("client_returned", Some(&target.path), &t!("watch-client-returned"));
+                target.missing = false;
+                rearm.push(target.path.clone());
+            } else if !exists && !target.missing {
+                if !target.pinned {
+                    out.watch("client_removed", Some(&target.path), &t!("watch-client-removed"));
+                    return false;
+                }
+                out.watch("client_missing", Some(&target.path), &t!("watch-client-missing"));
+                target.missing = true;
+                rearm.push(target.path.clone());
+            }
+            true
+        });
+
+        let Ok(detected) = detected_tidal_paths(None).await else {
+            return;
+        };
+        for path in detected {
+            if self.targets.iter().any(|target| target.path == path) {
+                continue;
+            }
+            let Some(base) = versioned_base(&path) else {
+                continue;
+            };
+            let siblings: Vec<&WatchedTarget> = self
+                .targets
+                .iter()
+                .filter(|target| versioned_base(&target.path) == Some(base))
+                .collect();
+            if siblings.is_empty() {
+                continue;
+            }
+            // Carry over the newest receipt among the versions installed next to it
+            let sibling_receipt = siblings
+                .iter()
+                .filter_map(|target| target.receipt.clone())
+                .max_by_key(|receipt| receipt.installed_at);
+
+            out.watch("new_client", Some(&path), &t!("watch-new-client"));
+            self.targets.push(WatchedTarget {
+                receipt: read_receipt(&path).or(sibling_receipt),
+                path,
+                pinned: false,
+                missing: false,
+            });
+        }
+    }
+
+    async fn check_targets(&mut self) {
+        self.refresh_targets().await;
+        for index in 0..self.targets.len() {
+            if self.targets[index].missing || luna_active(&self.targets[index].path) {
+                continue;
+            }
+            let path = self.targets[index].path.clone();
+            self.out.watch("injection_lost", Some(&path), &t!("watch-injection-lost"));
+            self.rearm.push(path);
+            self.repair(index).await;
+        }
+    }
+
+    /// Installs a newer Luna release into every watched target that is behind
+    async fn check_for_release(&mut self) {
+        let out = self.out;
+        let config = self.config;
+        let Ok(releases) = load_releases(out, config).await else {
+            return;
+        };
+
+        for index in 0..self.targets.len() {
+            if self.targets[index].missing {
+                continue;
+            }
+            let Some(receipt) = self.targets[index].receipt.clone() else {
+                continue;
+            };
+            let channel = config.channel.as_deref().unwrap_or(&receipt.channel);
+            let Ok((release, version)) = select_release(&releases, Some(channel), config.version.as_deref()) else {
+                continue;
+            };
+            if parse_release_version(&version.version) <= parse_release_version(&receipt.version) {
+                continue;
+            }
+
+            let path = self.targets[index].path.clone();
+            out.watch(
+                "update_available",
+                Some(&path),
+                &t!("watch-update-available", version = version.version, installed = receipt.version),
+            );
+            out.run_started("install", &path, Some((&release.name, &version.version)), true);
+            let manager = install_pipeline(&InstallOptions {
+                path: path.clone(),
+                channel: release.name.clone(),
+                version: version.version.clone(),
+                download_url: version.download.clone(),
+                reinstall: true,
+                refresh_asar_backup: false,
+                suppress_console_window: false,
+                verify: configured_verify(config),
+                launch_tidal: config.launch != LaunchPolicy::Never,
+                kill_tidal: true,
+                cache_dir: config.archive_cache_dir(),
+                download_progress: None,
+                cancel: None,
+            });
+            let (outcome, _) = run_pipeline(out, manager).await;
+            if outcome.success() {
+                self.targets[index].receipt = read_receipt(&path);
+            } else {
+                self.failed = true;
+            }
+        }
+    }
+}
+
+pub(super) async fn run_watch(out: &Output, args: WatchArgs, config: &Config) -> CliExit {
+    let paths = match initial_targets(out, &args, config).await {
+        Ok(paths) => paths,
+        Err(exit) => return exit,
+    };
+
+    let targets: Vec<WatchedTarget> = paths
+        .into_iter()
+        .filter_map(|(path, pinned)| {
+            let recorded = patched_install(&path);
+            if path.join("app").is_dir() {
+                let receipt = read_receipt(&path).or_else(|| recorded.and_then(|recorded| recorded.receipt));
+                // Installs from before the state dir existed are recorded now, so a later
+                // update that replaces the resources directory can still be repaired
+                let _ = record_patched(&path, receipt.clone());
+                Some(WatchedTarget {
+                    path,
+                    receipt,
+                    pinned,
+                    missing: false,
+                })
+            } else if let Some(recorded) = recorded {
+                // Patched before and `app/` is gone: check_targets reports and repairs it
+                Some(WatchedTarget {
+                    missing: !path.is_dir(),
+                    path,
+                    receipt: recorded.receipt,
+                    pinned: true,
+                })
+            } else {
+                out.warn(&t!("watch-not-installed", path = format!("{:?}", path)));
+                None
+            }
+        })
+        .collect();
+
+    if targets.is_empty() {
+        return out.error(ErrorCode::TidalNotFound, &t!("watch-nothing"));
+    }
+
+    let mut watch = Watch {
+        out,
+        config,
+        targets,
+        failed: false,
+        rearm: Vec::new(),
+    };
+
+    if args.once {
+        watch.check_targets().await;
+        if args.update {
+            watch.check_for_release().await;
+        }
+        return if watch.failed { CliExit::Failure } else { CliExit::Success };
+    }
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut watcher = match notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let _ = tx.send(event);
+    }) {
+        Ok(watcher) => watcher,
+        Err(e) => return out.error(ErrorCode::WatchFailed, &t!("watch-start-failed", error = e)),
+    };
+
+    let mut watched = HashSet::new();
+    for dir in watch_dirs(&watch.targets) {
+        if let Err(e) = watcher.watch(&dir, RecursiveMode::NonRecursive) {
+            return out.error(ErrorCode::WatchFailed, &t!("watch-dir-failed", path = format!("{:?}", dir), error = e));
+        }
+        out.watch("started", Some(&dir), &t!("watch-started"));
+        watched.insert(dir);
+    }
+    if !out.is_json() {
+        println!("{}\n", t!("watch-stop-hint"));
+    }
+
+    let mut release_check = args.check_interval.map(|minutes| {
+        let mut interval = tokio::time::interval(Duration::from_secs(minutes.max(1) * 60));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        interval
+    });
+    let settle_time = Duration::from_secs(args.settle_secs);
+
+    // Catch anything that happened while the installer was not running
+    watch.check_targets().await;
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            Some(event) = rx.recv() => {
+                if let Err(e) = event {
+                    out.warn(&t!("watch-error", error = e));
+                    continue;
+                }
+                settle(&mut rx, settle_time).await;
+                watch.check_targets().await;
+                // Events caused by the repair itself are not worth another pass
+                settle(&mut rx, settle_time).await;
+            }
+            _ = tick(&mut release_check) => {
+                watch.check_for_release().await;
+                settle(&mut rx, settle_time).await;
+            }
+        }
+
+        // A replaced directory is a new inode, and the old watch went away with the old one
+        for dir in std::mem::take(&mut watch.rearm) {
+            if watched.remove(&dir) {
+                let _ = watcher.unwatch(&dir);
+            }
+        }
+        let dirs = watch_dirs(&watch.targets);
+        for dir in watched.difference(&dirs).cloned().collect::<Vec<_>>() {
+            let _ = watcher.unwatch(&dir);
+            watched.remove(&dir);
+        }
+        for dir in dirs {
+            if watched.contains(&dir) {
+                continue;
+            }
+            match watcher.watch(&dir, RecursiveMode::NonRecursive) {
+                Ok(()) => {
+                    out.watch("started", Some(&dir), &t!("watch-started"));
+                    watched.insert(dir);
+                }
+                Err(e) => out.warn(&t!("watch-dir-failed", path = format!("{:?}", dir), error = e)),
+            }
+        }
+    }
+
+    out.watch("stopped", None, &t!("watch-stopped"));
+    CliExit::Success
+}
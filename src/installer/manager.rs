@@ -1,4 +1,64 @@
 use crate::installer::step::{InstallStep, SubLog};
+use crate::t;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Identifies a step within a running pipeline
+#[derive(Debug, Clone)]
+pub struct StepInfo {
+    /// Zero-based position in the pipeline
+    pub index: usize,
+    pub total: usize,
+    pub id: String,
+    pub name: String,
+}
+
+/// Progress reported while a pipeline runs
+#[derive(Debug, Clone)]
+pub enum InstallEvent {
+    StepStarted(StepInfo),
+    SubLog { step: StepInfo, message: String },
+    StepFinished { step: StepInfo, success: bool, message: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct FailedStep {
+    pub step: StepInfo,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RunOutcome {
+    /// The step that failed, or the one that was next when the run was cancelled
+    pub failed_step: Option<FailedStep>,
+    pub cancelled: bool,
+}
+
+impl RunOutcome {
+    pub fn success(&self) -> bool {
+        self.failed_step.is_none()
+    }
+}
+
+/// Shared between a run and whoever may stop it. A run checks it before every step;
+/// steps that hold it (the download) also stop early.
+#[derive(Debug, Clone, Default)]
+pub struct CancelFlag(Arc<AtomicBool>);
+
+impl CancelFlag {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Re-arms the flag for another run of the same pipeline
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
 
 pub struct InstallManager {
     pub steps: Vec<Box<dyn InstallStep + Send + Sync>>,
@@ -13,39 +73,76 @@ impl InstallManager {
         self.steps.push(step);
     }
 
-    pub async fn run(
+    pub async fn run(&self, on_event: impl Fn(InstallEvent) + Send + Sync) -> RunOutcome {
+        self.run_from(0, None, on_event).await
+    }
+
+    /// Runs the steps from `start` on. Earlier steps are assumed done, which holds when
+    /// the same manager already ran them, since steps keep their results on disk.
+    pub async fn run_from(
         &self,
-        sublog_cb: impl Fn(String) + Send + Sync,
-        steplog_cb: impl Fn(String) + Send + Sync,
-        on_step_start_cb: impl Fn(String) + Send + Sync,
-        on_step_end_cb: impl Fn(bool) + Send + Sync,
-    ) {
-        for step in &self.steps {
-            on_step_start_cb(step.name().to_string());
-            steplog_cb(format!("Starting step: {}", step.name()));
+        start: usize,
+        cancel: Option<&CancelFlag>,
+        on_event: impl Fn(InstallEvent) + Send + Sync,
+    ) -> RunOutcome {
+        let total = self.steps.len();
+
+        for (index, step) in self.steps.iter().enumerate().skip(start) {
+            let info = StepInfo {
+                index,
+                total,
+                id: step.id().to_string(),
+                name: step.name(),
+            };
+
+            let cancelled = cancel.is_some_and(CancelFlag::is_cancelled);
+            if cancelled && step.cancellable() {
+                return RunOutcome {
+                    failed_step: Some(FailedStep {
+                        step: info,
+                        message: t!("run-cancelled-before-step"),
+                    }),
+                    cancelled: true,
+                };
+            }
+
+            on_event(InstallEvent::StepStarted(info.clone()));
+            if cancelled {
+                on_event(InstallEvent::SubLog {
+                    step: info.clone(),
+                    message: t!("run-cancel-deferred"),
+                });
+            }
 
             let result = step
                 .run(&|sublog: SubLog| {
-                    sublog_cb(format!("[{}] {}", step.name(), sublog.message))
+                    on_event(InstallEvent::SubLog {
+                        step: info.clone(),
+                        message: sublog.message,
+                    })
                 })
                 .await;
 
-            let message = if result.success {
-                format!(
-                    "Step finished successfully: {} - {}",
-                    step.name(),
-                    result.message
-                )
-            } else {
-                format!("Step failed: {} - {}", step.name(), result.message)
-            };
-
-            steplog_cb(message);
-            on_step_end_cb(result.success);
+            on_event(InstallEvent::StepFinished {
+                step: info.clone(),
+                success: result.success,
+                message: result.message.clone(),
+            });
 
             if !result.success {
-                break;
+                return RunOutcome {
+                    failed_step: Some(FailedStep {
+                        step: info,
+                        message: result.message,
+                    }),
+                    cancelled: cancel.is_some_and(CancelFlag::is_cancelled),
+                };
             }
         }
+
+        RunOutcome {
+            failed_step: None,
+            cancelled: false,
+        }
     }
 }
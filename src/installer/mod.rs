@@ -1,3 +1,5 @@
 pub mod manager;
+pub mod pipeline;
 pub mod step;
-pub mod steps;
\ No newline at end of file
+pub mod steps;
+pub mod work_dir;
@@ -0,0 +1,291 @@
+use std::path::{Path, PathBuf};
+
+use crate::installer::{
+    manager::{CancelFlag, InstallManager, RunOutcome},
+    steps::copy_asar_install::CopyAsarInstallStep,
+    steps::copy_asar_uninstall::CopyAsarUninstallStep,
+    steps::download_luna::{DownloadLunaStep, DownloadProgress},
+    steps::extract_luna::ExtractLunaStep,
+    steps::insert_luna::InsertLunaStep,
+    steps::local_archive::LocalArchiveStep,
+    steps::kill_tidal::KillTidalStep,
+    steps::launch_tidal::LaunchTidalStep,
+    steps::reinstall_cleanup::ReinstallCleanupStep,
+    steps::restore_ownership::RestoreOwnershipStep,
+    steps::setup::SetupStep,
+    steps::sign_tidal::SignTidalStep,
+    steps::uninstall::UninstallStep,
+    steps::rollback_luna::RollbackLunaStep,
+    steps::verify_luna::VerifyLunaStep,
+    steps::wipe_luna_data::WipeLunaDataStep,
+    steps::write_receipt::WriteReceiptStep,
+    work_dir::WorkDir,
+};
+use crate::utils::receipt::InstallReceipt;
+
+#[derive(Debug, Clone)]
+pub struct VerifyOptions {
+    pub timeout_secs: u64,
+    pub rollback_on_failure: bool,
+}
+
+/// Everything needed to assemble the install pipeline for one TIDAL installation
+#[derive(Debug, Clone)]
+pub struct InstallOptions {
+    pub path: PathBuf,
+    pub channel: String,
+    pub version: String,
+    /// A `file://` URL installs an archive from disk, see `local_archive_url`
+    pub download_url: String,
+    pub reinstall: bool,
+    /// Repair after a TIDAL update: the current app.asar replaces the original.asar backup
+    pub refresh_asar_backup: bool,
+    pub suppress_console_window: bool,
+    /// Launches and checks TIDAL instead of just launching it
+    pub verify: Option<VerifyOptions>,
+    /// Ignored when `verify` is set
+    pub launch_tidal: bool,
+    /// False when TIDAL was already stopped for a multi-target run
+    pub kill_tidal: bool,
+    pub cache_dir: Option<PathBuf>,
+    /// Called while the archive downloads; not called for cached archives
+    pub download_progress: Option<DownloadProgress>,
+    /// Lets the download stop early; pass the same flag to `InstallManager::run_from`
+    pub cancel: Option<CancelFlag>,
+}
+
+/// Options for pipelines that work on an existing installation (uninstall, rollback)
+#[derive(Debug, Clone)]
+pub struct TargetOptions {
+    pub path: PathBuf,
+    pub suppress_console_window: bool,
+    pub launch_tidal: bool,
+    pub kill_tidal: bool,
+    /// Uninstall only: moves Luna into the previous slot instead of deleting it
+    pub keep_backup: bool,
+    /// Uninstall only: also deletes Luna's data from TIDAL's user data directory
+    pub wipe_user_data: bool,
+}
+
+/// How a pipeline went for one installation of a multi-target run
+#[derive(Debug, Clone)]
+pub struct TargetOutcome {
+    pub path: PathBuf,
+    pub outcome: RunOutcome,
+}
+
+/// Download URL for an archive on disk. Receipts keep it, so repairs find the archive again.
+pub fn local_archive_url(archive: &Path) -> Option<String> {
+    reqwest::Url::from_file_path(archive).ok().map(String::from)
+}
+
+fn local_archive_path(download_url: &str) -> Option<PathBuf> {
+    reqwest::Url::parse(download_url)
+        .ok()
+        .filter(|url| url.scheme() == "file")
+        .and_then(|url| url.to_file_path().ok())
+}
+
+/// Stops every TIDAL client once before a multi-target run
+pub fn kill_pipeline() -> InstallManager {
+    let mut manager = InstallManager::new();
+    manager.add_step(Box::new(KillTidalStep));
+    manager
+}
+
+pub fn install_pipeline(options: &InstallOptions) -> InstallManager {
+    let path = &options.path;
+    let work_dir = WorkDir::default();
+    let mut manager = InstallManager::new();
+
+    if options.kill_tidal {
+        manager.add_step(Box::new(KillTidalStep));
+    }
+    if options.reinstall {
+        manager.add_step(Box::new(ReinstallCleanupStep {
+            overwrite_path: Some(path.clone()),
+        }));
+    }
+    manager.add_step(Box::new(SetupStep {
+        overwrite_path: Some(path.clone()),
+        work_dir: work_dir.clone(),
+    }));
+    match local_archive_path(&options.download_url) {
+        Some(archive) => manager.add_step(Box::new(LocalArchiveStep {
+            archive,
+            work_dir: work_dir.clone(),
+        })),
+        None => manager.add_step(Box::new(DownloadLunaStep {
+            download_url: options.download_url.clone(),
+            version: options.version.clone(),
+            cache_dir: options.cache_dir.clone(),
+            progress: options.download_progress.clone(),
+            cancel: options.cancel.clone(),
+            work_dir: work_dir.clone(),
+        })),
+    }
+    manager.add_step(Box::new(ExtractLunaStep {
+        work_dir: work_dir.clone(),
+    }));
+    manager.add_step(Box::new(CopyAsarInstallStep {
+        overwrite_path: Some(path.clone()),
+        refresh_backup: options.refresh_asar_backup,
+    }));
+    manager.add_step(Box::new(InsertLunaStep {
+        overwrite_path: Some(path.clone()),
+        work_dir,
+    }));
+    manager.add_step(Box::new(WriteReceiptStep {
+        overwrite_path: path.clone(),
+        receipt: InstallReceipt::new(&options.channel, &options.version, &options.download_url),
+    }));
+    manager.add_step(Box::new(RestoreOwnershipStep {
+        overwrite_path: Some(path.clone()),
+    }));
+    manager.add_step(Box::new(SignTidalStep));
+
+    match &options.verify {
+        Some(verify) => manager.add_step(Box::new(VerifyLunaStep {
+            overwrite_path: Some(path.clone()),
+            suppress_console_window: options.suppress_console_window,
+            timeout_secs: verify.timeout_secs,
+            rollback_on_failure: verify.rollback_on_failure,
+        })),
+        None if options.launch_tidal => manager.add_step(Box::new(LaunchTidalStep {
+            overwrite_path: Some(path.clone()),
+            suppress_console_window: options.suppress_console_window,
+        })),
+        None => {}
+    }
+
+    manager
+}
+
+pub fn uninstall_pipeline(options: &TargetOptions) -> InstallManager {
+    let path = &options.path;
+    let mut manager = InstallManager::new();
+
+    if options.kill_tidal {
+        manager.add_step(Box::new(KillTidalStep));
+    }
+    manager.add_step(Box::new(CopyAsarUninstallStep {
+        overwrite_path: Some(path.clone()),
+    }));
+    manager.add_step(Box::new(UninstallStep {
+        overwrite_path: Some(path.clone()),
+        keep_backup: options.keep_backup,
+    }));
+    if options.wipe_user_data {
+        manager.add_step(Box::new(WipeLunaDataStep));
+    }
+    manager.add_step(Box::new(RestoreOwnershipStep {
+        overwrite_path: Some(path.clone()),
+    }));
+    manager.add_step(Box::new(SignTidalStep));
+    if options.launch_tidal {
+        manager.add_step(Box::new(LaunchTidalStep {
+            overwrite_path: Some(path.clone()),
+            suppress_console_window: options.suppress_console_window,
+        }));
+    }
+
+    manager
+}
+
+/// Swaps the previous Luna install back in; works offline
+pub fn rollback_pipeline(options: &TargetOptions) -> InstallManager {
+    let path = &options.path;
+    let mut manager = InstallManager::new();
+
+    if options.kill_tidal {
+        manager.add_step(Box::new(KillTidalStep));
+    }
+    manager.add_step(Box::new(RollbackLunaStep {
+        overwrite_path: path.clone(),
+    }));
+    // Needed when Luna was uninstalled since: backs up and removes app.asar again
+    manager.add_step(Box::new(CopyAsarInstallStep {
+        overwrite_path: Some(path.clone()),
+        refresh_backup: false,
+    }));
+    manager.add_step(Box::new(RestoreOwnershipStep {
+        overwrite_path: Some(path.clone()),
+    }));
+    manager.add_step(Box::new(SignTidalStep));
+    if options.launch_tidal {
+        manager.add_step(Box::new(LaunchTidalStep {
+            overwrite_path: Some(path.clone()),
+            suppress_console_window: options.suppress_console_window,
+        }));
+    }
+
+    manager
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn luna_archive(path: &Path) {
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+        zip.start_file("index.js", zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(b"console.log('[Luna] ready');").unwrap();
+        zip.finish().unwrap();
+    }
+
+    /// The file-handling part of `install_pipeline`, without steps that touch the system
+    fn files_pipeline(path: &Path, archive: &Path) -> InstallManager {
+        let work_dir = WorkDir::default();
+        let mut manager = InstallManager::new();
+        manager.add_step(Box::new(SetupStep {
+            overwrite_path: Some(path.to_path_buf()),
+            work_dir: work_dir.clone(),
+        }));
+        manager.add_step(Box::new(LocalArchiveStep {
+            archive: archive.to_path_buf(),
+            work_dir: work_dir.clone(),
+        }));
+        manager.add_step(Box::new(ExtractLunaStep {
+            work_dir: work_dir.clone(),
+        }));
+        manager.add_step(Box::new(CopyAsarInstallStep {
+            overwrite_path: Some(path.to_path_buf()),
+            refresh_backup: false,
+        }));
+        manager.add_step(Box::new(InsertLunaStep {
+            overwrite_path: Some(path.to_path_buf()),
+            work_dir,
+        }));
+        manager
+    }
+
+    #[tokio::test]
+    async fn retry_from_extract_after_another_pipeline_ran() {
+        let sandbox = tempfile::tempdir().unwrap();
+        let archive = sandbox.path().join("luna.zip");
+        luna_archive(&archive);
+        let first = sandbox.path().join("first");
+        let second = sandbox.path().join("second");
+        for resources in [&first, &second] {
+            std::fs::create_dir(resources).unwrap();
+            std::fs::write(resources.join("app.asar"), b"tidal").unwrap();
+        }
+        // A file where Luna goes makes the first target fail at insert_luna
+        std::fs::write(first.join("app"), b"").unwrap();
+
+        let failing = files_pipeline(&first, &archive);
+        let outcome = failing.run(|_| {}).await;
+        assert_eq!(outcome.failed_step.unwrap().step.id, "insert_luna");
+
+        let other = files_pipeline(&second, &archive);
+        assert!(other.run(|_| {}).await.success());
+        assert!(second.join("app").join("index.js").is_file());
+
+        std::fs::remove_file(first.join("app")).unwrap();
+        let extract = failing.steps.iter().position(|step| step.id() == "extract_luna").unwrap();
+        let retry = failing.run_from(extract, None, |_| {}).await;
+        assert!(retry.success(), "{:?}", retry.failed_step);
+        assert!(first.join("app").join("index.js").is_file());
+    }
+}
@@ -9,7 +9,17 @@ pub struct StepResult {
 
 #[async_trait::async_trait]
 pub trait InstallStep: Send + Sync {
-    fn name(&self) -> &str;
+    /// Stable identifier used in machine-readable output; never shown to users
+    fn id(&self) -> &'static str;
+
+    /// Display name in the user's language
+    fn name(&self) -> String;
+
+    /// False for a step that has to run once the step before it did, because stopping in
+    /// between would leave TIDAL unable to start. A cancel then takes effect after it.
+    fn cancellable(&self) -> bool {
+        true
+    }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult;
-}
\ No newline at end of file
+}
@@ -1,4 +1,5 @@
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
 use crate::utils::fs_helpers::get_tidal_directory;
 use async_trait::async_trait;
 use std::path::PathBuf;
@@ -6,12 +7,18 @@ use tokio::fs;
 
 pub struct CopyAsarInstallStep {
     pub overwrite_path: Option<PathBuf>,
+    /// TIDAL replaced app.asar since Luna was installed, so the backup is outdated
+    pub refresh_backup: bool,
 }
 
 #[async_trait]
 impl InstallStep for CopyAsarInstallStep {
-    fn name(&self) -> &str {
-        "Copy ASAR for Installation"
+    fn id(&self) -> &'static str {
+        "copy_asar_install"
+    }
+
+    fn name(&self) -> String {
+        t!("step-copy-asar-install")
     }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
@@ -23,35 +30,33 @@ impl InstallStep for CopyAsarInstallStep {
                 _ => {
                     return StepResult {
                         success: false,
-                        message: "Tidal is not installed or path could not be found".into(),
+                        message: t!("step-tidal-not-found"),
                     }
                 }
             }
         };
 
         sublog_callback(SubLog {
-            message: format!("Using Tidal path: {:?}", tidal_path),
+            message: t!("copy-asar-using-path", path = format!("{:?}", tidal_path)),
         });
 
         if !tidal_path.exists() {
             return StepResult {
                 success: false,
-                message: "Tidal path does not exist".into(),
+                message: t!("step-path-missing"),
             };
         }
 
         let mut has_asar = false;
         if let Ok(mut entries) = fs::read_dir(&tidal_path).await {
             while let Ok(Some(entry)) = entries.next_entry().await {
-                if let Ok(file_type) = entry.file_type().await {
-                    if file_type.is_file() {
-                        if let Some(ext) = entry.path().extension() {
-                            if ext == "asar" {
-                                has_asar = true;
-                                break;
-                            }
-                        }
-                    }
+                if let Ok(file_type) = entry.file_type().await
+                    && file_type.is_file()
+                    && let Some(ext) = entry.path().extension()
+                    && ext == "asar"
+                {
+                    has_asar = true;
+                    break;
                 }
             }
         }
@@ -59,47 +64,59 @@ impl InstallStep for CopyAsarInstallStep {
         if !has_asar {
             return StepResult {
                 success: false,
-                message: "No .asar files found — Tidal is not installed correctly".into(),
+                message: t!("copy-asar-no-asar"),
             };
         }
 
         let original_asar = tidal_path.join("original.asar");
         let app_asar = tidal_path.join("app.asar");
 
+        if self.refresh_backup && original_asar.exists() && app_asar.exists() {
+            sublog_callback(SubLog {
+                message: t!("copy-asar-refreshing-backup"),
+            });
+            if let Err(e) = fs::copy(&app_asar, &original_asar).await {
+                return StepResult {
+                    success: false,
+                    message: t!("copy-asar-refresh-failed", error = e),
+                };
+            }
+        }
+
         if !original_asar.exists() {
             if !app_asar.exists() {
                 return StepResult {
                     success: false,
-                    message: "app.asar not found. Tidal installation may be corrupt.".into(),
+                    message: t!("copy-asar-app-asar-missing"),
                 };
             }
             sublog_callback(SubLog {
-                message: "Creating original.asar backup".into(),
+                message: t!("copy-asar-creating-backup"),
             });
             if let Err(e) = fs::copy(&app_asar, &original_asar).await {
                 return StepResult {
                     success: false,
-                    message: format!("Failed to backup app.asar: {}", e),
+                    message: t!("copy-asar-backup-failed", error = e),
                 };
             }
         }
 
-        if app_asar.exists() {
-            if let Err(e) = fs::remove_file(&app_asar).await {
-                return StepResult {
-                    success: false,
-                    message: format!("Failed to delete existing app.asar: {}", e),
-                };
-            }
+        if app_asar.exists()
+            && let Err(e) = fs::remove_file(&app_asar).await
+        {
+            return StepResult {
+                success: false,
+                message: t!("copy-asar-delete-failed", error = e),
+            };
         }
 
         sublog_callback(SubLog {
-            message: "app.asar copied to original.asar successfully".into(),
+            message: t!("copy-asar-copied"),
         });
 
         StepResult {
             success: true,
-            message: "Copy ASAR step completed".into(),
+            message: t!("copy-asar-done"),
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
 use async_trait::async_trait;
 use std::path::PathBuf;
 use tokio::fs;
@@ -10,8 +11,12 @@ pub struct CopyAsarUninstallStep {
 
 #[async_trait]
 impl InstallStep for CopyAsarUninstallStep {
-    fn name(&self) -> &str {
-        "Restore original ASAR"
+    fn id(&self) -> &'static str {
+        "restore_original_asar"
+    }
+
+    fn name(&self) -> String {
+        t!("step-restore-asar")
     }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
@@ -22,20 +27,20 @@ impl InstallStep for CopyAsarUninstallStep {
                 Ok(p) if !p.as_os_str().is_empty() => p,
                 Ok(_) => {
                     sublog_callback(SubLog {
-                        message: "Tidal path could not be resolved".into(),
+                        message: t!("step-path-unresolved"),
                     });
                     return StepResult {
                         success: false,
-                        message: "Invalid Tidal path".into(),
+                        message: t!("step-invalid-path"),
                     };
                 }
                 Err(err) => {
                     sublog_callback(SubLog {
-                        message: format!("Tidal is not installed: {}", err),
+                        message: t!("step-tidal-missing", error = err),
                     });
                     return StepResult {
                         success: false,
-                        message: "Invalid Tidal path".into(),
+                        message: t!("step-invalid-path"),
                     };
                 }
             }
@@ -46,47 +51,47 @@ impl InstallStep for CopyAsarUninstallStep {
 
         if !original_asar.exists() {
             sublog_callback(SubLog {
-                message: "original.asar not found, cannot restore app.asar".into(),
+                message: t!("restore-asar-original-not-found"),
             });
             return StepResult {
                 success: false,
-                message: "original.asar missing".into(),
+                message: t!("restore-asar-original-missing"),
             };
         }
 
         sublog_callback(SubLog {
-            message: "Restoring original app.asar...".into(),
+            message: t!("restore-asar-restoring"),
         });
 
-        if app_asar.exists() {
-            if let Err(err) = fs::remove_file(&app_asar).await {
-                sublog_callback(SubLog {
-                    message: format!("Failed to remove existing app.asar: {}", err),
-                });
-                return StepResult {
-                    success: false,
-                    message: format!("Failed to remove app.asar: {}", err),
-                };
-            }
+        if app_asar.exists()
+            && let Err(err) = fs::remove_file(&app_asar).await
+        {
+            sublog_callback(SubLog {
+                message: t!("restore-asar-remove-existing-failed", error = err),
+            });
+            return StepResult {
+                success: false,
+                message: t!("restore-asar-remove-failed", error = err),
+            };
         }
 
         if let Err(err) = fs::copy(&original_asar, &app_asar).await {
             sublog_callback(SubLog {
-                message: format!("Failed to restore app.asar: {}", err),
+                message: t!("restore-asar-copy-failed", error = err),
             });
             return StepResult {
                 success: false,
-                message: format!("Failed to restore app.asar: {}", err),
+                message: t!("restore-asar-copy-failed", error = err),
             };
         }
 
         sublog_callback(SubLog {
-            message: "original.asar restored to app.asar successfully".into(),
+            message: t!("restore-asar-restored"),
         });
 
         StepResult {
             success: true,
-            message: "ASAR restored successfully".into(),
+            message: t!("restore-asar-done"),
         }
     }
 }
@@ -1,6 +1,16 @@
+use crate::installer::manager::CancelFlag;
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::installer::work_dir::WorkDir;
+use crate::t;
+use crate::utils::history::write_atomic;
+use crate::utils::http;
 use async_trait::async_trait;
+use reqwest::header::{self, HeaderMap};
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
@@ -30,186 +40,339 @@ fn has_zip_end_of_central_directory(bytes: &[u8]) -> bool {
         .any(|window| window == EOCD_SIGNATURE)
 }
 
-fn validate_zip_bytes(bytes: &[u8]) -> Result<(), String> {
+pub fn validate_zip_bytes(bytes: &[u8]) -> Result<(), String> {
     if !has_zip_signature(bytes) {
-        return Err("missing ZIP signature".to_string());
+        return Err(t!("zip-missing-signature"));
     }
 
     if !has_zip_end_of_central_directory(bytes) {
-        return Err("missing ZIP central directory".to_string());
+        return Err(t!("zip-missing-central-directory"));
     }
 
     let cursor = Cursor::new(bytes);
     ZipArchive::new(cursor)
         .map(|_| ())
-        .map_err(|err| format!("ZIP parse validation failed: {}", err))
+        .map_err(|err| t!("zip-parse-failed", error = err))
 }
 
-pub struct DownloadLunaStep {
-    pub download_url: String,
+/// File name for a cached archive; the URL is kept readable so the cache can be inspected by hand
+fn cached_archive_name(version: &str, download_url: &str) -> String {
+    let url = download_url.trim_start_matches("https://").trim_start_matches("http://");
+    let name: String = format!("{}-{}", version, url)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    let name = &name[name.len().saturating_sub(180)..];
+
+    if name.to_ascii_lowercase().ends_with(".zip") {
+        name.to_string()
+    } else {
+        format!("{}.zip", name)
+    }
 }
 
-#[async_trait]
-impl InstallStep for DownloadLunaStep {
-    fn name(&self) -> &str {
-        "Download Luna"
+/// What the server reported for an archive when it was cached. Rolling release tags
+/// publish new builds under the same URL, so a cached copy is only reused while these match.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ArchiveValidators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    size: Option<u64>,
+}
+
+impl ArchiveValidators {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let text = |name| headers.get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+        Self {
+            etag: text(header::ETAG),
+            last_modified: text(header::LAST_MODIFIED),
+            size: text(header::CONTENT_LENGTH).and_then(|length| length.parse().ok()),
+        }
     }
 
-    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
-        let temp_dir = std::env::temp_dir().join("TidaLunaInstaller");
-        sublog_callback(SubLog {
-            message: format!("Using temporary directory: {:?}", temp_dir),
-        });
+    fn path(archive: &Path) -> PathBuf {
+        archive.with_extension("json")
+    }
 
-        if let Err(err) = tokio::fs::create_dir_all(&temp_dir).await {
-            return StepResult {
-                success: false,
-                message: format!("Failed to create temporary directory: {}", err),
-            };
+    /// True when `remote` is the build that was cached; unknown counts as changed
+    fn matches(&self, remote: &Self) -> bool {
+        if let (Some(cached), Some(remote)) = (&self.etag, &remote.etag) {
+            return cached == remote;
         }
+        matches!(
+            (&self.last_modified, &remote.last_modified, self.size, remote.size),
+            (Some(cached_date), Some(remote_date), Some(cached_size), Some(remote_size))
+                if cached_date == remote_date && cached_size == remote_size
+        )
+    }
+}
 
-        let zip_path = temp_dir.join("Luna.zip");
-        sublog_callback(SubLog {
-            message: "Downloading Luna...".into(),
-        });
+async fn read_cached_archive(path: &Path) -> Option<Vec<u8>> {
+    let bytes = tokio::fs::read(path).await.ok()?;
+    validate_zip_bytes(&bytes).ok()?;
+    Some(bytes)
+}
+
+/// Writes the archive to `Luna.zip` in `temp_dir`, where `ExtractLunaStep` picks it up;
+/// returns its path and size
+pub async fn write_luna_zip(temp_dir: &Path, bytes: &[u8]) -> Result<(PathBuf, u64), String> {
+    let zip_path = temp_dir.join("Luna.zip");
+    let part_path = temp_dir.join("Luna.zip.part");
 
-        let client = match reqwest::Client::builder()
+    if part_path.exists() {
+        let _ = tokio::fs::remove_file(&part_path).await;
+    }
+
+    let mut file = File::create(&part_path)
+        .await
+        .map_err(|err| t!("download-part-create-failed", error = err))?;
+    file.write_all(bytes)
+        .await
+        .map_err(|err| t!("download-part-write-failed", error = err))?;
+    file.flush()
+        .await
+        .map_err(|err| t!("download-part-flush-failed", error = err))?;
+    file.sync_all()
+        .await
+        .map_err(|err| t!("download-part-sync-failed", error = err))?;
+    drop(file);
+
+    tokio::fs::rename(&part_path, &zip_path)
+        .await
+        .map_err(|err| t!("download-finalize-failed", error = err))?;
+
+    let written_size = tokio::fs::metadata(&zip_path)
+        .await
+        .map_err(|err| t!("download-metadata-failed", error = err))?
+        .len();
+
+    if written_size != bytes.len() as u64 {
+        return Err(t!(
+            "download-size-mismatch",
+            written = written_size,
+            expected = bytes.len()
+        ));
+    }
+
+    Ok((zip_path, written_size))
+}
+
+/// Receives the bytes downloaded so far and the total size, when the server sends one
+#[derive(Clone)]
+pub struct DownloadProgress(pub Arc<dyn Fn(u64, Option<u64>) + Send + Sync>);
+
+impl fmt::Debug for DownloadProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DownloadProgress")
+    }
+}
+
+pub struct DownloadLunaStep {
+    pub download_url: String,
+    /// Part of the cache key, next to the URL
+    pub version: String,
+    /// Where downloaded archives are kept for reuse; `None` disables the cache
+    pub cache_dir: Option<PathBuf>,
+    pub progress: Option<DownloadProgress>,
+    pub cancel: Option<CancelFlag>,
+    pub work_dir: WorkDir,
+}
+
+impl DownloadLunaStep {
+    /// Asks the server whether the cached archive is still the one it serves. `None` when
+    /// it cannot be reached, so offline repairs still use the cache.
+    async fn cache_is_current(&self, archive: &Path) -> Option<bool> {
+        let client = http::client_builder().timeout(Duration::from_secs(15)).build().ok()?;
+        let response = client.head(&self.download_url).send().await.ok()?;
+        if !response.status().is_success() {
+            return None;
+        }
+
+        let cached: Option<ArchiveValidators> = tokio::fs::read(ArchiveValidators::path(archive))
+            .await
+            .ok()
+            .and_then(|contents| serde_json::from_slice(&contents).ok());
+        let remote = ArchiveValidators::from_headers(response.headers());
+        Some(cached.is_some_and(|cached| cached.matches(&remote)))
+    }
+
+    async fn download(&self) -> Result<(Vec<u8>, ArchiveValidators), String> {
+        let client = http::client_builder()
             .timeout(Duration::from_secs(120))
             .build()
-        {
-            Ok(client) => client,
-            Err(err) => {
-                return StepResult {
-                    success: false,
-                    message: format!("Failed to build HTTP client: {}", err),
-                };
-            }
-        };
+            .map_err(|err| t!("download-client-failed", error = err))?;
 
-        let response = match client.get(&self.download_url).send().await {
-            Ok(resp) => resp,
-            Err(err) => {
-                return StepResult {
-                    success: false,
-                    message: format!("Failed to send download request: {}", err),
-                };
-            }
-        };
+        let mut response = client
+            .get(&self.download_url)
+            .send()
+            .await
+            .map_err(|err| t!("download-request-failed", error = err))?;
 
         if !response.status().is_success() {
-            return StepResult {
-                success: false,
-                message: format!("Failed to download Luna, HTTP status: {}", response.status()),
-            };
+            return Err(t!("download-http-status", status = response.status()));
         }
 
-        let bytes = match response.bytes().await {
-            Ok(b) => b,
-            Err(err) => {
-                return StepResult {
-                    success: false,
-                    message: format!("Failed to read download response: {}", err),
-                };
+        let validators = ArchiveValidators::from_headers(response.headers());
+        let total = response.content_length();
+        // Roughly 200 reports per download, but not one per network chunk
+        let report_every = total.map_or(0, |total| total / 200).max(64 * 1024);
+        let mut bytes = Vec::with_capacity(total.unwrap_or(0).min(256 * 1024 * 1024) as usize);
+        let mut reported = 0u64;
+
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|err| t!("download-read-failed", error = err))?
+        {
+            if self.cancel.as_ref().is_some_and(CancelFlag::is_cancelled) {
+                return Err(t!("download-cancelled"));
             }
-        };
+            bytes.extend_from_slice(&chunk);
+            let received = bytes.len() as u64;
+            if let Some(progress) = &self.progress
+                && received - reported >= report_every
+            {
+                reported = received;
+                (progress.0)(received, total);
+            }
+        }
+        if let Some(progress) = &self.progress {
+            (progress.0)(bytes.len() as u64, total);
+        }
 
         if bytes.is_empty() {
-            return StepResult {
-                success: false,
-                message: "Download completed but returned an empty file".into(),
-            };
+            return Err(t!("download-empty"));
         }
 
         if let Err(err) = validate_zip_bytes(&bytes) {
             let preview = String::from_utf8_lossy(&bytes[..bytes.len().min(180)])
-                .replace('\n', " ")
-                .replace('\r', " ");
-
-            return StepResult {
-                success: false,
-                message: format!(
-                    "Downloaded file failed ZIP validation ({}). URL: {}. Response preview: {}",
-                    err, self.download_url, preview
-                ),
-            };
-        }
-
-        let part_path = temp_dir.join("Luna.zip.part");
+                .replace(['\n', '\r'], " ");
 
-        if part_path.exists() {
-            let _ = tokio::fs::remove_file(&part_path).await;
+            return Err(t!(
+                "download-invalid-zip",
+                error = err,
+                url = self.download_url,
+                preview = preview
+            ));
         }
 
-        match File::create(&part_path).await {
-            Ok(mut file) => {
-                if let Err(err) = file.write_all(&bytes).await {
-                    return StepResult {
-                        success: false,
-                        message: format!("Failed to write Luna.zip.part: {}", err),
-                    };
-                }
+        Ok((bytes, validators))
+    }
+}
 
-                if let Err(err) = file.flush().await {
-                    return StepResult {
-                        success: false,
-                        message: format!("Failed to flush Luna.zip.part: {}", err),
-                    };
-                }
+#[async_trait]
+impl InstallStep for DownloadLunaStep {
+    fn id(&self) -> &'static str {
+        "download_luna"
+    }
 
-                if let Err(err) = file.sync_all().await {
-                    return StepResult {
-                        success: false,
-                        message: format!("Failed to sync Luna.zip.part: {}", err),
-                    };
-                }
-            }
+    fn name(&self) -> String {
+        t!("step-download-luna")
+    }
+
+    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
+        let temp_dir = match self.work_dir.path() {
+            Ok(dir) => dir,
             Err(err) => {
                 return StepResult {
                     success: false,
-                    message: format!("Failed to create Luna.zip.part: {}", err),
+                    message: t!("setup-temp-dir-failed", error = err),
                 };
             }
-        }
+        };
+        sublog_callback(SubLog {
+            message: t!("download-temp-dir", path = format!("{:?}", temp_dir)),
+        });
 
-        if let Err(err) = tokio::fs::rename(&part_path, &zip_path).await {
-            return StepResult {
-                success: false,
-                message: format!("Failed to finalize Luna.zip: {}", err),
-            };
-        }
+        let cached_path = self
+            .cache_dir
+            .as_ref()
+            .map(|dir| dir.join(cached_archive_name(&self.version, &self.download_url)));
 
-        let written_size = match tokio::fs::metadata(&zip_path).await {
-            Ok(metadata) => metadata.len(),
-            Err(err) => {
+        let cached = match &cached_path {
+            Some(path) => match read_cached_archive(path).await {
+                Some(bytes) => match self.cache_is_current(path).await {
+                    Some(true) => Some((path, bytes)),
+                    Some(false) => {
+                        sublog_callback(SubLog {
+                            message: t!("download-cache-outdated", path = format!("{:?}", path)),
+                        });
+                        None
+                    }
+                    None => {
+                        sublog_callback(SubLog {
+                            message: t!("download-cache-unverified"),
+                        });
+                        Some((path, bytes))
+                    }
+                },
+                None => None,
+            },
+            None => None,
+        };
+
+        let bytes = match cached {
+            Some((path, bytes)) => {
+                sublog_callback(SubLog {
+                    message: t!("download-using-cache", path = format!("{:?}", path)),
+                });
+                bytes
+            }
+            None => {
+                sublog_callback(SubLog {
+                    message: t!("download-running"),
+                });
+
+                let (bytes, validators) = match self.download().await {
+                    Ok(downloaded) => downloaded,
+                    Err(message) => {
+                        return StepResult {
+                            success: false,
+                            message,
+                        };
+                    }
+                };
+
+                if let Some(path) = &cached_path {
+                    let stored = serde_json::to_string(&validators)
+                        .map_err(std::io::Error::other)
+                        .and_then(|contents| write_atomic(&ArchiveValidators::path(path), contents))
+                        .and_then(|()| write_atomic(path, &bytes));
+                    match stored {
+                        Ok(()) => sublog_callback(SubLog {
+                            message: t!("download-cached", path = format!("{:?}", path)),
+                        }),
+                        Err(err) => sublog_callback(SubLog {
+                            message: t!("download-cache-failed", error = err),
+                        }),
+                    }
+                }
+
+                bytes
+            }
+        };
+
+        let (zip_path, written_size) = match write_luna_zip(&temp_dir, &bytes).await {
+            Ok(written) => written,
+            Err(message) => {
                 return StepResult {
                     success: false,
-                    message: format!("Failed to verify Luna.zip metadata: {}", err),
+                    message,
                 };
             }
         };
 
-        if written_size != bytes.len() as u64 {
-            return StepResult {
-                success: false,
-                message: format!(
-                    "Written Luna.zip size mismatch: wrote {} bytes but expected {}",
-                    written_size,
-                    bytes.len()
-                ),
-            };
-        }
-
         sublog_callback(SubLog {
-            message: format!(
-                "Luna downloaded successfully to {:?} ({} bytes)",
-                zip_path,
-                written_size
+            message: t!(
+                "download-saved",
+                path = format!("{:?}", zip_path),
+                size = written_size
             ),
         });
 
         StepResult {
             success: true,
-            message: "Download completed successfully".into(),
+            message: t!("download-done"),
         }
     }
 }
@@ -1,4 +1,6 @@
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::installer::work_dir::WorkDir;
+use crate::t;
 use async_trait::async_trait;
 use std::fs;
 use std::fs::File;
@@ -23,33 +25,42 @@ fn safe_zip_join(base: &Path, zip_entry_name: &str) -> Option<std::path::PathBuf
     Some(base.join(safe_relative))
 }
 
-pub struct ExtractLunaStep;
+pub struct ExtractLunaStep {
+    pub work_dir: WorkDir,
+}
 
 #[async_trait]
 impl InstallStep for ExtractLunaStep {
-    fn name(&self) -> &str {
-        "Extract Luna"
+    fn id(&self) -> &'static str {
+        "extract_luna"
+    }
+
+    fn name(&self) -> String {
+        t!("step-extract-luna")
     }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
-        let temp_dir = std::env::temp_dir().join("TidaLunaInstaller");
+        let temp_dir = match self.work_dir.path() {
+            Ok(dir) => dir,
+            Err(e) => return StepResult { success: false, message: t!("setup-temp-dir-failed", error = e) },
+        };
         let zip_path = temp_dir.join("Luna.zip");
         let extract_path = temp_dir.join("LunaExtracted");
 
         if extract_path.exists() {
-            sublog_callback(SubLog { message: format!("Cleaning existing extract path: {:?}", extract_path) });
+            sublog_callback(SubLog { message: t!("extract-cleaning", path = format!("{:?}", extract_path)) });
             if let Err(e) = fs::remove_dir_all(&extract_path) {
-                return StepResult { success: false, message: format!("Failed to clean extract dir: {}", e) };
+                return StepResult { success: false, message: t!("extract-clean-failed", error = e) };
             }
         }
 
-        sublog_callback(SubLog { message: format!("Ensuring extract path exists: {:?}", extract_path) });
+        sublog_callback(SubLog { message: t!("extract-ensuring-dir", path = format!("{:?}", extract_path)) });
 
         if let Err(e) = fs::create_dir_all(&extract_path) {
-            return StepResult { success: false, message: format!("Failed to create extract dir: {}", e) };
+            return StepResult { success: false, message: t!("extract-create-dir-failed", error = e) };
         }
 
-        sublog_callback(SubLog { message: "Extracting Luna...".into() });
+        sublog_callback(SubLog { message: t!("extract-running") });
 
         let mut raw_bytes = Vec::new();
         match File::open(&zip_path) {
@@ -57,14 +68,14 @@ impl InstallStep for ExtractLunaStep {
                 if let Err(e) = zip_file.read_to_end(&mut raw_bytes) {
                     return StepResult {
                         success: false,
-                        message: format!("Failed to read zip bytes before extraction: {}", e),
+                        message: t!("extract-read-failed", error = e),
                     };
                 }
             }
             Err(e) => {
                 return StepResult {
                     success: false,
-                    message: format!("Failed to open zip for validation: {}", e),
+                    message: t!("extract-open-validation-failed", error = e),
                 };
             }
         }
@@ -72,44 +83,40 @@ impl InstallStep for ExtractLunaStep {
         if raw_bytes.len() < 22 {
             return StepResult {
                 success: false,
-                message: format!("Zip file is unexpectedly small: {} bytes", raw_bytes.len()),
+                message: t!("extract-too-small", size = raw_bytes.len()),
             };
         }
 
         if let Err(e) = ZipArchive::new(std::io::Cursor::new(&raw_bytes)) {
             return StepResult {
                 success: false,
-                message: format!(
-                    "Failed ZIP validation before extraction: {} (size: {} bytes)",
-                    e,
-                    raw_bytes.len()
-                ),
+                message: t!("extract-invalid-zip", error = e, size = raw_bytes.len()),
             };
         }
         
         let file = match File::open(&zip_path) {
             Ok(f) => f,
-            Err(e) => return StepResult { success: false, message: format!("Failed to open zip: {}", e) },
+            Err(e) => return StepResult { success: false, message: t!("extract-open-failed", error = e) },
         };
         
         let mut archive = match ZipArchive::new(file) {
             Ok(a) => a,
-            Err(e) => return StepResult { success: false, message: format!("Failed to read zip archive: {}", e) },
+            Err(e) => return StepResult { success: false, message: t!("extract-archive-failed", error = e) },
         };
 
         for i in 0..archive.len() {
             let mut file_in_zip = match archive.by_index(i) {
                 Ok(f) => f,
-                Err(e) => return StepResult { success: false, message: format!("Failed to access zip entry: {}", e) },
+                Err(e) => return StepResult { success: false, message: t!("extract-entry-failed", error = e) },
             };
 
             if file_in_zip.size() > MAX_ZIP_ENTRY_SIZE {
                 return StepResult {
                     success: false,
-                    message: format!(
-                        "Zip entry is too large (>{} bytes): {}",
-                        MAX_ZIP_ENTRY_SIZE,
-                        file_in_zip.name()
+                    message: t!(
+                        "extract-entry-too-large",
+                        limit = MAX_ZIP_ENTRY_SIZE,
+                        name = file_in_zip.name()
                     ),
                 };
             }
@@ -119,32 +126,32 @@ impl InstallStep for ExtractLunaStep {
                 None => {
                     return StepResult {
                         success: false,
-                        message: format!("Unsafe zip entry path rejected: {}", file_in_zip.name()),
+                        message: t!("extract-unsafe-entry", name = file_in_zip.name()),
                     }
                 }
             };
 
             if file_in_zip.is_dir() {
                 if let Err(e) = fs::create_dir_all(&out_path) {
-                    return StepResult { success: false, message: format!("Failed to create dir: {}", e) };
+                    return StepResult { success: false, message: t!("extract-mkdir-failed", error = e) };
                 }
             } else {
-                if let Some(p) = out_path.parent() {
-                    if let Err(e) = fs::create_dir_all(p) {
-                        return StepResult { success: false, message: format!("Failed to create parent dir: {}", e) };
-                    }
+                if let Some(p) = out_path.parent()
+                    && let Err(e) = fs::create_dir_all(p)
+                {
+                    return StepResult { success: false, message: t!("extract-parent-dir-failed", error = e) };
                 }
                 let mut outfile = match fs::File::create(&out_path) {
                     Ok(f) => f,
-                    Err(e) => return StepResult { success: false, message: format!("Failed to create file: {}", e) },
+                    Err(e) => return StepResult { success: false, message: t!("extract-create-file-failed", error = e) },
                 };
                 if let Err(e) = std::io::copy(&mut file_in_zip, &mut outfile) {
-                    return StepResult { success: false, message: format!("Failed to write file: {}", e) };
+                    return StepResult { success: false, message: t!("extract-write-failed", error = e) };
                 }
             }
         }
 
-        sublog_callback(SubLog { message: "Luna extracted successfully".into() });
-        StepResult { success: true, message: "Extraction completed".into() }
+        sublog_callback(SubLog { message: t!("extract-extracted") });
+        StepResult { success: true, message: t!("extract-done") }
     }
 }
@@ -1,20 +1,33 @@
 use async_trait::async_trait;
 use tokio::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::collections::VecDeque;
 
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::installer::work_dir::WorkDir;
+use crate::t;
 use crate::utils::fs_helpers::get_tidal_directory;
 
 /// Inserts extracted Luna files into the Tidal app directory
 pub struct InsertLunaStep {
     pub overwrite_path: Option<PathBuf>,
+    /// Left in place: a retry of this pipeline may need the extracted files again
+    pub work_dir: WorkDir,
 }
 
 #[async_trait]
 impl InstallStep for InsertLunaStep {
-    fn name(&self) -> &str {
-        "Insert Luna"
+    fn id(&self) -> &'static str {
+        "insert_luna"
+    }
+
+    fn name(&self) -> String {
+        t!("step-insert-luna")
+    }
+
+    /// `CopyAsarInstallStep` already moved app.asar away; without `app/` TIDAL does not start
+    fn cancellable(&self) -> bool {
+        false
     }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
@@ -26,7 +39,7 @@ impl InstallStep for InsertLunaStep {
                 _ => {
                     return StepResult {
                         success: false,
-                        message: "Tidal is not installed or path could not be found".into(),
+                        message: t!("step-tidal-not-found"),
                     }
                 }
             }
@@ -35,72 +48,64 @@ impl InstallStep for InsertLunaStep {
         if !tidal_path.exists() {
             return StepResult {
                 success: false,
-                message: "Tidal path does not exist".into(),
+                message: t!("step-path-missing"),
             };
         }
 
-        let temp_dir = std::env::temp_dir().join("TidaLunaInstaller");
-        let temp_luna_dir = temp_dir.join("LunaExtracted");
+        let temp_luna_dir = match self.work_dir.path() {
+            Ok(dir) => dir.join("LunaExtracted"),
+            Err(err) => {
+                return StepResult {
+                    success: false,
+                    message: t!("setup-temp-dir-failed", error = err),
+                };
+            }
+        };
         let destination_path = tidal_path.join("app");
 
         sublog_callback(SubLog {
-            message: format!("Using temp directory: {:?}", temp_luna_dir),
+            message: t!("insert-temp-dir", path = format!("{:?}", temp_luna_dir)),
         });
 
         if !temp_luna_dir.exists() {
             return StepResult {
                 success: false,
-                message: "Temporary Luna directory does not exist".into(),
+                message: t!("insert-temp-dir-missing"),
             };
         }
 
         sublog_callback(SubLog {
-            message: "Copying Luna files into Tidal app directory".into(),
+            message: t!("insert-copying"),
         });
 
         if let Err(err) = fs::create_dir_all(&destination_path).await {
             return StepResult {
                 success: false,
-                message: format!("Failed to create destination directory: {}", err),
+                message: t!("insert-create-dir-failed", error = err),
             };
         }
 
         if let Err(err) = copy_dir_recursive(&temp_luna_dir, &destination_path).await {
             return StepResult {
                 success: false,
-                message: format!("Failed to copy Luna files: {}", err),
-            };
-        }
-
-        sublog_callback(SubLog {
-            message: "Luna files copied successfully".into(),
-        });
-
-        sublog_callback(SubLog {
-            message: "Cleaning up temporary files".into(),
-        });
-
-        if let Err(err) = fs::remove_dir_all(&temp_dir).await {
-            return StepResult {
-                success: false,
-                message: format!("Failed to clean up temporary files: {}", err),
+                message: t!("insert-copy-failed", error = err),
             };
         }
 
         sublog_callback(SubLog {
-            message: "Temporary files cleaned up successfully".into(),
+            message: t!("insert-copied"),
         });
 
         StepResult {
             success: true,
-            message: "Insert Luna step completed successfully".into(),
+            message: t!("insert-done"),
         }
     }
 }
 
-pub async fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> std::io::Result<()> {
+pub async fn copy_dir_recursive(src: &Path, dst: &Path) -> std::io::Result<()> {
     let mut queue = VecDeque::new();
-    queue.push_back((src.clone(), dst.clone()));
+    queue.push_back((src.to_path_buf(), dst.to_path_buf()));
 
     while let Some((current_src, current_dst)) = queue.pop_front() {
         fs::create_dir_all(&current_dst).await?;
@@ -1,4 +1,5 @@
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
 use async_trait::async_trait;
 use std::process::Command;
 
@@ -6,19 +7,106 @@ fn run_command(program: &str, args: &[&str]) -> Option<std::process::Output> {
     Command::new(program).args(args).output().ok()
 }
 
+fn parent_pid(pid: u32) -> Option<u32> {
+    let output = run_command("ps", &["-o", "ppid=", "-p", &pid.to_string()])?;
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+/// The installer and the processes that started it (shell, sudo, a `watch` service).
+/// `tidaluna-installer` itself contains "tidal", so pattern kills must skip these.
+fn own_process_tree() -> Vec<u32> {
+    let mut pids = vec![std::process::id()];
+    while let Some(parent) = pids.last().copied().and_then(parent_pid) {
+        if parent <= 1 || pids.contains(&parent) {
+            break;
+        }
+        pids.push(parent);
+    }
+    pids
+}
+
+const WINDOWS_IMAGES: [&str; 4] = ["TIDAL.exe", "Tidal.exe", "tidal.exe", "Update.exe"];
+const MACOS_PATTERNS: [&str; 2] = ["TIDAL", "Tidal"];
+const LINUX_PATTERNS: [&str; 2] = ["tidal-hifi", "tidal"];
+
+/// Like `pgrep -f pattern` minus `spared`; `None` when `pgrep` is unavailable
+fn matching_pids(pattern: &str, spared: &[u32]) -> Option<Vec<String>> {
+    let output = run_command("pgrep", &["-f", pattern])?;
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.trim().parse::<u32>().ok())
+            .filter(|pid| !spared.contains(pid))
+            .map(|pid| pid.to_string())
+            .collect(),
+    )
+}
+
+/// Like `pkill -f pattern`, but never hits the installer's own process tree.
+/// Returns `None` when `pgrep` is unavailable and whether anything was killed otherwise.
+fn kill_matching(pattern: &str, spared: &[u32]) -> Option<bool> {
+    let pids = matching_pids(pattern, spared)?;
+    if pids.is_empty() {
+        return Some(false);
+    }
+
+    let mut args = vec!["-TERM"];
+    args.extend(pids.iter().map(String::as_str));
+    Some(run_command("kill", &args).is_some_and(|output| output.status.success()))
+}
+
+/// Whether a TIDAL client is running, i.e. whether `KillTidalStep` would stop something.
+/// `None` when the OS offers no way to tell.
+pub fn is_tidal_running() -> Option<bool> {
+    match std::env::consts::OS {
+        "windows" => {
+            // Image names are case-insensitive on Windows; Update.exe alone is not a running client
+            let output = run_command("tasklist", &["/FI", "IMAGENAME eq TIDAL.exe", "/NH"])?;
+            Some(
+                String::from_utf8_lossy(&output.stdout)
+                    .to_ascii_lowercase()
+                    .contains("tidal.exe"),
+            )
+        }
+        "macos" | "linux" => {
+            let patterns: &[&str] = if std::env::consts::OS == "macos" {
+                &MACOS_PATTERNS
+            } else {
+                &LINUX_PATTERNS
+            };
+            let spared = own_process_tree();
+            let mut found = None;
+            for pattern in patterns {
+                if let Some(pids) = matching_pids(pattern, &spared) {
+                    if !pids.is_empty() {
+                        return Some(true);
+                    }
+                    found = Some(false);
+                }
+            }
+            found
+        }
+        _ => None,
+    }
+}
+
 pub struct KillTidalStep;
 
 #[async_trait]
 impl InstallStep for KillTidalStep {
-    fn name(&self) -> &str {
-        "Kill TIDAL"
+    fn id(&self) -> &'static str {
+        "kill_tidal"
+    }
+
+    fn name(&self) -> String {
+        t!("step-kill-tidal")
     }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
         let os = std::env::consts::OS;
 
         sublog_callback(SubLog {
-            message: format!("Detected OS: {}", os),
+            message: t!("kill-detected-os", os = os),
         });
 
         let mut executed = false;
@@ -27,16 +115,16 @@ impl InstallStep for KillTidalStep {
         match os {
             "windows" => {
                 sublog_callback(SubLog {
-                    message: "Killing TIDAL process(es) (Windows)".into(),
+                    message: t!("kill-running", os = "Windows"),
                 });
 
-                for image in ["TIDAL.exe", "Tidal.exe", "tidal.exe", "Update.exe"] {
+                for image in WINDOWS_IMAGES {
                     if let Some(output) = run_command("taskkill", &["/IM", image, "/T", "/F"]) {
                         executed = true;
                         if output.status.success() {
                             killed_any = true;
                             sublog_callback(SubLog {
-                                message: format!("Stopped process image: {}", image),
+                                message: t!("kill-stopped-image", image = image),
                             });
                         }
                     }
@@ -44,16 +132,17 @@ impl InstallStep for KillTidalStep {
             }
             "macos" => {
                 sublog_callback(SubLog {
-                    message: "Killing TIDAL process(es) (macOS)".into(),
+                    message: t!("kill-running", os = "macOS"),
                 });
 
-                for pattern in ["TIDAL", "Tidal"] {
-                    if let Some(output) = run_command("pkill", &["-f", pattern]) {
+                let spared = own_process_tree();
+                for pattern in MACOS_PATTERNS {
+                    if let Some(killed) = kill_matching(pattern, &spared) {
                         executed = true;
-                        if output.status.success() {
+                        if killed {
                             killed_any = true;
                             sublog_callback(SubLog {
-                                message: format!("Stopped process pattern: {}", pattern),
+                                message: t!("kill-stopped-pattern", pattern = pattern),
                             });
                         }
                     }
@@ -61,16 +150,17 @@ impl InstallStep for KillTidalStep {
             }
             "linux" => {
                 sublog_callback(SubLog {
-                    message: "Killing TIDAL process(es) (Linux)".into(),
+                    message: t!("kill-running", os = "Linux"),
                 });
 
-                for pattern in ["tidal-hifi", "tidal"] {
-                    if let Some(output) = run_command("pkill", &["-f", pattern]) {
+                let spared = own_process_tree();
+                for pattern in LINUX_PATTERNS {
+                    if let Some(killed) = kill_matching(pattern, &spared) {
                         executed = true;
-                        if output.status.success() {
+                        if killed {
                             killed_any = true;
                             sublog_callback(SubLog {
-                                message: format!("Stopped process pattern: {}", pattern),
+                                message: t!("kill-stopped-pattern", pattern = pattern),
                             });
                         }
                     }
@@ -79,28 +169,28 @@ impl InstallStep for KillTidalStep {
             _ => {
                 return StepResult {
                     success: false,
-                    message: "Unsupported operating system".into(),
+                    message: t!("step-unsupported-os"),
                 };
             }
         }
 
         if !executed {
             sublog_callback(SubLog {
-                message: "Warning: no kill command could be executed on this system".into(),
+                message: t!("kill-no-command"),
             });
         } else if !killed_any {
             sublog_callback(SubLog {
-                message: "No running TIDAL process found to kill".into(),
+                message: t!("kill-nothing-running"),
             });
         }
 
         sublog_callback(SubLog {
-            message: "Kill TIDAL step completed".into(),
+            message: t!("kill-completed"),
         });
 
         StepResult {
             success: true,
-            message: "Kill TIDAL completed (non-fatal)".into(),
+            message: t!("kill-done"),
         }
     }
 }
\ No newline at end of file
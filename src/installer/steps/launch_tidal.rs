@@ -1,5 +1,7 @@
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
 use crate::utils::fs_helpers::get_tidal_directory;
+use crate::utils::invoking_user::invoking_user;
 use async_trait::async_trait;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -12,7 +14,7 @@ pub struct LaunchTidalStep {
     pub suppress_console_window: bool,
 }
 
-enum LaunchCandidate {
+pub(crate) enum LaunchCandidate {
     Path {
         program: PathBuf,
         args: Vec<String>,
@@ -23,8 +25,80 @@ enum LaunchCandidate {
     },
 }
 
+impl LaunchCandidate {
+    /// Whether the spawned process is TIDAL itself rather than a launcher that hands off and exits
+    pub(crate) fn is_direct(&self) -> bool {
+        match self {
+            LaunchCandidate::Path { program, .. } => !program
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().eq_ignore_ascii_case("update"))
+                .unwrap_or(false),
+            LaunchCandidate::Command { program, .. } => program != "open",
+        }
+    }
+
+    pub(crate) fn is_available(&self) -> bool {
+        match self {
+            LaunchCandidate::Path { program, .. } => program.exists(),
+            LaunchCandidate::Command { .. } => true,
+        }
+    }
+
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            LaunchCandidate::Path { program, .. } => format!("{:?}", program),
+            LaunchCandidate::Command { program, args } => {
+                t!("launch-command", command = format!("{} {}", program, args.join(" ")))
+            }
+        }
+    }
+
+    pub(crate) fn command(&self, extra_args: &[&str]) -> Command {
+        let mut cmd = match self {
+            LaunchCandidate::Path { program, args } => {
+                let mut cmd = Command::new(program);
+                cmd.args(args);
+                cmd
+            }
+            LaunchCandidate::Command { program, args } => {
+                let mut cmd = Command::new(program);
+                cmd.args(args);
+                cmd
+            }
+        };
+        cmd.args(extra_args);
+        cmd
+    }
+}
+
+/// Applies the platform flags that keep TIDAL running independently of the installer and,
+/// when running under sudo/pkexec, hands the process to the invoking desktop user
+pub(crate) fn configure_detached(cmd: &mut Command, suppress_console_window: bool) {
+    if let Some(user) = invoking_user() {
+        user.apply_to_command(cmd);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        const DETACHED_PROCESS: u32 = 0x00000008;
+        const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+        let mut flags = DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP;
+        if suppress_console_window {
+            flags |= CREATE_NO_WINDOW;
+        }
+        cmd.creation_flags(flags);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (cmd, suppress_console_window);
+    }
+}
+
 #[cfg(target_os = "linux")]
-fn is_running_as_root_linux() -> bool {
+pub(crate) fn is_running_as_root_linux() -> bool {
     match Command::new("id").arg("-u").output() {
         Ok(output) => {
             if !output.status.success() {
@@ -39,11 +113,11 @@ fn is_running_as_root_linux() -> bool {
 }
 
 #[cfg(not(target_os = "linux"))]
-fn is_running_as_root_linux() -> bool {
+pub(crate) fn is_running_as_root_linux() -> bool {
     false
 }
 
-fn build_launch_candidates(resources_path: &Path) -> Vec<LaunchCandidate> {
+pub(crate) fn build_launch_candidates(resources_path: &Path) -> Vec<LaunchCandidate> {
     match std::env::consts::OS {
         "windows" => {
             let app_dir = resources_path.parent().unwrap_or(resources_path);
@@ -113,81 +187,63 @@ fn build_launch_candidates(resources_path: &Path) -> Vec<LaunchCandidate> {
     }
 }
 
-fn try_launch(
+pub(crate) fn try_launch(
     candidate: &LaunchCandidate,
     suppress_console_window: bool,
     sublog_callback: &(dyn Fn(SubLog) + Send + Sync),
 ) -> bool {
-    const DETACHED_PROCESS: u32 = 0x00000008;
-    const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
-    const CREATE_NO_WINDOW: u32 = 0x08000000;
-
-    let spawn_detached = |cmd: &mut Command| {
-        #[cfg(target_os = "windows")]
-        {
-            let mut flags = DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP;
-            if suppress_console_window {
-                flags |= CREATE_NO_WINDOW;
-            }
-            cmd.creation_flags(flags);
-        }
-        cmd.spawn()
-    };
+    if !candidate.is_available() {
+        return false;
+    }
 
-    match candidate {
-        LaunchCandidate::Path { program, args } => {
-            if !program.exists() {
-                return false;
-            }
+    let mut cmd = candidate.command(&[]);
+    configure_detached(&mut cmd, suppress_console_window);
 
-            match spawn_detached(Command::new(program).args(args)) {
-                Ok(_) => {
-                    sublog_callback(SubLog {
-                        message: format!("TIDAL launched detached with {:?}", program),
-                    });
-                    true
-                }
-                Err(err) => {
-                    sublog_callback(SubLog {
-                        message: format!("Failed launching {:?}: {}", program, err),
-                    });
-                    false
-                }
-            }
+    match cmd.spawn() {
+        Ok(_) => {
+            sublog_callback(SubLog {
+                message: t!("launch-launched", candidate = candidate.describe()),
+            });
+            true
+        }
+        Err(err) => {
+            sublog_callback(SubLog {
+                message: t!("launch-failed", candidate = candidate.describe(), error = err),
+            });
+            false
         }
-        LaunchCandidate::Command { program, args } => match spawn_detached(Command::new(program).args(args)) {
-            Ok(_) => {
-                sublog_callback(SubLog {
-                    message: format!("TIDAL launched detached with command '{} {}'", program, args.join(" ")),
-                });
-                true
-            }
-            Err(err) => {
-                sublog_callback(SubLog {
-                    message: format!("Failed launching '{}' command: {}", program, err),
-                });
-                false
-            }
-        },
     }
 }
 
 #[async_trait]
 impl InstallStep for LaunchTidalStep {
-    fn name(&self) -> &str {
-        "Launch TIDAL"
+    fn id(&self) -> &'static str {
+        "launch_tidal"
+    }
+
+    fn name(&self) -> String {
+        t!("step-launch-tidal")
     }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
         if is_running_as_root_linux() {
-            sublog_callback(SubLog {
-                message: "Skipping auto-launch: running as root on Linux is not supported by Electron (sandbox restriction). Launch TIDAL manually as your normal user.".into(),
-            });
+            match invoking_user() {
+                Some(user) => {
+                    sublog_callback(SubLog {
+                        message: t!("launch-as-user", user = user.name),
+                    });
+                }
+                None => {
+                    sublog_callback(SubLog {
+                        message: t!("launch-root-skipped"),
+                    });
 
-            return StepResult {
-                success: true,
-                message: "Installation finished; TIDAL auto-launch skipped for root Linux session".into(),
-            };
+                    return StepResult {
+                        success: true,
+                        message: t!("launch-root-done"),
+                    };
+                }
+            }
         }
 
         let resources_path = if let Some(path) = &self.overwrite_path {
@@ -197,11 +253,11 @@ impl InstallStep for LaunchTidalStep {
                 Ok(path) => path,
                 Err(err) => {
                     sublog_callback(SubLog {
-                        message: format!("Skipping launch: could not resolve TIDAL path ({})", err),
+                        message: t!("launch-path-unresolved", error = err),
                     });
                     return StepResult {
                         success: true,
-                        message: "Installation finished; TIDAL auto-launch skipped".into(),
+                        message: t!("launch-skipped"),
                     };
                 }
             }
@@ -209,16 +265,16 @@ impl InstallStep for LaunchTidalStep {
 
         if resources_path.as_os_str().is_empty() || !resources_path.exists() {
             sublog_callback(SubLog {
-                message: "Skipping launch: TIDAL resources path is not available".into(),
+                message: t!("launch-path-unavailable"),
             });
             return StepResult {
                 success: true,
-                message: "Installation finished; TIDAL auto-launch skipped".into(),
+                message: t!("launch-skipped"),
             };
         }
 
         sublog_callback(SubLog {
-            message: format!("Trying to relaunch TIDAL from {:?}", resources_path),
+            message: t!("launch-trying", path = format!("{:?}", resources_path)),
         });
 
         let candidates = build_launch_candidates(&resources_path);
@@ -226,14 +282,14 @@ impl InstallStep for LaunchTidalStep {
             if try_launch(candidate, self.suppress_console_window, sublog_callback) {
                 return StepResult {
                     success: true,
-                    message: "TIDAL relaunched successfully".into(),
+                    message: t!("launch-done"),
                 };
             }
         }
 
         StepResult {
             success: true,
-            message: "Installation finished; no runnable TIDAL binary found for auto-launch".into(),
+            message: t!("launch-no-binary"),
         }
     }
 }
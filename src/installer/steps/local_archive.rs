@@ -0,0 +1,151 @@
+use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::installer::steps::download_luna::{validate_zip_bytes, write_luna_zip};
+use crate::installer::work_dir::WorkDir;
+use crate::t;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::io::{Cursor, Read};
+use std::path::PathBuf;
+use zip::ZipArchive;
+
+/// Channel recorded in receipts of archives installed from disk
+pub const LOCAL_ARCHIVE_CHANNEL: &str = "local";
+
+/// How many top-level entries a summary names before it stops listing
+const MAX_LISTED_ENTRIES: usize = 8;
+
+/// What a Luna archive contains, shown before installing it
+#[derive(Debug, Clone)]
+pub struct ArchiveSummary {
+    pub size: u64,
+    pub files: usize,
+    pub unpacked_size: u64,
+    /// First entries of the top level, sorted, with `/` after directories
+    pub top_level: Vec<String>,
+    /// More top-level entries than `top_level` lists
+    pub more_entries: usize,
+    /// `version` of a top-level package.json, if the archive has one
+    pub version: Option<String>,
+}
+
+/// Checks `bytes` like a downloaded archive and lists what it would install
+pub fn summarize_archive(bytes: &[u8]) -> Result<ArchiveSummary, String> {
+    validate_zip_bytes(bytes)?;
+    let mut archive =
+        ZipArchive::new(Cursor::new(bytes)).map_err(|err| t!("zip-parse-failed", error = err))?;
+
+    let mut files = 0;
+    let mut unpacked_size = 0;
+    let mut top_level = Vec::new();
+    for index in 0..archive.len() {
+        let entry = archive
+            .by_index(index)
+            .map_err(|err| t!("extract-entry-failed", error = err))?;
+        if entry.is_file() {
+            files += 1;
+            unpacked_size += entry.size();
+        }
+
+        let name = entry.name().trim_start_matches("./");
+        let top = match name.split_once('/') {
+            Some((dir, _)) => format!("{}/", dir),
+            None => name.to_string(),
+        };
+        if !top.is_empty() && top != "/" && !top_level.contains(&top) {
+            top_level.push(top);
+        }
+    }
+    top_level.sort();
+    let more_entries = top_level.len().saturating_sub(MAX_LISTED_ENTRIES);
+    top_level.truncate(MAX_LISTED_ENTRIES);
+
+    let version = archive.by_name("package.json").ok().and_then(|mut entry| {
+        let mut contents = String::new();
+        entry.read_to_string(&mut contents).ok()?;
+        let package: Value = serde_json::from_str(&contents).ok()?;
+        package.get("version")?.as_str().map(str::to_string)
+    });
+
+    Ok(ArchiveSummary {
+        size: bytes.len() as u64,
+        files,
+        unpacked_size,
+        top_level,
+        more_entries,
+        version,
+    })
+}
+
+/// Takes the place of `DownloadLunaStep` for an archive that is already on disk
+pub struct LocalArchiveStep {
+    pub archive: PathBuf,
+    pub work_dir: WorkDir,
+}
+
+#[async_trait]
+impl InstallStep for LocalArchiveStep {
+    fn id(&self) -> &'static str {
+        "local_archive"
+    }
+
+    fn name(&self) -> String {
+        t!("step-local-archive")
+    }
+
+    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
+        let temp_dir = match self.work_dir.path() {
+            Ok(dir) => dir,
+            Err(err) => {
+                return StepResult {
+                    success: false,
+                    message: t!("setup-temp-dir-failed", error = err),
+                };
+            }
+        };
+
+        sublog_callback(SubLog {
+            message: t!("local-archive-reading", path = format!("{:?}", self.archive)),
+        });
+        let bytes = match tokio::fs::read(&self.archive).await {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                return StepResult {
+                    success: false,
+                    message: t!("local-archive-read-failed", error = err),
+                };
+            }
+        };
+
+        match summarize_archive(&bytes) {
+            Ok(summary) => sublog_callback(SubLog {
+                message: t!(
+                    "local-archive-summary",
+                    files = summary.files,
+                    size = format!("{:.1}", summary.unpacked_size as f64 / (1024.0 * 1024.0))
+                ),
+            }),
+            Err(err) => {
+                return StepResult {
+                    success: false,
+                    message: t!("local-archive-invalid", path = format!("{:?}", self.archive), error = err),
+                };
+            }
+        }
+
+        match write_luna_zip(&temp_dir, &bytes).await {
+            Ok((zip_path, size)) => {
+                sublog_callback(SubLog {
+                    message: t!("local-archive-copied", path = format!("{:?}", zip_path), size = size),
+                });
+                StepResult {
+                    success: true,
+                    message: t!("local-archive-done"),
+                }
+            }
+            Err(message) => StepResult {
+                success: false,
+                message,
+            },
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod setup;
 pub mod download_luna;
+pub mod local_archive;
 pub mod extract_luna;
 pub mod copy_asar_install;
 pub mod insert_luna;
@@ -8,4 +9,9 @@ pub mod sign_tidal;
 pub mod launch_tidal;
 pub mod reinstall_cleanup;
 pub mod uninstall;
-pub mod copy_asar_uninstall;
\ No newline at end of file
+pub mod copy_asar_uninstall;
+pub mod verify_luna;
+pub mod restore_ownership;
+pub mod write_receipt;
+pub mod rollback_luna;
+pub mod wipe_luna_data;
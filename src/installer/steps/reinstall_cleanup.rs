@@ -1,4 +1,6 @@
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
+use crate::utils::receipt::{keep_as_previous, read_receipt};
 use async_trait::async_trait;
 use std::path::PathBuf;
 use tokio::fs;
@@ -9,8 +11,12 @@ pub struct ReinstallCleanupStep {
 
 #[async_trait]
 impl InstallStep for ReinstallCleanupStep {
-    fn name(&self) -> &str {
-        "Reinstall Cleanup"
+    fn id(&self) -> &'static str {
+        "reinstall_cleanup"
+    }
+
+    fn name(&self) -> String {
+        t!("step-reinstall-cleanup")
     }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
@@ -21,11 +27,11 @@ impl InstallStep for ReinstallCleanupStep {
                 Ok(path) => path,
                 Err(err) => {
                     sublog_callback(SubLog {
-                        message: format!("Skipping reinstall cleanup: could not resolve TIDAL path ({})", err),
+                        message: t!("cleanup-path-unresolved", error = err),
                     });
                     return StepResult {
                         success: true,
-                        message: "Reinstall cleanup skipped".into(),
+                        message: t!("cleanup-skipped"),
                     };
                 }
             }
@@ -33,11 +39,11 @@ impl InstallStep for ReinstallCleanupStep {
 
         if !tidal_path.exists() {
             sublog_callback(SubLog {
-                message: "Skipping reinstall cleanup: TIDAL path does not exist".into(),
+                message: t!("cleanup-path-missing"),
             });
             return StepResult {
                 success: true,
-                message: "Reinstall cleanup skipped".into(),
+                message: t!("cleanup-skipped"),
             };
         }
 
@@ -47,46 +53,55 @@ impl InstallStep for ReinstallCleanupStep {
 
         if original_asar.exists() {
             sublog_callback(SubLog {
-                message: "Attempting to restore original app.asar before reinstall".into(),
+                message: t!("cleanup-restoring-asar"),
             });
 
-            if app_asar.exists() {
-                if let Err(err) = fs::remove_file(&app_asar).await {
-                    sublog_callback(SubLog {
-                        message: format!("Warning: failed removing app.asar before restore: {}", err),
-                    });
-                }
+            if app_asar.exists()
+                && let Err(err) = fs::remove_file(&app_asar).await
+            {
+                sublog_callback(SubLog {
+                    message: t!("cleanup-remove-asar-failed", error = err),
+                });
             }
 
             if let Err(err) = fs::copy(&original_asar, &app_asar).await {
                 sublog_callback(SubLog {
-                    message: format!("Warning: failed restoring app.asar from original.asar: {}", err),
+                    message: t!("cleanup-restore-asar-failed", error = err),
                 });
             }
         } else {
             sublog_callback(SubLog {
-                message: "original.asar not found, skipping ASAR restore for reinstall".into(),
+                message: t!("cleanup-no-original"),
             });
         }
 
         if luna_dir.exists() {
+            let version = read_receipt(&tidal_path)
+                .map(|receipt| receipt.version)
+                .unwrap_or_else(|| t!("unknown-version"));
             sublog_callback(SubLog {
-                message: "Removing existing TidaLuna app directory before reinstall".into(),
+                message: t!("cleanup-keeping-previous", version = version),
             });
-            if let Err(err) = fs::remove_dir_all(&luna_dir).await {
+
+            if let Err(err) = keep_as_previous(&tidal_path) {
                 sublog_callback(SubLog {
-                    message: format!("Warning: failed removing existing app directory: {}", err),
+                    message: t!("cleanup-keep-failed", error = err),
                 });
+                if let Err(err) = fs::remove_dir_all(&luna_dir).await {
+                    sublog_callback(SubLog {
+                        message: t!("cleanup-remove-app-failed", error = err),
+                    });
+                }
             }
         } else {
             sublog_callback(SubLog {
-                message: "No existing TidaLuna app directory found; cleanup not needed".into(),
+                message: t!("cleanup-not-needed"),
             });
         }
 
         StepResult {
             success: true,
-            message: "Reinstall cleanup completed".into(),
+            message: t!("cleanup-done"),
         }
     }
 }
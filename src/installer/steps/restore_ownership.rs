@@ -0,0 +1,91 @@
+use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
+use crate::utils::fs_helpers::get_tidal_directory;
+use crate::utils::invoking_user::invoking_user;
+use crate::utils::receipt::{PREVIOUS_DIR, RECEIPT_FILE};
+use async_trait::async_trait;
+use std::path::PathBuf;
+
+/// Hands files the installer wrote back to the sudo/pkexec user when TIDAL lives in their home
+pub struct RestoreOwnershipStep {
+    pub overwrite_path: Option<PathBuf>,
+}
+
+#[async_trait]
+impl InstallStep for RestoreOwnershipStep {
+    fn id(&self) -> &'static str {
+        "restore_ownership"
+    }
+
+    fn name(&self) -> String {
+        t!("step-restore-ownership")
+    }
+
+    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
+        let Some(user) = invoking_user() else {
+            sublog_callback(SubLog {
+                message: t!("ownership-not-elevated"),
+            });
+            return StepResult {
+                success: true,
+                message: t!("ownership-not-needed"),
+            };
+        };
+
+        let tidal_path = match &self.overwrite_path {
+            Some(path) => path.clone(),
+            None => match get_tidal_directory().await {
+                Ok(path) => path,
+                Err(err) => {
+                    sublog_callback(SubLog {
+                        message: t!("ownership-path-unresolved", error = err),
+                    });
+                    return StepResult {
+                        success: true,
+                        message: t!("ownership-skipped"),
+                    };
+                }
+            },
+        };
+
+        if !user.owns(&tidal_path) {
+            sublog_callback(SubLog {
+                message: t!(
+                    "ownership-foreign-path",
+                    path = format!("{:?}", tidal_path),
+                    user = user.name
+                ),
+            });
+            return StepResult {
+                success: true,
+                message: t!("ownership-system-location"),
+            };
+        }
+
+        for name in ["app", "app.asar", "original.asar", RECEIPT_FILE, PREVIOUS_DIR] {
+            let target = tidal_path.join(name);
+            if !target.exists() {
+                continue;
+            }
+
+            match user.chown_recursive(&target) {
+                Ok(count) => sublog_callback(SubLog {
+                    message: t!(
+                        "ownership-changed",
+                        count = count,
+                        path = format!("{:?}", target),
+                        user = user.name
+                    ),
+                }),
+                Err(err) => sublog_callback(SubLog {
+                    message: t!("ownership-change-failed", path = format!("{:?}", target), error = err),
+                }),
+            }
+        }
+
+        StepResult {
+            success: true,
+            message: t!("ownership-done", user = user.name),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
+use crate::utils::receipt::{previous_install, read_receipt, record_patched, PREVIOUS_DIR, RECEIPT_FILE};
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// Moves `app/` and its receipt from `from` to `to`, skipping whichever is missing
+async fn move_install(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to).await?;
+    if from.join("app").exists() {
+        fs::rename(from.join("app"), to.join("app")).await?;
+    }
+    if from.join(RECEIPT_FILE).exists() {
+        fs::rename(from.join(RECEIPT_FILE), to.join(RECEIPT_FILE)).await?;
+    }
+    Ok(())
+}
+
+/// Swaps the Luna install in the previous slot with the current one, without any download
+pub struct RollbackLunaStep {
+    pub overwrite_path: PathBuf,
+}
+
+#[async_trait]
+impl InstallStep for RollbackLunaStep {
+    fn id(&self) -> &'static str {
+        "rollback_luna"
+    }
+
+    fn name(&self) -> String {
+        t!("step-rollback-luna")
+    }
+
+    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
+        let tidal_path = &self.overwrite_path;
+
+        let Some(previous) = previous_install(tidal_path) else {
+            return StepResult {
+                success: false,
+                message: t!("rollback-no-previous", path = format!("{:?}", tidal_path.join(PREVIOUS_DIR))),
+            };
+        };
+
+        let current_version = read_receipt(tidal_path).map(|receipt| receipt.version);
+        sublog_callback(SubLog {
+            message: t!(
+                "rollback-swapping",
+                current = current_version.unwrap_or_else(|| t!("unknown-version")),
+                previous = previous.version_label()
+            ),
+        });
+
+        let staging = tidal_path.join(format!("{}.swap", PREVIOUS_DIR));
+        if staging.exists() {
+            let _ = fs::remove_dir_all(&staging).await;
+        }
+
+        if let Err(err) = move_install(tidal_path, &staging).await {
+            return StepResult {
+                success: false,
+                message: t!("rollback-set-aside-failed", error = err),
+            };
+        }
+
+        if let Err(err) = move_install(&previous.dir, tidal_path).await {
+            // Put the current install back so TIDAL is left as it was
+            let _ = move_install(&staging, tidal_path).await;
+            return StepResult {
+                success: false,
+                message: t!("rollback-restore-failed", error = err),
+            };
+        }
+
+        let _ = fs::remove_dir_all(&previous.dir).await;
+        if staging.join("app").exists() {
+            match fs::rename(&staging, &previous.dir).await {
+                Ok(()) => sublog_callback(SubLog {
+                    message: t!("rollback-kept-replaced"),
+                }),
+                Err(err) => sublog_callback(SubLog {
+                    message: t!("rollback-keep-failed", error = err),
+                }),
+            }
+        } else {
+            let _ = fs::remove_dir_all(&staging).await;
+        }
+
+        let _ = record_patched(tidal_path, read_receipt(tidal_path));
+
+        StepResult {
+            success: true,
+            message: t!("rollback-done", version = previous.version_label()),
+        }
+    }
+}
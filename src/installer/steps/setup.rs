@@ -1,39 +1,43 @@
 use async_trait::async_trait;
 use std::path::PathBuf;
-use tokio::fs;
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::installer::work_dir::WorkDir;
+use crate::t;
 use crate::utils::fs_helpers::{get_tidal_directory, has_tidal_app_asar};
 
 /// SetupStep: creates temporary directory and checks if Tidal is installed
 pub struct SetupStep {
     /// Optional override path (like `options.overwrite_path`)
     pub overwrite_path: Option<PathBuf>,
+    pub work_dir: WorkDir,
 }
 
 #[async_trait]
 impl InstallStep for SetupStep {
-    fn name(&self) -> &str {
-        "Setup"
+    fn id(&self) -> &'static str {
+        "setup"
     }
 
-    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
-        let tmp_dir = std::env::temp_dir().join("TidaLunaInstaller");
-        sublog_callback(SubLog {
-            message: format!("Getting system temporary directory: {:?}", tmp_dir),
-        });
+    fn name(&self) -> String {
+        t!("step-setup")
+    }
 
-        if let Err(err) = fs::create_dir_all(&tmp_dir).await {
-            return StepResult {
-                success: false,
-                message: format!("Failed to create temporary directory: {}", err),
-            };
-        }
+    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
+        let tmp_dir = match self.work_dir.path() {
+            Ok(dir) => dir,
+            Err(err) => {
+                return StepResult {
+                    success: false,
+                    message: t!("setup-temp-dir-failed", error = err),
+                };
+            }
+        };
         sublog_callback(SubLog {
-            message: format!("Temporary directory created: {:?}", tmp_dir),
+            message: t!("setup-temp-dir-created", path = format!("{:?}", tmp_dir)),
         });
 
         sublog_callback(SubLog {
-            message: "Checking if Tidal is installed".into(),
+            message: t!("setup-checking-tidal"),
         });
 
         let tidal_path: PathBuf = match &self.overwrite_path {
@@ -43,7 +47,7 @@ impl InstallStep for SetupStep {
                 _ => {
                     return StepResult {
                         success: false,
-                        message: "Tidal is not installed or path could not be found".into(),
+                        message: t!("step-tidal-not-found"),
                     }
                 }
             },
@@ -52,24 +56,24 @@ impl InstallStep for SetupStep {
         if !tidal_path.exists() {
             return StepResult {
                 success: false,
-                message: format!("Tidal path does not exist: {:?}", tidal_path),
+                message: t!("setup-tidal-path-missing", path = format!("{:?}", tidal_path)),
             };
         }
 
         if !has_tidal_app_asar(&tidal_path) {
             return StepResult {
                 success: false,
-                message: "app.asar not found — Tidal is not installed correctly".into(),
+                message: t!("setup-app-asar-missing"),
             };
         }
 
         sublog_callback(SubLog {
-            message: "Tidal is installed and valid".into(),
+            message: t!("setup-tidal-valid"),
         });
 
         StepResult {
             success: true,
-            message: "Setup step completed successfully".into(),
+            message: t!("setup-done"),
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
 use crate::utils::fs_helpers::get_tidal_directory;
 use async_trait::async_trait;
 use std::process::Command;
@@ -8,8 +9,12 @@ pub struct SignTidalStep;
 
 #[async_trait]
 impl InstallStep for SignTidalStep {
-    fn name(&self) -> &str {
-        "Sign TIDAL"
+    fn id(&self) -> &'static str {
+        "sign_tidal"
+    }
+
+    fn name(&self) -> String {
+        t!("step-sign-tidal")
     }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
@@ -18,25 +23,25 @@ impl InstallStep for SignTidalStep {
         match os {
             "windows" => {
                 sublog_callback(SubLog {
-                    message: "No need to sign TIDAL on Windows, skipping...".into(),
+                    message: t!("sign-not-needed", os = "Windows"),
                 });
                 StepResult {
                     success: true,
-                    message: "Signing skipped on Windows".into(),
+                    message: t!("sign-skipped", os = "Windows"),
                 }
             }
             "linux" => {
                 sublog_callback(SubLog {
-                    message: "No need to sign TIDAL on Linux, skipping...".into(),
+                    message: t!("sign-not-needed", os = "Linux"),
                 });
                 StepResult {
                     success: true,
-                    message: "Signing skipped on Linux".into(),
+                    message: t!("sign-skipped", os = "Linux"),
                 }
             }
             "macos" => {
                 sublog_callback(SubLog {
-                    message: "Signing TIDAL on macOS...".into(),
+                    message: t!("sign-running"),
                 });
 
                 let sign_target = match get_tidal_directory().await {
@@ -49,7 +54,7 @@ impl InstallStep for SignTidalStep {
                 };
 
                 sublog_callback(SubLog {
-                    message: format!("Using codesign target: {:?}", sign_target),
+                    message: t!("sign-target", path = format!("{:?}", sign_target)),
                 });
 
                 let output = Command::new("codesign")
@@ -78,27 +83,27 @@ impl InstallStep for SignTidalStep {
                             }
                             return StepResult {
                                 success: false,
-                                message: "Error signing TIDAL on macOS".into(),
+                                message: t!("sign-error"),
                             };
                         }
 
                         sublog_callback(SubLog {
-                            message: "TIDAL signed successfully on macOS".into(),
+                            message: t!("sign-signed"),
                         });
                         StepResult {
                             success: true,
-                            message: "Signing completed successfully".into(),
+                            message: t!("sign-done"),
                         }
                     }
                     Err(err) => StepResult {
                         success: false,
-                        message: format!("Failed to execute codesign: {}", err),
+                        message: t!("sign-codesign-failed", error = err),
                     },
                 }
             }
             _ => StepResult {
                 success: false,
-                message: "Unsupported operating system".into(),
+                message: t!("step-unsupported-os"),
             },
         }
     }
@@ -1,4 +1,6 @@
 use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
+use crate::utils::receipt::{forget_patched, keep_as_previous, read_receipt, RECEIPT_FILE};
 use async_trait::async_trait;
 use std::path::PathBuf;
 use tokio::fs;
@@ -6,12 +8,18 @@ use tokio::fs;
 /// Step to uninstall TidaLuna / Neptune
 pub struct UninstallStep {
     pub overwrite_path: Option<PathBuf>,
+    /// Moves the install into the previous slot instead of deleting it, so `rollback` restores it
+    pub keep_backup: bool,
 }
 
 #[async_trait]
 impl InstallStep for UninstallStep {
-    fn name(&self) -> &str {
-        "Uninstall TidaLuna"
+    fn id(&self) -> &'static str {
+        "uninstall_luna"
+    }
+
+    fn name(&self) -> String {
+        t!("step-uninstall")
     }
 
     async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
@@ -22,20 +30,20 @@ impl InstallStep for UninstallStep {
                 Ok(p) if !p.as_os_str().is_empty() => p,
                 Ok(_) => {
                     sublog_callback(SubLog {
-                        message: "Tidal path could not be resolved".into(),
+                        message: t!("step-path-unresolved"),
                     });
                     return StepResult {
                         success: false,
-                        message: "Invalid Tidal path".into(),
+                        message: t!("step-invalid-path"),
                     };
                 }
                 Err(err) => {
                     sublog_callback(SubLog {
-                        message: format!("Tidal is not installed: {}", err),
+                        message: t!("step-tidal-missing", error = err),
                     });
                     return StepResult {
                         success: false,
-                        message: "Invalid Tidal path".into(),
+                        message: t!("step-invalid-path"),
                     };
                 }
             }
@@ -43,47 +51,71 @@ impl InstallStep for UninstallStep {
 
         if !tidal_path.exists() {
             sublog_callback(SubLog {
-                message: "Tidal is not installed, skipping uninstallation".into(),
+                message: t!("uninstall-no-tidal"),
             });
             return StepResult {
                 success: false,
-                message: "Invalid Tidal path".into(),
+                message: t!("step-invalid-path"),
             };
         }
 
         sublog_callback(SubLog {
-            message: "Uninstalling TidaLuna / Neptune...".into(),
+            message: t!("uninstall-running"),
         });
 
         let luna_dir = tidal_path.join("app");
+        // Luna is meant to be gone, so `watch` must not treat its absence as a lost injection
+        let _ = forget_patched(&tidal_path);
 
         if !luna_dir.exists() {
             sublog_callback(SubLog {
-                message: "TidaLuna / Neptune is not installed, skipping uninstallation...".into(),
+                message: t!("uninstall-no-luna"),
             });
             return StepResult {
                 success: true,
-                message: "Nothing to uninstall".into(),
+                message: t!("uninstall-nothing"),
             };
         }
 
+        if self.keep_backup {
+            let version = read_receipt(&tidal_path)
+                .map(|receipt| receipt.version)
+                .unwrap_or_else(|| t!("unknown-version"));
+            sublog_callback(SubLog {
+                message: t!("uninstall-keeping-backup", version = version),
+            });
+            match keep_as_previous(&tidal_path) {
+                Ok(()) => {
+                    return StepResult {
+                        success: true,
+                        message: t!("uninstall-done"),
+                    };
+                }
+                Err(err) => sublog_callback(SubLog {
+                    message: t!("uninstall-backup-failed", error = err),
+                }),
+            }
+        }
+
         match fs::remove_dir_all(&luna_dir).await {
             Ok(_) => {
+                // The previous slot stays so `rollback` can bring Luna back without a download
+                let _ = fs::remove_file(tidal_path.join(RECEIPT_FILE)).await;
                 sublog_callback(SubLog {
-                    message: "TidaLuna / Neptune uninstalled successfully".into(),
+                    message: t!("uninstall-removed"),
                 });
                 StepResult {
                     success: true,
-                    message: "Uninstallation completed successfully".into(),
+                    message: t!("uninstall-done"),
                 }
             }
             Err(err) => {
                 sublog_callback(SubLog {
-                    message: format!("Error uninstalling TidaLuna / Neptune: {}", err),
+                    message: t!("uninstall-error", error = err),
                 });
                 StepResult {
                     success: false,
-                    message: format!("Failed to uninstall: {}", err),
+                    message: t!("uninstall-failed", error = err),
                 }
             }
         }
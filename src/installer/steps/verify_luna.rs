@@ -0,0 +1,296 @@
+use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
+use crate::installer::steps::launch_tidal::{
+    build_launch_candidates, configure_detached, is_running_as_root_linux, try_launch,
+};
+use crate::utils::fs_helpers::get_tidal_directory;
+use crate::utils::invoking_user::invoking_user;
+use async_trait::async_trait;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Stdio};
+use std::time::{Duration, Instant};
+use tokio::fs;
+
+/// Case-insensitive prefix of the lines Luna's loader logs inside TIDAL (`[Luna]`, `[Luna.native]`).
+/// Bare names like "tidaluna" also appear in install paths and are no proof Luna ran.
+const READY_MARKER: &str = "[luna";
+
+/// Case-insensitive markers Electron prints when the main process dies during startup
+const CRASH_MARKERS: [&str; 4] = [
+    "a javascript error occurred in the main process",
+    "uncaught exception",
+    "cannot find module",
+    "fatal:",
+];
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Launches TIDAL with logging enabled and watches it to confirm Luna actually loaded
+pub struct VerifyLunaStep {
+    pub overwrite_path: Option<PathBuf>,
+    pub suppress_console_window: bool,
+    pub timeout_secs: u64,
+    /// Restore the backed-up app.asar and remove Luna if verification fails
+    pub rollback_on_failure: bool,
+}
+
+enum VerifyOutcome {
+    Ready,
+    /// TIDAL stayed up until the timeout, but Luna never logged anything
+    NotLoaded,
+    Crashed(String),
+}
+
+fn scan_log(log_path: &Path) -> Option<VerifyOutcome> {
+    let contents = std::fs::read(log_path).ok()?;
+    let contents = String::from_utf8_lossy(&contents).to_lowercase();
+
+    for line in contents.lines() {
+        if let Some(marker) = CRASH_MARKERS.iter().find(|marker| line.contains(*marker)) {
+            return Some(VerifyOutcome::Crashed(t!(
+                "verify-startup-error",
+                marker = marker,
+                line = line.trim()
+            )));
+        }
+    }
+
+    if contents.contains(READY_MARKER) {
+        return Some(VerifyOutcome::Ready);
+    }
+
+    None
+}
+
+async fn watch_process(child: &mut Child, log_path: &Path, timeout: Duration) -> VerifyOutcome {
+    let started = Instant::now();
+
+    loop {
+        if let Some(outcome) = scan_log(log_path) {
+            return outcome;
+        }
+
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                // The process may have flushed a crash reason right before exiting
+                if let Some(outcome @ VerifyOutcome::Crashed(_)) = scan_log(log_path) {
+                    return outcome;
+                }
+                return VerifyOutcome::Crashed(t!(
+                    "verify-exited",
+                    seconds = format!("{:.1}", started.elapsed().as_secs_f32()),
+                    status = status
+                ));
+            }
+            Ok(None) => {}
+            Err(err) => {
+                return VerifyOutcome::Crashed(t!("verify-poll-failed", error = err));
+            }
+        }
+
+        if started.elapsed() >= timeout {
+            return VerifyOutcome::NotLoaded;
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+async fn rollback_to_original_asar(
+    tidal_path: &Path,
+    sublog_callback: &(dyn Fn(SubLog) + Send + Sync),
+) -> Result<(), String> {
+    let original_asar = tidal_path.join("original.asar");
+    let app_asar = tidal_path.join("app.asar");
+    let luna_dir = tidal_path.join("app");
+
+    if !original_asar.exists() {
+        return Err(t!("verify-no-backup"));
+    }
+
+    sublog_callback(SubLog {
+        message: t!("verify-rollback-asar"),
+    });
+
+    if app_asar.exists() {
+        fs::remove_file(&app_asar)
+            .await
+            .map_err(|err| t!("verify-remove-asar-failed", error = err))?;
+    }
+
+    fs::copy(&original_asar, &app_asar)
+        .await
+        .map_err(|err| t!("verify-restore-asar-failed", error = err))?;
+
+    if luna_dir.exists() {
+        sublog_callback(SubLog {
+            message: t!("verify-rollback-app"),
+        });
+        fs::remove_dir_all(&luna_dir)
+            .await
+            .map_err(|err| t!("verify-remove-app-failed", error = err))?;
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl InstallStep for VerifyLunaStep {
+    fn id(&self) -> &'static str {
+        "verify_luna"
+    }
+
+    fn name(&self) -> String {
+        t!("step-verify-luna")
+    }
+
+    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
+        if is_running_as_root_linux() {
+            match invoking_user() {
+                Some(user) => {
+                    sublog_callback(SubLog {
+                        message: t!("verify-as-user", user = user.name),
+                    });
+                }
+                None => {
+                    sublog_callback(SubLog {
+                        message: t!("verify-root-skipped"),
+                    });
+
+                    return StepResult {
+                        success: true,
+                        message: t!("verify-root-done"),
+                    };
+                }
+            }
+        }
+
+        let resources_path = match &self.overwrite_path {
+            Some(path) => path.clone(),
+            None => match get_tidal_directory().await {
+                Ok(path) => path,
+                Err(err) => {
+                    return StepResult {
+                        success: false,
+                        message: t!("verify-path-unresolved", error = err),
+                    };
+                }
+            },
+        };
+
+        // A private per-run directory: this runs as root under sudo, and a fixed path in the
+        // shared temp dir could be pre-planted as a symlink or clobbered by a concurrent run
+        let log_dir = match tempfile::Builder::new().prefix("TidaLunaInstaller-verify-").tempdir() {
+            Ok(dir) => dir,
+            Err(err) => {
+                return StepResult {
+                    success: false,
+                    message: t!("verify-log-dir-failed", error = err),
+                };
+            }
+        };
+        let log_path = log_dir.path().join("tidal-startup.log");
+
+        let candidates = build_launch_candidates(&resources_path);
+        let mut launched = None;
+
+        for candidate in candidates.iter().filter(|c| c.is_direct() && c.is_available()) {
+            let (stdout, stderr) = match File::create(&log_path)
+                .and_then(|file| Ok((file.try_clone()?, file)))
+            {
+                Ok(handles) => handles,
+                Err(err) => {
+                    return StepResult {
+                        success: false,
+                        message: t!("verify-log-file-failed", error = err),
+                    };
+                }
+            };
+
+            let mut cmd = candidate.command(&["--enable-logging"]);
+            cmd.stdin(Stdio::null())
+                .stdout(Stdio::from(stdout))
+                .stderr(Stdio::from(stderr));
+            configure_detached(&mut cmd, self.suppress_console_window);
+
+            match cmd.spawn() {
+                Ok(child) => {
+                    sublog_callback(SubLog {
+                        message: t!("verify-launched", candidate = candidate.describe()),
+                    });
+                    launched = Some(child);
+                    break;
+                }
+                Err(err) => {
+                    sublog_callback(SubLog {
+                        message: t!("launch-failed", candidate = candidate.describe(), error = err),
+                    });
+                }
+            }
+        }
+
+        let Some(mut child) = launched else {
+            return StepResult {
+                success: false,
+                message: t!("verify-no-binary"),
+            };
+        };
+
+        sublog_callback(SubLog {
+            message: t!(
+                "verify-watching",
+                seconds = self.timeout_secs,
+                path = format!("{:?}", log_path)
+            ),
+        });
+
+        let reason = match watch_process(&mut child, &log_path, Duration::from_secs(self.timeout_secs)).await {
+            VerifyOutcome::Ready => {
+                sublog_callback(SubLog {
+                    message: t!("verify-marker-found"),
+                });
+                return StepResult {
+                    success: true,
+                    message: t!("verify-done"),
+                };
+            }
+            VerifyOutcome::NotLoaded => t!("verify-no-marker", seconds = self.timeout_secs),
+            VerifyOutcome::Crashed(reason) => reason,
+        };
+        // Left behind so the startup log named above can be attached to a bug report
+        let _ = log_dir.keep();
+
+        sublog_callback(SubLog {
+            message: reason.clone(),
+        });
+
+        if !self.rollback_on_failure {
+            return StepResult {
+                success: false,
+                message: t!("verify-failed", reason = reason),
+            };
+        }
+
+        let _ = child.kill();
+        let _ = child.wait();
+
+        if let Err(err) = rollback_to_original_asar(&resources_path, sublog_callback).await {
+            return StepResult {
+                success: false,
+                message: t!("verify-rollback-failed", reason = reason, error = err),
+            };
+        }
+
+        for candidate in &candidates {
+            if try_launch(candidate, self.suppress_console_window, sublog_callback) {
+                break;
+            }
+        }
+
+        StepResult {
+            success: false,
+            message: t!("verify-rolled-back", reason = reason),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
+use crate::utils::fs_helpers::luna_user_data_paths;
+use async_trait::async_trait;
+use tokio::fs;
+
+/// Deletes Luna's settings and plugin storage from TIDAL's user data
+pub struct WipeLunaDataStep;
+
+#[async_trait]
+impl InstallStep for WipeLunaDataStep {
+    fn id(&self) -> &'static str {
+        "wipe_luna_data"
+    }
+
+    fn name(&self) -> String {
+        t!("step-wipe-luna-data")
+    }
+
+    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
+        let paths = luna_user_data_paths();
+        if paths.is_empty() {
+            return StepResult {
+                success: true,
+                message: t!("wipe-nothing-found"),
+            };
+        }
+
+        let mut failed = 0;
+        for path in &paths {
+            let removed = if path.is_dir() {
+                fs::remove_dir_all(path).await
+            } else {
+                fs::remove_file(path).await
+            };
+            match removed {
+                Ok(()) => sublog_callback(SubLog {
+                    message: t!("wipe-removed", path = format!("{:?}", path)),
+                }),
+                Err(err) => {
+                    failed += 1;
+                    sublog_callback(SubLog {
+                        message: t!("wipe-remove-failed", path = format!("{:?}", path), error = err),
+                    });
+                }
+            }
+        }
+
+        if failed > 0 {
+            return StepResult {
+                success: false,
+                message: t!("wipe-failed", count = failed),
+            };
+        }
+
+        StepResult {
+            success: true,
+            message: t!("wipe-done"),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::installer::step::{InstallStep, StepResult, SubLog};
+use crate::t;
+use crate::utils::receipt::{record_patched, write_receipt, InstallReceipt, RECEIPT_FILE};
+use async_trait::async_trait;
+use std::path::PathBuf;
+
+/// Records which Luna version was installed so it can be rolled back to later
+pub struct WriteReceiptStep {
+    pub overwrite_path: PathBuf,
+    pub receipt: InstallReceipt,
+}
+
+#[async_trait]
+impl InstallStep for WriteReceiptStep {
+    fn id(&self) -> &'static str {
+        "write_receipt"
+    }
+
+    fn name(&self) -> String {
+        t!("step-write-receipt")
+    }
+
+    async fn run(&self, sublog_callback: &(dyn Fn(SubLog) + Send + Sync)) -> StepResult {
+        // Kept outside the resources directory too, so `watch` can repair an install whose
+        // resources directory was replaced by a client update
+        if let Err(err) = record_patched(&self.overwrite_path, Some(self.receipt.clone())) {
+            sublog_callback(SubLog {
+                message: t!("receipt-record-failed", error = err),
+            });
+        }
+
+        match write_receipt(&self.overwrite_path, &self.receipt) {
+            Ok(()) => sublog_callback(SubLog {
+                message: t!(
+                    "receipt-recorded",
+                    channel = self.receipt.channel,
+                    version = self.receipt.version,
+                    path = format!("{:?}", self.overwrite_path.join(RECEIPT_FILE))
+                ),
+            }),
+            // Luna works without a receipt; only rollback loses the version label
+            Err(err) => sublog_callback(SubLog {
+                message: t!("receipt-write-failed", error = err),
+            }),
+        }
+
+        StepResult {
+            success: true,
+            message: t!("receipt-done"),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Scratch directory of one install pipeline, holding `Luna.zip` and `LunaExtracted`.
+/// Every pipeline gets its own, so one target's run never removes another's files. The
+/// directory is deleted once the pipeline is dropped, which keeps it for a retry.
+#[derive(Debug, Clone, Default)]
+pub struct WorkDir(Arc<Mutex<Option<tempfile::TempDir>>>);
+
+impl WorkDir {
+    /// Returns the directory, creating it on first use
+    pub fn path(&self) -> io::Result<PathBuf> {
+        let mut dir = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(dir) = dir.as_ref() {
+            return Ok(dir.path().to_path_buf());
+        }
+
+        let created = tempfile::Builder::new().prefix("TidaLunaInstaller-").tempdir()?;
+        let path = created.path().to_path_buf();
+        *dir = Some(created);
+        Ok(path)
+    }
+}
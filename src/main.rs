@@ -6,24 +6,49 @@ mod ui;
 mod utils;
 
 use clap::Parser;
-use args::Args;
+use args::{Args, Command};
+use cli::CliExit;
+use utils::config;
+use std::process::ExitCode;
 
-fn main() {
+fn main() -> ExitCode {
     let args = Args::parse();
+    // Also what `self-update` runs on a freshly downloaded binary to check it
+    if args.wants_version() {
+        println!("tidaluna-installer {}", utils::updater::current_installer_version());
+        return ExitCode::SUCCESS;
+    }
+
+    // A broken config is reported by the command that needs it; the language just stays detected
+    if let Ok(config) = config::load()
+        && config.locale.is_some()
+    {
+        utils::i18n::set_locale(config.locale.as_deref());
+    }
+    let command = args.resolve_command();
+
+    let headless = match &command {
+        Some(Command::Gui) => false,
+        Some(_) => true,
+        None => args.headless,
+    };
 
-    if args.headless {
+    if headless {
         match tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()
         {
-            Ok(runtime) => runtime.block_on(cli::run_cli(args)),
+            Ok(runtime) => runtime.block_on(cli::run_cli(command, args.output, args.non_interactive)).into(),
             Err(err) => {
-                eprintln!("Failed to initialize async runtime: {}", err);
+                eprintln!("{}", t!("cli-runtime-failed", error = err));
+                CliExit::Failure.into()
             }
         }
     } else {
         if let Err(err) = ui::run_gui() {
-            eprintln!("Failed to launch GUI: {}", err);
+            eprintln!("{}", t!("cli-gui-failed", error = err));
+            return CliExit::Failure.into();
         }
+        ExitCode::SUCCESS
     }
 }
@@ -1 +1,2 @@
-pub mod types;
\ No newline at end of file
+#[allow(clippy::module_inception)]
+pub mod types;
@@ -4,6 +4,14 @@ use serde::{Deserialize, Serialize};
 pub struct ReleaseVersion {
     pub version: String,
     pub download: String,
+    /// Release notes as published, usually Markdown
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// RFC 3339 timestamp, e.g. `2025-03-01T12:00:00Z`
+    #[serde(default)]
+    pub published_at: Option<String>,
+    #[serde(default)]
+    pub prerelease: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1,9 +1,9 @@
+use iced::multi_window::Application;
 use iced::{
-    executor, Alignment, Application, Background, Border, Color, Command, Element, Length,
-    Settings, Shadow, Size, Subscription, Theme, Vector,
+    executor, window, Alignment, Command, Element, Length, Settings, Size, Subscription, Theme,
 };
 use iced::widget::{
-    button, checkbox, combo_box, horizontal_space, image, progress_bar, scrollable, text,
+    button, checkbox, combo_box, horizontal_space, image, pick_list, progress_bar, scrollable, text,
     text_input, tooltip,
     Column, Container, Row, Scrollable,
 };
@@ -12,16 +12,38 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::runtime::Runtime;
 use semver::Version;
 use std::sync::Arc;
-use crate::utils::updater;
-
+use crate::installer::pipeline::local_archive_url;
+use crate::installer::steps::local_archive::LOCAL_ARCHIVE_CHANNEL;
+use crate::t;
+use crate::utils::config::{self, LaunchPolicy, ThemePreference};
+use crate::utils::fs_helpers::normalize_tidal_resources_path;
+use crate::utils::history::format_timestamp;
+use crate::utils::i18n;
+use crate::utils::receipt::previous_install;
+use crate::utils::updater::{self, UpdateChannel};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+mod cache;
+mod log_window;
 mod models;
 mod tasks;
+mod theme;
 
-use models::{LogEntry, LogLevel, Message, MyApp};
+use log_window::{default_bundle_path, default_log_save_path, export_log, log_entry_row};
+use theme::ThemeVariant;
+use models::{
+    AppRelease, AppVersionInfo, CardAction, DroppedArchive, InstallExecutionResult, InstallRequest, InstallationCard, LanguageChoice, LogEntry, LogLevel, Message, MyApp,
+    RunEvents, RunProgress, RunUpdate, UninstallChoices, UninstallPreview,
+};
+use crate::installer::manager::CancelFlag;
 use tasks::{
-    apply_installer_update_async, check_installation_async, check_installer_update_async,
-    detect_tidal_paths_async, install_async, load_releases_async, load_stargazers_async,
-    uninstall_async,
+    apply_installer_update_async, check_installation_async, detect_tidal_paths_async, export_diagnostics_async,
+    inspect_archive_async,
+    load_installations_async, load_installer_releases_async, load_releases_async, load_stargazers_async,
+    load_cached_releases_async, load_cached_stargazers_async, preview_uninstall_async, run_subscription,
+    spawn_install, spawn_retry, spawn_rollback, spawn_uninstall,
 };
 
 fn load_app_icon() -> Option<iced::window::Icon> {
@@ -29,36 +51,80 @@ fn load_app_icon() -> Option<iced::window::Icon> {
     iced::window::icon::from_file_data(icon_bytes, None).ok()
 }
 
+/// How `selected` relates to `installed`; `None` when either is not a semantic version
+fn compare_versions(installed: &str, selected: &str) -> Option<Ordering> {
+    let (installed, selected) = (installed.trim_start_matches('v'), selected.trim_start_matches('v'));
+    if installed == selected {
+        return Some(Ordering::Equal);
+    }
+    Some(Version::parse(selected).ok()?.cmp(&Version::parse(installed).ok()?))
+}
+
 impl Default for MyApp {
     fn default() -> Self {
         let channel_pick_list = combo_box::State::new(vec![]);
         let version_pick_list = combo_box::State::new(vec![]);
         let install_path_pick_list = combo_box::State::new(vec![]);
+        let config = config::load_or_default();
+        let current_installer_version = updater::current_installer_version();
 
         Self {
             releases: Vec::new(),
             selected_channel: String::new(),
             selected_version: String::new(),
             selected_install_path: String::new(),
-            custom_install_path: String::new(),
+            custom_install_path: config.gui_path.clone().unwrap_or_default(),
             is_loading: true,
             is_installing: false,
             is_uninstalling: false,
+            is_rolling_back: false,
+            previous_luna_version: None,
             is_advanced_open: false,
+            verify_after_install: config.launch == LaunchPolicy::Verify,
+            launch_before_verify: None,
+            rollback_on_verify_failure: config.rollback_on_verify_failure,
+            apply_to_all: false,
             is_luna_installed: false,
             is_loading_stargazers: true,
+            releases_cached_at: None,
+            stargazers_cached_at: None,
+            is_offline: false,
             channel_pick_list,
             version_pick_list,
             install_path_pick_list,
             install_path_options: Vec::new(),
+            installations: Vec::new(),
+            dropped_archive: None,
+            uninstall_preview: None,
+            is_loading_uninstall_preview: false,
             stargazers: Vec::new(),
             stargazers_error: None,
             stargazers_page: 0,
-            current_installer_version: updater::current_installer_version(),
+            installer_releases: Vec::new(),
+            show_installer_prereleases: UpdateChannel::for_version(&current_installer_version)
+                == UpdateChannel::Prerelease,
+            current_installer_version,
             available_installer_update: None,
             show_installer_update_prompt: false,
             is_applying_installer_update: false,
+            active_run: None,
+            next_run_id: 0,
+            run_progress: None,
+            cancel_flag: CancelFlag::default(),
+            is_cancelling: false,
+            is_retrying: false,
+            resume_points: Vec::new(),
             log_entries: Vec::new(),
+            next_log_id: 0,
+            log_window: None,
+            log_search: String::new(),
+            hidden_log_levels: HashSet::new(),
+            collapsed_log_steps: HashSet::new(),
+            log_save_path: default_log_save_path().to_string_lossy().to_string(),
+            log_export_status: None,
+            is_exporting_diagnostics: false,
+            theme: ThemeVariant::resolve(config.theme),
+            config,
             runtime: Arc::new(
                 Runtime::new().unwrap_or_else(|e| {
                     panic!("Failed to create Tokio runtime: {}", e);
@@ -78,38 +144,51 @@ impl Application for MyApp {
     fn new(_flags: ()) -> (Self, Command<Self::Message>) {
         let app = Self::default();
 
-        let load_releases = Command::perform(load_releases_async(app.runtime.clone()), Message::ReleasesLoaded);
+        // The cache fills the window right away; the network loads below replace it when they succeed
+        let load_cached_releases =
+            Command::perform(load_cached_releases_async(app.runtime.clone()), Message::CachedReleasesLoaded);
+        let load_cached_stargazers =
+            Command::perform(load_cached_stargazers_async(app.runtime.clone()), Message::CachedStargazersLoaded);
         let check_installation = Command::perform(check_installation_async(app.runtime.clone()), |is_installed| {
             Message::InstallationStatus(is_installed)
         });
         let check_update = Command::perform(
-            check_installer_update_async(app.runtime.clone(), app.current_installer_version.clone()),
-            Message::InstallerUpdateChecked,
+            load_installer_releases_async(app.runtime.clone()),
+            Message::InstallerReleasesLoaded,
         );
         let detect_paths = Command::perform(detect_tidal_paths_async(app.runtime.clone()), Message::TidalPathsDetected);
+        let load_installations =
+            Command::perform(load_installations_async(app.runtime.clone()), Message::InstallationsLoaded);
         let load_stargazers = Command::perform(load_stargazers_async(app.runtime.clone()), Message::StargazersLoaded);
 
         let cmd = Command::batch(vec![
+            load_cached_releases,
+            load_cached_stargazers,
             Command::perform(async {}, |_| Message::LoadReleases),
-            load_releases,
             check_installation,
             check_update,
             detect_paths,
+            load_installations,
             load_stargazers,
         ]);
 
         (app, cmd)
     }
     
-    fn title(&self) -> String {
-        format!("TidaLuna Installer v{}", self.current_installer_version)
+    fn title(&self, window: window::Id) -> String {
+        if self.log_window == Some(window) {
+            t!("gui-log-window-title", version = self.current_installer_version)
+        } else {
+            t!("gui-window-title", version = self.current_installer_version)
+        }
     }
 
     fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
         match message {
             Message::LoadReleases => {
-                self.is_loading = true;
-                self.add_log("Loading releases...", LogLevel::Info);
+                // Cached releases stay usable while the refresh runs
+                self.is_loading = self.releases.is_empty();
+                self.add_log(&t!("gui-loading-releases"), LogLevel::Info);
                 let runtime = self.runtime.clone();
                 Command::perform(load_releases_async(runtime), Message::ReleasesLoaded)
             }
@@ -118,30 +197,15 @@ impl Application for MyApp {
                 self.is_loading = false;
                 match result {
                     Ok(releases) => {
-                        self.releases = releases;
-                        self.add_log("Releases loaded successfully", LogLevel::Success);
-
-                        let channel_options: Vec<String> = self.releases.iter()
-                            .map(|r| r.name.clone())
-                            .collect();
-                        self.channel_pick_list = combo_box::State::new(channel_options);
-
-                        let default_channel = self
-                            .releases
-                            .iter()
-                            .find(|r| r.name == "stable")
-                            .or_else(|| self.releases.iter().find(|r| r.name == "beta"))
-                            .or_else(|| self.releases.iter().find(|r| r.name == "alpha"))
-                            .map(|r| r.name.clone())
-                            .unwrap_or_default();
-
-                        if !default_channel.is_empty() {
-                            return self.update(Message::ReleaseChannelSelected(default_channel));
-                        }
+                        self.releases_cached_at = None;
+                        self.is_offline = false;
+                        self.add_log(&t!("gui-releases-loaded"), LogLevel::Success);
+                        return self.apply_releases(releases);
                     }
                     Err(err) => {
+                        self.is_offline = true;
                         self.add_log(
-                            &format!("Failed to load releases: {}", err),
+                            &t!("releases-load-failed", error = err),
                             LogLevel::Error,
                         );
                     }
@@ -149,23 +213,66 @@ impl Application for MyApp {
                 Command::none()
             }
 
-            Message::InstallerUpdateChecked(result) => {
+            Message::CachedReleasesLoaded(cached) => {
+                // Too late when the network was faster
+                let Some(cached) = cached.filter(|_| self.releases.is_empty()) else {
+                    return Command::none();
+                };
+                self.is_loading = false;
+                self.releases_cached_at = Some(cached.saved_at);
+                self.add_log(
+                    &t!("gui-releases-from-cache", date = format_timestamp(cached.saved_at)),
+                    LogLevel::Info,
+                );
+                self.apply_releases(cached.data)
+            }
+
+            Message::RefreshOnlineData => {
+                self.is_offline = false;
+                if self.stargazers.is_empty() {
+                    self.is_loading_stargazers = true;
+                }
+                Command::batch(vec![
+                    self.update(Message::LoadReleases),
+                    Command::perform(load_stargazers_async(self.runtime.clone()), Message::StargazersLoaded),
+                ])
+            }
+
+            Message::InstallerReleasesLoaded(result) => {
                 match result {
-                    Ok(Some(update)) => {
-                        self.available_installer_update = Some(update);
-                        self.show_installer_update_prompt = true;
-                    }
-                    Ok(None) => {
-                        self.available_installer_update = None;
-                        self.show_installer_update_prompt = false;
+                    Ok(releases) => {
+                        let channel = UpdateChannel::for_version(&self.current_installer_version);
+                        let update = updater::newest_update(&releases, &self.current_installer_version, channel).cloned();
+                        self.show_installer_update_prompt = update.is_some();
+                        self.available_installer_update = update;
+                        self.installer_releases = releases;
                     }
                     Err(err) => {
-                        self.add_log(&format!("Installer update check failed: {}", err), LogLevel::Info);
+                        self.add_log(&t!("cli-update-check-failed", error = err), LogLevel::Info);
                     }
                 }
                 Command::none()
             }
 
+            Message::InstallerReleaseSelected(release) => {
+                if !self.is_applying_installer_update {
+                    self.available_installer_update = Some(release);
+                }
+                Command::none()
+            }
+
+            Message::ToggleInstallerPrereleases(show) => {
+                self.show_installer_prereleases = show;
+                let channel = if show { UpdateChannel::Prerelease } else { UpdateChannel::Stable };
+                // Keep the selection only if it is still listed
+                if self.available_installer_update.as_ref().is_some_and(|release| release.prerelease && !show) {
+                    self.available_installer_update =
+                        updater::newest_update(&self.installer_releases, &self.current_installer_version, channel)
+                            .cloned();
+                }
+                Command::none()
+            }
+
             Message::AcceptInstallerUpdate => {
                 let Some(update) = self.available_installer_update.clone() else {
                     return Command::none();
@@ -177,14 +284,13 @@ impl Application for MyApp {
 
                 self.is_applying_installer_update = true;
                 self.add_log(
-                    &format!("Downloading installer update v{}...", update.version),
+                    &t!("gui-downloading-update", version = update.version),
                     LogLevel::Info,
                 );
 
                 let runtime = self.runtime.clone();
-                let url = update.download_url;
                 Command::perform(
-                    apply_installer_update_async(runtime, url),
+                    apply_installer_update_async(runtime, update),
                     Message::InstallerUpdateApplied,
                 )
             }
@@ -200,7 +306,8 @@ impl Application for MyApp {
 
                 match result {
                     Ok(outcome) => {
-                        self.add_log(&outcome.message, LogLevel::Success);
+                        let level = if outcome.applied { LogLevel::Success } else { LogLevel::Info };
+                        self.add_log(&outcome.message, level);
                         self.show_installer_update_prompt = false;
                         self.available_installer_update = None;
 
@@ -209,7 +316,7 @@ impl Application for MyApp {
                         }
                     }
                     Err(err) => {
-                        self.add_log(&format!("Installer update failed: {}", err), LogLevel::Error);
+                        self.add_log(&t!("gui-update-failed", error = err), LogLevel::Error);
                     }
                 }
 
@@ -222,23 +329,47 @@ impl Application for MyApp {
                 match result {
                     Ok(stargazers) => {
                         self.stargazers = stargazers;
+                        self.stargazers_cached_at = None;
                         self.stargazers_error = None;
                         self.stargazers_page = 0;
                     }
+                    Err(err) if self.stargazers_cached_at.is_some() => {
+                        self.is_offline = true;
+                        self.add_log(&t!("gui-stargazers-failed", error = err), LogLevel::Info);
+                    }
                     Err(err) => {
                         self.stargazers.clear();
                         self.stargazers_error = Some(err.clone());
                         self.stargazers_page = 0;
-                        self.add_log(&format!("Failed to load stargazers: {}", err), LogLevel::Info);
+                        self.add_log(&t!("gui-stargazers-failed", error = err), LogLevel::Info);
                     }
                 }
 
                 Command::none()
             }
 
+            Message::CachedStargazersLoaded(cached) => {
+                let Some(cached) = cached.filter(|cached| self.stargazers.is_empty() && !cached.data.is_empty()) else {
+                    return Command::none();
+                };
+                self.is_loading_stargazers = false;
+                self.stargazers = cached.data;
+                self.stargazers_cached_at = Some(cached.saved_at);
+                self.stargazers_error = None;
+                self.stargazers_page = 0;
+                Command::none()
+            }
+
             Message::ReleaseChannelSelected(channel) => {
                 self.selected_channel = channel.clone();
-                self.add_log(&format!("Selected channel: {}", channel), LogLevel::Info);
+                self.add_log(&t!("gui-selected-channel", channel = channel), LogLevel::Info);
+
+                // A pinned version belongs to its channel, so switching channels unpins it
+                if self.config.channel.as_deref() != Some(channel.as_str()) {
+                    self.config.channel = Some(channel.clone());
+                    self.config.version = None;
+                    self.save_config();
+                }
 
                 if let Some(release) = self.releases.iter().find(|r| r.name == channel) {
                     let mut versions: Vec<String> = release
@@ -255,10 +386,19 @@ impl Application for MyApp {
 
                     self.version_pick_list = combo_box::State::new(versions.clone());
 
-                    if let Some(latest) = versions.first() {
+                    let saved_version = self
+                        .config
+                        .version
+                        .as_ref()
+                        .filter(|version| versions.contains(version));
+
+                    if let Some(saved) = saved_version {
+                        self.selected_version = saved.clone();
+                        self.add_log(&t!("gui-selected-saved-version", version = saved), LogLevel::Info);
+                    } else if let Some(latest) = versions.first() {
                         self.selected_version = latest.clone();
                         self.add_log(
-                            &format!("Auto-selected version: {}", latest),
+                            &t!("gui-auto-selected-version", version = latest),
                             LogLevel::Info,
                         );
                     }
@@ -269,17 +409,21 @@ impl Application for MyApp {
 
             Message::VersionSelected(version) => {
                 self.selected_version = version.clone();
-                self.add_log(&format!("Selected version: {}", version), LogLevel::Info);
+                self.add_log(&t!("gui-selected-version", version = version), LogLevel::Info);
+                self.config.version = Some(version);
+                self.save_config();
                 Command::none()
             }
 
             Message::InstallPathChanged(path) => {
                 self.custom_install_path = path;
+                self.refresh_previous_version();
                 Command::none()
             }
 
             Message::InstallPathOptionSelected(path) => {
                 self.selected_install_path = path;
+                self.refresh_previous_version();
                 Command::none()
             }
 
@@ -291,98 +435,297 @@ impl Application for MyApp {
 
                         if paths.len() == 1 && self.selected_install_path.trim().is_empty() {
                             self.selected_install_path = paths[0].clone();
-                            self.add_log("Detected one TIDAL installation path automatically", LogLevel::Info);
+                            self.add_log(&t!("gui-detected-one-path"), LogLevel::Info);
                         } else if paths.len() > 1 {
                             self.selected_install_path = paths[0].clone();
-                            self.add_log("Multiple TIDAL installations detected. Auto-selected the latest path; change it in Advanced Options if needed.", LogLevel::Info);
+                            self.add_log(&t!("gui-detected-many-paths"), LogLevel::Info);
                         }
                     }
                     Err(err) => {
-                        self.add_log(&format!("Could not auto-detect TIDAL paths: {}", err), LogLevel::Info);
+                        self.add_log(&t!("gui-detect-failed", error = err), LogLevel::Info);
                     }
                 }
+                self.refresh_previous_version();
                 Command::none()
             }
 
             Message::Install => {
-                if self.custom_install_path.trim().is_empty() && self.selected_install_path.trim().is_empty() {
-                    self.add_log("No TIDAL path selected. Choose one from the dropdown or enter a custom path in Advanced Options.", LogLevel::Error);
+                let paths = self.target_paths();
+                if paths.is_empty() {
+                    self.add_log(&t!("gui-no-path-hint"), LogLevel::Error);
                     return Command::none();
                 }
+                self.remember_custom_path();
 
-                self.is_installing = true;
-                self.clear_log();
-                self.add_log("Starting installation...", LogLevel::Step);
-
-                let releases = self.releases.clone();
-                let channel = self.selected_channel.clone();
-                let version = self.selected_version.clone();
-                let selected_path = self.selected_install_path.clone();
-                let custom_path = self.custom_install_path.clone();
-                let reinstall_mode = self.is_luna_installed;
-                let runtime = self.runtime.clone();
-
-                Command::perform(
-                    install_async(releases, channel, version, selected_path, custom_path, reinstall_mode, runtime),
-                    Message::InstallationComplete,
-                )
+                match self.install_request(paths, &self.selected_channel, &self.selected_version) {
+                    Ok(mut request) => {
+                        request.reinstall_mode = self.is_luna_installed;
+                        self.start_install(request, &t!("gui-starting-install"));
+                    }
+                    Err(err) => self.add_log(&err, LogLevel::Error),
+                }
+                Command::none()
             }
 
             Message::Uninstall => {
-                if self.custom_install_path.trim().is_empty() && self.selected_install_path.trim().is_empty() {
-                    self.add_log("No TIDAL path selected. Choose one from the dropdown or enter a custom path in Advanced Options.", LogLevel::Error);
+                let paths = self.target_paths();
+                if paths.is_empty() {
+                    self.add_log(&t!("gui-no-path-hint"), LogLevel::Error);
                     return Command::none();
                 }
+                self.remember_custom_path();
 
-                self.is_uninstalling = true;
-                self.clear_log();
-                self.add_log("Starting uninstallation...", LogLevel::Step);
+                self.preview_uninstall(paths)
+            }
 
-                let selected_path = self.selected_install_path.clone();
-                let custom_path = self.custom_install_path.clone();
-                let runtime = self.runtime.clone();
+            Message::UninstallPreviewLoaded(result) => {
+                self.is_loading_uninstall_preview = false;
+                match result {
+                    Ok(preview) => self.uninstall_preview = Some(preview),
+                    Err(err) => self.add_log(&err, LogLevel::Error),
+                }
+                Command::none()
+            }
 
-                Command::perform(
-                    uninstall_async(selected_path, custom_path, runtime),
-                    Message::InstallationComplete,
-                )
+            Message::ToggleUninstallKeepBackup(keep_backup) => {
+                if let Some(preview) = &mut self.uninstall_preview {
+                    preview.choices.keep_backup = keep_backup;
+                }
+                Command::none()
+            }
+
+            Message::ToggleUninstallWipeData(wipe_user_data) => {
+                if let Some(preview) = &mut self.uninstall_preview {
+                    preview.choices.wipe_user_data = wipe_user_data;
+                }
+                Command::none()
             }
 
-            Message::InstallationComplete(result) => {
-                self.is_installing = false;
-                self.is_uninstalling = false;
+            Message::ConfirmUninstall => {
+                if self.is_busy() {
+                    return Command::none();
+                }
+                if let Some(preview) = self.uninstall_preview.take() {
+                    self.start_uninstall(preview.paths, preview.choices);
+                }
+                Command::none()
+            }
+
+            Message::CancelUninstall => {
+                self.uninstall_preview = None;
+                Command::none()
+            }
+
+            Message::InstallationsLoaded(installations) => {
+                self.installations = installations;
+                Command::none()
+            }
+
+            Message::FileDropped(path) => {
+                if !path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("zip")) {
+                    let name = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy().into_owned();
+                    self.add_log(&t!("gui-drop-not-zip", name = name), LogLevel::Error);
+                    return Command::none();
+                }
+
+                self.add_log(&t!("gui-drop-checking", path = path.to_string_lossy()), LogLevel::Info);
+                Command::perform(inspect_archive_async(self.runtime.clone(), path.clone()), move |result| {
+                    Message::ArchiveInspected(path, result)
+                })
+            }
 
+            Message::ArchiveInspected(path, result) => {
                 match result {
-                    Ok(execution) => {
-                        for log in execution.logs {
-                            if log.is_substep {
-                                self.add_log(&format!("  {}", log.message), LogLevel::SubStep);
-                            } else {
-                                self.add_log(&log.message, LogLevel::Step);
+                    Ok(summary) => {
+                        let archive = DroppedArchive { path, summary };
+                        self.add_log(&t!("gui-archive-ready", name = archive.file_name()), LogLevel::Success);
+                        self.dropped_archive = Some(archive);
+                    }
+                    Err(err) => self.add_log(
+                        &t!("local-archive-invalid", path = format!("{:?}", path), error = err),
+                        LogLevel::Error,
+                    ),
+                }
+                Command::none()
+            }
+
+            Message::InstallDroppedArchive => {
+                if self.is_busy() {
+                    return Command::none();
+                }
+                let Some(archive) = self.dropped_archive.clone() else {
+                    return Command::none();
+                };
+                let paths = self.target_paths();
+                if paths.is_empty() {
+                    self.add_log(&t!("gui-no-path-hint"), LogLevel::Error);
+                    return Command::none();
+                }
+                let Some(url) = local_archive_url(&archive.path) else {
+                    self.add_log(&t!("gui-archive-bad-path", path = archive.path.to_string_lossy()), LogLevel::Error);
+                    return Command::none();
+                };
+
+                let mut request = self.request_for(paths, LOCAL_ARCHIVE_CHANNEL, &archive.version(), &url);
+                request.reinstall_mode = self.is_luna_installed;
+                self.dropped_archive = None;
+                self.start_install(request, &t!("gui-archive-installing", name = archive.file_name()));
+                Command::none()
+            }
+
+            Message::DiscardDroppedArchive => {
+                self.dropped_archive = None;
+                Command::none()
+            }
+
+            Message::CardAction(path, action) => {
+                if self.is_busy() {
+                    return Command::none();
+                }
+                let Some(card) = self.installations.iter().find(|card| card.path == path).cloned() else {
+                    return Command::none();
+                };
+
+                match action {
+                    CardAction::Install => {
+                        match self.install_request(vec![path], &self.selected_channel, &self.selected_version) {
+                            Ok(mut request) => {
+                                request.reinstall_mode = card.luna_installed;
+                                let label = t!("gui-card-installing", version = request.version, path = card.path);
+                                self.start_install(request, &label);
+                            }
+                            Err(err) => self.add_log(&err, LogLevel::Error),
+                        }
+                    }
+                    CardAction::Update => {
+                        let Some((channel, version)) = self.card_update(&card) else {
+                            return Command::none();
+                        };
+                        match self.install_request(vec![path], &channel, &version) {
+                            Ok(mut request) => {
+                                request.reinstall_mode = true;
+                                let label = t!("gui-card-updating", path = card.path, version = version);
+                                self.start_install(request, &label);
+                            }
+                            Err(err) => self.add_log(&err, LogLevel::Error),
+                        }
+                    }
+                    CardAction::Repair => {
+                        // Without a receipt the selected version is the best guess
+                        let request = match &card.receipt {
+                            Some(receipt) => Ok(self.request_for(
+                                vec![path],
+                                &receipt.channel,
+                                &receipt.version,
+                                &receipt.download_url,
+                            )),
+                            None => self.install_request(vec![path], &self.selected_channel, &self.selected_version),
+                        };
+                        match request {
+                            Ok(mut request) => {
+                                request.repair = true;
+                                let label = t!("gui-card-repairing", version = request.version, path = card.path);
+                                self.start_install(request, &label);
                             }
+                            Err(err) => self.add_log(&err, LogLevel::Error),
                         }
+                    }
+                    CardAction::Uninstall => return self.preview_uninstall(vec![path]),
+                }
+                Command::none()
+            }
+
+            Message::Rollback => {
+                let paths = self.target_paths();
+                if paths.is_empty() {
+                    self.add_log(&t!("gui-no-path-hint"), LogLevel::Error);
+                    return Command::none();
+                }
+
+                self.is_rolling_back = true;
+                self.clear_log();
+                self.add_log(&t!("gui-rolling-back-log"), LogLevel::Step);
+
+                let launch_tidal = self.config.launch != LaunchPolicy::Never;
+                let events = spawn_rollback(paths, launch_tidal, self.new_cancel_flag(), &self.runtime);
+                self.start_run(events);
+                Command::none()
+            }
+
+            Message::CancelRun => {
+                if self.is_busy() && !self.is_cancelling {
+                    self.is_cancelling = true;
+                    self.cancel_flag.cancel();
+                    self.add_log(&t!("gui-cancelling-log"), LogLevel::Info);
+                }
+                Command::none()
+            }
 
-                        if execution.success {
-                            self.add_log("Operation completed successfully!", LogLevel::Success);
+            Message::RetryFailedSteps => {
+                if self.is_busy() || self.resume_points.is_empty() {
+                    return Command::none();
+                }
+
+                self.is_retrying = true;
+                let resume = std::mem::take(&mut self.resume_points);
+                for point in &resume {
+                    self.add_log(
+                        &t!("gui-retrying-log", path = point.path.to_string_lossy(), step = point.step_name),
+                        LogLevel::Step,
+                    );
+                }
+                // The pipelines hold this flag, so it is re-armed instead of replaced
+                self.cancel_flag.reset();
+                let events = spawn_retry(resume, self.cancel_flag.clone(), &self.runtime);
+                self.start_run(events);
+                Command::none()
+            }
+
+            Message::RunUpdate(id, update) => {
+                // Late updates of a run that already finished
+                if self.active_run.as_ref().map(|(active, _)| *active) != Some(id) {
+                    return Command::none();
+                }
+
+                match update {
+                    RunUpdate::Log(log) => {
+                        if log.is_substep {
+                            self.add_log(&format!("  {}", log.message), LogLevel::SubStep);
                         } else {
-                            self.add_log("Operation failed: one or more steps failed", LogLevel::Error);
+                            self.add_log(&log.message, LogLevel::Step);
                         }
+                        Command::none()
                     }
-                    Err(err) => {
-                        self.add_log(&format!("Operation failed: {}", err), LogLevel::Error);
+                    RunUpdate::TargetStarted { index, total } => {
+                        if let Some(progress) = &mut self.run_progress {
+                            progress.target = (index, total);
+                            progress.step = (0, 0);
+                            progress.download = None;
+                        }
+                        Command::none()
+                    }
+                    RunUpdate::StepStarted { index, total, name } => {
+                        if let Some(progress) = &mut self.run_progress {
+                            progress.step = (index, total);
+                            progress.step_name = name;
+                            progress.download = None;
+                        }
+                        Command::none()
+                    }
+                    RunUpdate::Download { received, total } => {
+                        if let Some(progress) = &mut self.run_progress {
+                            progress.download = Some((received, total));
+                        }
+                        Command::none()
                     }
+                    RunUpdate::Finished(result) => self.finish_run(result),
                 }
-
-                let runtime = self.runtime.clone();
-                Command::perform(check_installation_async(runtime), |is_installed| {
-                    Message::InstallationStatus(is_installed)
-                })
             }
 
             Message::InstallationStatus(is_installed) => {
                 self.is_luna_installed = is_installed;
                 if is_installed {
-                    self.add_log("TidaLuna is already installed", LogLevel::Info);
+                    self.add_log(&t!("gui-already-installed"), LogLevel::Info);
                 }
                 Command::none()
             }
@@ -392,6 +735,55 @@ impl Application for MyApp {
                 Command::none()
             }
 
+            Message::ToggleVerifyAfterInstall(enabled) => {
+                self.verify_after_install = enabled;
+                if enabled {
+                    if self.config.launch != LaunchPolicy::Verify {
+                        self.launch_before_verify = Some(self.config.launch);
+                    }
+                    self.config.launch = LaunchPolicy::Verify;
+                } else if self.config.launch == LaunchPolicy::Verify {
+                    // Nothing is remembered when the saved config already said verify
+                    self.config.launch = self.launch_before_verify.take().unwrap_or_default();
+                }
+                self.save_config();
+                Command::none()
+            }
+
+            Message::ToggleRollbackOnVerifyFailure(enabled) => {
+                self.rollback_on_verify_failure = enabled;
+                self.config.rollback_on_verify_failure = enabled;
+                self.save_config();
+                Command::none()
+            }
+
+            Message::LanguageSelected(choice) => {
+                i18n::set_locale(choice.0);
+                self.config.locale = choice.0.map(str::to_string);
+                self.save_config();
+                Command::none()
+            }
+
+            Message::ThemeSelected(preference) => {
+                self.theme = ThemeVariant::resolve(preference);
+                self.config.theme = preference;
+                self.save_config();
+                Command::none()
+            }
+
+            Message::WindowFocused => {
+                if self.config.theme == ThemePreference::System {
+                    self.theme = ThemeVariant::resolve(ThemePreference::System);
+                }
+                Command::none()
+            }
+
+            Message::ToggleApplyToAll(enabled) => {
+                self.apply_to_all = enabled;
+                self.refresh_previous_version();
+                Command::none()
+            }
+
             Message::PrevStargazersPage => {
                 if self.stargazers_page > 0 {
                     self.stargazers_page -= 1;
@@ -411,77 +803,182 @@ impl Application for MyApp {
                 self.clear_log();
                 Command::none()
             }
+
+            Message::OpenLogWindow => {
+                if self.log_window.is_some() {
+                    return Command::none();
+                }
+                let (id, spawn) = window::spawn(window::Settings {
+                    size: Size::new(900.0, 600.0),
+                    min_size: Some(Size::new(500.0, 300.0)),
+                    icon: load_app_icon(),
+                    ..window::Settings::default()
+                });
+                self.log_window = Some(id);
+                spawn
+            }
+
+            Message::WindowClosed(id) => {
+                if self.log_window == Some(id) {
+                    self.log_window = None;
+                    return Command::none();
+                }
+                // Closing the main window quits, even with the log window still open
+                match (id == window::Id::MAIN, self.log_window.take()) {
+                    (true, Some(log_window)) => window::close(log_window),
+                    _ => Command::none(),
+                }
+            }
+
+            Message::LogSearchChanged(search) => {
+                self.log_search = search;
+                Command::none()
+            }
+
+            Message::ToggleLogLevel(level, shown) => {
+                if shown {
+                    self.hidden_log_levels.remove(&level);
+                } else {
+                    self.hidden_log_levels.insert(level);
+                }
+                Command::none()
+            }
+
+            Message::ToggleLogStep(id) => {
+                if !self.collapsed_log_steps.remove(&id) {
+                    self.collapsed_log_steps.insert(id);
+                }
+                Command::none()
+            }
+
+            Message::LogSavePathChanged(path) => {
+                self.log_save_path = path;
+                Command::none()
+            }
+
+            Message::SaveLog => {
+                let path = PathBuf::from(self.log_save_path.trim());
+                let contents = export_log(&self.log_entries, &self.current_installer_version);
+                self.log_export_status = Some(match std::fs::write(&path, contents) {
+                    Ok(()) => t!("gui-log-saved", path = path.to_string_lossy()),
+                    Err(err) => t!("gui-log-save-failed", path = path.to_string_lossy(), error = err),
+                });
+                Command::none()
+            }
+
+            Message::CopyLog => {
+                self.log_export_status = Some(t!("gui-log-copied"));
+                iced::clipboard::write(export_log(&self.log_entries, &self.current_installer_version))
+            }
+
+            Message::ExportDiagnostics => {
+                if self.is_exporting_diagnostics {
+                    return Command::none();
+                }
+                self.is_exporting_diagnostics = true;
+                self.add_log(&t!("gui-diagnostics-collecting"), LogLevel::Info);
+                Command::perform(
+                    export_diagnostics_async(self.runtime.clone(), default_bundle_path()),
+                    Message::DiagnosticsExported,
+                )
+            }
+
+            Message::DiagnosticsExported(result) => {
+                self.is_exporting_diagnostics = false;
+                let (status, level) = match result {
+                    Ok(path) => (
+                        t!("gui-diagnostics-saved", path = path.to_string_lossy()),
+                        LogLevel::Success,
+                    ),
+                    Err(err) => (err, LogLevel::Error),
+                };
+                self.add_log(&status, level);
+                self.log_export_status = Some(status);
+                Command::none()
+            }
         }
     }
 
-    fn view(&self) -> Element<'_, Self::Message> {
-        let card_style = |_: &Theme| iced::widget::container::Appearance {
-            text_color: None,
-            background: Some(Background::Color(Color::from_rgba(0.11, 0.12, 0.16, 0.94))),
-            border: Border {
-                radius: 8.0.into(),
-                width: 1.0,
-                color: Color::from_rgba(0.45, 0.55, 0.9, 0.18),
-            },
-            shadow: Shadow {
-                color: Color::from_rgba(0.0, 0.0, 0.0, 0.45),
-                offset: Vector::new(0.0, 8.0),
-                blur_radius: 18.0,
-            },
-        };
+    fn view(&self, window: window::Id) -> Element<'_, Self::Message> {
+        if self.log_window == Some(window) {
+            self.view_log_window()
+        } else {
+            self.view_main()
+        }
+    }
 
-        let warning_style = |_: &Theme| iced::widget::container::Appearance {
-            text_color: None,
-            background: Some(Background::Color(Color::from_rgba(0.26, 0.19, 0.08, 0.95))),
-            border: Border {
-                radius: 8.0.into(),
-                width: 1.0,
-                color: Color::from_rgba(0.95, 0.75, 0.25, 0.28),
-            },
-            shadow: Shadow {
-                color: Color::from_rgba(0.0, 0.0, 0.0, 0.38),
-                offset: Vector::new(0.0, 8.0),
-                blur_radius: 16.0,
-            },
-        };
+    fn theme(&self, _window: window::Id) -> Theme {
+        self.theme.iced_theme()
+    }
 
-        let background_style = |_: &Theme| iced::widget::container::Appearance {
-            text_color: None,
-            background: Some(Background::Color(Color::from_rgb(0.06, 0.07, 0.10))),
-            border: Border::default(),
-            shadow: Shadow::default(),
-        };
+    fn subscription(&self) -> Subscription<Message> {
+        let window_events = iced::event::listen_with(|event, _status| match event {
+            iced::Event::Window(id, window::Event::Closed) => Some(Message::WindowClosed(id)),
+            iced::Event::Window(_, window::Event::Focused) => Some(Message::WindowFocused),
+            iced::Event::Window(_, window::Event::FileDropped(path)) => Some(Message::FileDropped(path)),
+            _ => None,
+        });
+
+        match &self.active_run {
+            Some((id, events)) => Subscription::batch([window_events, run_subscription(*id, events.clone())]),
+            None => window_events,
+        }
+    }
+}
+
+
+impl MyApp {
+    const STARGAZERS_PER_PAGE: usize = 28;
+    const INLINE_LOG_LINES: usize = 200;
+
+    fn view_main(&self) -> Element<'_, Message> {
+        let palette = self.theme.palette();
+
+        // iced has no modal; the preview takes over the window until it is answered
+        if let Some(preview) = &self.uninstall_preview {
+            return Container::new(Scrollable::new(
+                Container::new(self.view_uninstall_preview(preview))
+                    .width(Length::Fill)
+                    .padding(24)
+                    .center_x(),
+            ))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(palette.window())
+            .center_y()
+            .into();
+        }
 
-        let title = text("TidaLuna Installer")
+        let title = text(t!("gui-title"))
             .size(36)
-            .style(iced::theme::Text::Color(Color::from_rgb(0.55, 0.76, 0.96)));
+            .style(iced::theme::Text::Color(palette.accent));
 
-        let subtitle = text("Install or remove TidaLuna from a TIDAL installation.")
+        let subtitle = text(t!("gui-subtitle"))
             .size(14)
-            .style(iced::theme::Text::Color(Color::from_rgb(0.74, 0.76, 0.84)));
+            .style(iced::theme::Text::Color(palette.secondary));
 
-        let subsubtitle = text("Works for the official TIDAL app and tidal-hifi. Note: If you are using the windows store version of TIDAL, please uninstall it and install the version from the official website.")
+        let subsubtitle = text(t!("gui-subsubtitle"))
             .size(12)
-            .style(iced::theme::Text::Color(Color::from_rgb(0.74, 0.76, 0.84)));
+            .style(iced::theme::Text::Color(palette.secondary));
 
-        let version_text = text(format!("Installer version: v{}", self.current_installer_version))
+        let version_text = text(t!("gui-installer-version", version = self.current_installer_version))
             .size(12)
-            .style(iced::theme::Text::Color(Color::from_rgb(0.62, 0.67, 0.76)));
+            .style(iced::theme::Text::Color(palette.muted));
 
         let status_text_label = if self.is_luna_installed {
             let installed_label = if cfg!(target_os = "windows") {
-                "Installed"
+                t!("gui-status-installed")
             } else {
-                "Installed ✓"
+                format!("{} ✓", t!("gui-status-installed"))
             };
 
             text(installed_label)
                 .size(15)
-                .style(iced::theme::Text::Color(Color::from_rgb(0.64, 0.95, 0.68)))
+                .style(iced::theme::Text::Color(palette.success))
         } else {
-            text("Not installed")
+            text(t!("gui-status-not-installed"))
                 .size(15)
-                .style(iced::theme::Text::Color(Color::from_rgb(0.86, 0.86, 0.90)))
+                .style(iced::theme::Text::Color(palette.text))
         };
 
         let status_text = Container::new(
@@ -489,20 +986,20 @@ impl Application for MyApp {
                 .spacing(8)
                 .align_items(Alignment::Center)
                 .push(
-                    text("Status")
+                    text(t!("gui-status"))
                         .size(13)
-                        .style(iced::theme::Text::Color(Color::from_rgb(0.66, 0.70, 0.80))),
+                        .style(iced::theme::Text::Color(palette.muted)),
                 )
                 .push(status_text_label),
         )
         .padding([6, 10]);
 
-        let channel_label = text("Release Channel").size(16);
+        let channel_label = text(t!("gui-release-channel")).size(16);
 
         let channel_pick = if self.is_loading {
             combo_box(
                 &self.channel_pick_list,
-                "Loading...",
+                &t!("gui-loading"),
                 Some(&self.selected_channel),
                 Message::ReleaseChannelSelected,
             )
@@ -511,7 +1008,7 @@ impl Application for MyApp {
         } else {
             combo_box(
                 &self.channel_pick_list,
-                "Select a release channel...",
+                &t!("gui-select-channel"),
                 Some(&self.selected_channel),
                 Message::ReleaseChannelSelected,
             )
@@ -519,12 +1016,12 @@ impl Application for MyApp {
             .padding(10)
         };
 
-        let version_label = text("Version").size(16);
+        let version_label = text(t!("gui-version")).size(16);
 
         let version_pick = if self.selected_channel.is_empty() {
             combo_box(
                 &self.version_pick_list,
-                "Select a channel first...",
+                &t!("gui-select-channel-first"),
                 Some(&self.selected_version),
                 Message::VersionSelected,
             )
@@ -533,7 +1030,7 @@ impl Application for MyApp {
         } else {
             combo_box(
                 &self.version_pick_list,
-                "Select a version...",
+                &t!("gui-select-version"),
                 Some(&self.selected_version),
                 Message::VersionSelected,
             )
@@ -541,14 +1038,14 @@ impl Application for MyApp {
             .padding(10)
         };
 
-        let path_label = text("Custom Path (optional)")
+        let path_label = text(t!("gui-custom-path"))
             .size(16);
 
-        let detected_path_label = text("Detected TIDAL installation path")
+        let detected_path_label = text(t!("gui-detected-path"))
             .size(16);
-        let detected_path_description = text("Used by default. If you enter a custom path below (click advanced options first), it overrides this selection.")
+        let detected_path_description = text(t!("gui-detected-path-description"))
             .size(12)
-            .style(iced::theme::Text::Color(Color::from_rgb(0.7, 0.72, 0.78)));
+            .style(iced::theme::Text::Color(palette.muted));
 
         let selected_detected_path = if self.selected_install_path.trim().is_empty() {
             None
@@ -558,75 +1055,129 @@ impl Application for MyApp {
 
         let detected_path_pick = combo_box(
             &self.install_path_pick_list,
-            "No detected TIDAL installation paths",
+            &t!("gui-no-detected-paths"),
             selected_detected_path,
             Message::InstallPathOptionSelected,
         )
         .width(Length::Fill)
         .padding(10);
 
+        let apply_to_all_toggle = checkbox(
+            t!("gui-apply-to-all", count = self.install_path_options.len()),
+            self.apply_to_all,
+        )
+        .on_toggle(Message::ToggleApplyToAll)
+        .size(16);
+
         let path_input = text_input(
-            "Leave empty for default Tidal directory",
+            &t!("gui-custom-path-placeholder"),
             &self.custom_install_path,
         )
         .on_input(Message::InstallPathChanged)
         .padding(10)
         .width(Length::Fill);
 
-        let advanced_toggle = checkbox("Show advanced options", self.is_advanced_open)
-            .on_toggle(|checked| Message::ToggleAdvancedOptions(checked))
+        let advanced_toggle = checkbox(t!("gui-show-advanced"), self.is_advanced_open)
+            .on_toggle(Message::ToggleAdvancedOptions)
             .size(16);
 
-        let advanced_section = if self.is_advanced_open {
-            Row::new()
-                .spacing(10)
-                .push(path_label.width(180))
-                .push(path_input)
-        } else {
-            Row::new()
-        };
+        let verify_toggle = checkbox(t!("gui-verify-toggle"), self.verify_after_install)
+            .on_toggle(Message::ToggleVerifyAfterInstall)
+            .size(16);
 
-        let install_button_text = if self.is_luna_installed {
-            "Reinstall"
-        } else {
-            "Install"
-        };
-        let install_button = if self.is_installing || self.is_uninstalling {
-            button(
-                text(install_button_text)
-                    .size(16)
-                    .style(iced::theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
-            )
-            .padding(15)
-            .width(150)
+        let rollback_toggle = if self.verify_after_install {
+            checkbox(t!("gui-rollback-toggle"), self.rollback_on_verify_failure)
+                .on_toggle(Message::ToggleRollbackOnVerifyFailure)
+                .size(16)
         } else {
-            button(
-                text(install_button_text)
-                    .size(16)
-                    .style(iced::theme::Text::Color(Color::WHITE)),
-            )
-            .on_press(Message::Install)
-            .padding(15)
-            .width(150)
-            .style(iced::theme::Button::Primary)
+            checkbox(t!("gui-rollback-toggle"), self.rollback_on_verify_failure)
+                .size(16)
         };
 
-        let uninstall_button = if !self.is_luna_installed
-            || self.is_installing
-            || self.is_uninstalling
-        {
+        let language_pick = pick_list(
+            LanguageChoice::all(),
+            Some(LanguageChoice::from_setting(self.config.locale.as_deref())),
+            Message::LanguageSelected,
+        )
+        .padding(8);
+
+        let theme_pick = pick_list(
+            ThemePreference::ALL.to_vec(),
+            Some(self.config.theme),
+            Message::ThemeSelected,
+        )
+        .padding(8);
+
+        let advanced_section = if self.is_advanced_open {
+            Column::new()
+                .spacing(10)
+                .push(
+                    Row::new()
+                        .spacing(10)
+                        .push(path_label.width(180))
+                        .push(path_input),
+                )
+                .push(verify_toggle)
+                .push(rollback_toggle)
+                .push(
+                    Row::new()
+                        .spacing(10)
+                        .align_items(Alignment::Center)
+                        .push(text(t!("gui-language")).size(16).width(180))
+                        .push(language_pick),
+                )
+                .push(
+                    Row::new()
+                        .spacing(10)
+                        .align_items(Alignment::Center)
+                        .push(text(t!("gui-theme")).size(16).width(180))
+                        .push(theme_pick),
+                )
+        } else {
+            Column::new()
+        };
+
+        let install_button_text = if self.is_luna_installed {
+            t!("gui-reinstall")
+        } else {
+            t!("gui-install")
+        };
+        let install_button = if self.is_busy() {
             button(
-                text("Uninstall")
+                text(install_button_text)
                     .size(16)
-                    .style(iced::theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+                    .style(iced::theme::Text::Color(palette.disabled)),
             )
             .padding(15)
             .width(150)
         } else {
             button(
-                text("Uninstall")
+                text(install_button_text)
                     .size(16)
-                    .style(iced::theme::Text::Color(Color::WHITE)),
+                    .style(iced::theme::Text::Color(palette.on_primary)),
+            )
+            .on_press(Message::Install)
+            .padding(15)
+            .width(150)
+            .style(iced::theme::Button::Primary)
+        };
+
+        let uninstall_button = if !self.is_luna_installed
+            || self.is_busy()
+            || self.is_loading_uninstall_preview
+        {
+            button(
+                text(t!("gui-uninstall"))
+                    .size(16)
+                    .style(iced::theme::Text::Color(palette.disabled)),
+            )
+            .padding(15)
+            .width(150)
+        } else {
+            button(
+                text(t!("gui-uninstall"))
+                    .size(16)
+                    .style(iced::theme::Text::Color(palette.on_primary)),
             )
             .on_press(Message::Uninstall)
             .padding(15)
@@ -634,23 +1185,58 @@ impl Application for MyApp {
             .style(iced::theme::Button::Destructive)
         };
 
-        let progress_indicator = if self.is_installing || self.is_uninstalling {
+        let rollback_label = match &self.previous_luna_version {
+            Some(version) => t!("gui-roll-back-to", version = version),
+            None => t!("gui-roll-back"),
+        };
+        let rollback_button = if self.previous_luna_version.is_none() || self.is_busy() {
+            button(
+                text(rollback_label)
+                    .size(16)
+                    .style(iced::theme::Text::Color(palette.disabled)),
+            )
+            .padding(15)
+        } else {
+            button(
+                text(rollback_label)
+                    .size(16)
+                    .style(iced::theme::Text::Color(palette.on_primary)),
+            )
+            .on_press(Message::Rollback)
+            .padding(15)
+            .style(iced::theme::Button::Secondary)
+        };
+
+        let progress_indicator = if self.is_busy() {
             Row::new()
                 .spacing(10)
                 .align_items(Alignment::Center)
                 .push(
-                    progress_bar(0.0..=100.0, if self.is_installing { 50.0 } else { 50.0 })
+                    progress_bar(0.0..=100.0, self.run_progress.as_ref().map_or(0.0, RunProgress::percent))
                         .width(200),
                 )
                 .push(
-                    text(if self.is_installing {
-                        "Installing..."
-                    } else {
-                        "Uninstalling..."
-                    })
+                    text(self.progress_label())
                     .size(14)
-                    .style(iced::theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+                    .style(iced::theme::Text::Color(palette.disabled)),
+                )
+                .push(
+                    button(text(t!("gui-cancel")).size(14))
+                        .on_press_maybe((!self.is_cancelling).then_some(Message::CancelRun))
+                        .padding([6, 12])
+                        .style(iced::theme::Button::Secondary),
                 )
+        } else if !self.resume_points.is_empty() {
+            let retry_label = match self.resume_points.as_slice() {
+                [point] => t!("gui-retry-from", step = point.step_name),
+                points => t!("gui-retry-many", count = points.len()),
+            };
+            Row::new().push(
+                button(text(retry_label).size(14))
+                    .on_press(Message::RetryFailedSteps)
+                    .padding([6, 12])
+                    .style(iced::theme::Button::Primary),
+            )
         } else {
             Row::new()
         };
@@ -659,82 +1245,34 @@ impl Application for MyApp {
             Column::new()
                 .spacing(4)
                 .push(
-                    text("Usage Warning")
+                    text(t!("gui-usage-warning"))
                         .size(16)
-                        .style(iced::theme::Text::Color(Color::from_rgb(0.98, 0.80, 0.35))),
+                        .style(iced::theme::Text::Color(palette.attention)),
                 )
                 .push(
-                    text("You cannot pirate media with TidaLuna or bypass TIDAL's free-tier limitations.")
+                    text(t!("gui-usage-warning-piracy"))
                         .size(14)
-                        .style(iced::theme::Text::Color(Color::from_rgb(0.9, 0.9, 0.9))),
+                        .style(iced::theme::Text::Color(palette.text)),
                 )
                 .push(
-                    text("Use only with a legitimate account. We will not help with issues related to piracy.")
+                    text(t!("gui-usage-warning-account"))
                         .size(13)
-                        .style(iced::theme::Text::Color(Color::from_rgb(0.7, 0.7, 0.7))),
+                        .style(iced::theme::Text::Color(palette.secondary)),
                 ),
         )
         .padding(12)
         .width(Length::Fill)
-        .style(warning_style);
+        .style(palette.warning());
 
-        let log_title = text("Installation Log")
+        let log_title = text(t!("gui-installation-log"))
             .size(18)
-            .style(iced::theme::Text::Color(Color::from_rgb(0.3, 0.3, 0.3)));
+            .style(iced::theme::Text::Color(palette.heading));
 
-        let log_entries: Vec<Element<Message>> = self.log_entries
+        // The full log is in the log window; the inline view keeps the newest lines cheap to draw
+        let inline_start = self.log_entries.len().saturating_sub(Self::INLINE_LOG_LINES);
+        let log_entries: Vec<Element<Message>> = self.log_entries[inline_start..]
             .iter()
-            .map(|entry| {
-                let color = match entry.level {
-                    LogLevel::Info => Color::from_rgb(0.3, 0.3, 0.3),
-                    LogLevel::Success => Color::from_rgb(0.0, 0.6, 0.0),
-                    LogLevel::Error => Color::from_rgb(0.8, 0.2, 0.2),
-                    LogLevel::Step => Color::from_rgb(0.2, 0.4, 0.8),
-                    LogLevel::SubStep => Color::from_rgb(0.4, 0.4, 0.4),
-                };
-
-                let prefix = if cfg!(target_os = "windows") {
-                    match entry.level {
-                        LogLevel::Step => ">> ",
-                        LogLevel::SubStep => "  -> ",
-                        _ => "* ",
-                    }
-                } else {
-                    match entry.level {
-                        LogLevel::Step => "▶ ",
-                        LogLevel::SubStep => "  → ",
-                        _ => "• ",
-                    }
-                };
-
-                let timestamp_secs = entry.timestamp / 1000;
-                let minutes = (timestamp_secs / 60) % 60;
-                let seconds = timestamp_secs % 60;
-                let millis = entry.timestamp % 1000;
-                let timestamp = format!("{:02}:{:02}.{:03}", minutes, seconds, millis);
-
-                Container::new(
-                    Row::new()
-                        .spacing(10)
-                        .align_items(Alignment::Center)
-                        .push(
-                            text(timestamp)
-                                .size(12)
-                                .style(iced::theme::Text::Color(Color::from_rgb(
-                                    0.5, 0.5, 0.5,
-                                )))
-                                .width(80),
-                        )
-                        .push(
-                            text(format!("{}{}", prefix, entry.message))
-                                .size(14)
-                                .style(iced::theme::Text::Color(color)),
-                        ),
-                )
-                .padding([6, 8])
-                .width(Length::Fill)
-                .into()
-            })
+            .map(|entry| log_entry_row(entry, None, palette))
             .collect();
 
         let log_content = scrollable(
@@ -743,20 +1281,30 @@ impl Application for MyApp {
         )
         .height(200);
 
-        let clear_log_button = if !self.is_installing && !self.is_uninstalling {
-            button(text("Clear Log").size(14))
+        let clear_log_button = if !self.is_busy() {
+            button(text(t!("gui-clear-log")).size(14))
                 .on_press(Message::ClearLog)
                 .padding(8)
                 .style(iced::theme::Button::Secondary)
         } else {
             button(
-                text("Clear Log")
+                text(t!("gui-clear-log"))
                     .size(14)
-                    .style(iced::theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+                    .style(iced::theme::Text::Color(palette.disabled)),
             )
             .padding(8)
         };
 
+        let diagnostics_button = button(text(t!("gui-diagnostics-export")).size(14))
+            .on_press_maybe((!self.is_exporting_diagnostics).then_some(Message::ExportDiagnostics))
+            .padding(8)
+            .style(iced::theme::Button::Secondary);
+
+        let open_log_window_button = button(text(t!("gui-open-log-window")).size(14))
+            .on_press_maybe(self.log_window.is_none().then_some(Message::OpenLogWindow))
+            .padding(8)
+            .style(iced::theme::Button::Secondary);
+
         let header_box = Container::new(
             Column::new()
                 .spacing(10)
@@ -774,7 +1322,7 @@ impl Application for MyApp {
         )
         .padding(18)
         .width(Length::Fill)
-        .style(card_style);
+        .style(palette.card());
 
         let main_box = Container::new(
             Column::new()
@@ -804,12 +1352,18 @@ impl Application for MyApp {
                                 .push(advanced_toggle),
                         ),
                 )
+                .push(self.view_release_notes())
                 .push(
                     Column::new()
                         .spacing(6)
                         .push(detected_path_label)
                         .push(detected_path_description)
-                        .push(detected_path_pick),
+                        .push(detected_path_pick)
+                        .push(if self.install_path_options.len() > 1 {
+                            Row::new().push(apply_to_all_toggle)
+                        } else {
+                            Row::new()
+                        }),
                 )
                 .push(if self.is_advanced_open {
                     Row::new().spacing(10).push(advanced_section)
@@ -822,9 +1376,15 @@ impl Application for MyApp {
                         .align_items(Alignment::Center)
                         .push(install_button)
                         .push(uninstall_button)
+                        .push(rollback_button)
                         .push(horizontal_space())
                         .push(progress_indicator),
                 )
+                .push(
+                    text(t!("gui-drop-hint"))
+                        .size(12)
+                        .style(iced::theme::Text::Color(palette.muted)),
+                )
                 .push(
                     Column::new()
                         .spacing(10)
@@ -834,6 +1394,8 @@ impl Application for MyApp {
                                 .align_items(Alignment::Center)
                                 .push(log_title)
                                 .push(horizontal_space())
+                                .push(diagnostics_button)
+                                .push(open_log_window_button)
                                 .push(clear_log_button),
                         )
                         .push(log_content),
@@ -841,15 +1403,18 @@ impl Application for MyApp {
         )
         .padding(18)
         .width(Length::Fill)
-        .style(card_style);
+        .style(palette.card());
 
         let content = Column::new()
             .spacing(16)
             .padding(24)
             .width(Length::Fill)
             .push(header_box)
+            .push(self.view_offline_banner())
             .push(self.view_update_prompt())
             .push(legal_warning)
+            .push(self.view_dashboard())
+            .push(self.view_dropped_archive())
             .push(main_box)
             .push(self.view_stargazers());
 
@@ -858,23 +1423,287 @@ impl Application for MyApp {
         )
         .width(Length::Fill)
         .height(Length::Fill)
-        .style(background_style)
+        .style(palette.window())
         .center_y()
         .into()
     }
 
-    fn theme(&self) -> Theme {
-        Theme::Dark
+    /// Installations the next install/uninstall acts on: every detected one when
+    /// "apply to all" is on, otherwise the custom path or the selected detected path
+    fn target_paths(&self) -> Vec<String> {
+        if self.apply_to_all && self.install_path_options.len() > 1 {
+            return self.install_path_options.clone();
+        }
+
+        if !self.custom_install_path.trim().is_empty() {
+            vec![self.custom_install_path.clone()]
+        } else if !self.selected_install_path.trim().is_empty() {
+            vec![self.selected_install_path.clone()]
+        } else {
+            Vec::new()
+        }
     }
 
-    fn subscription(&self) -> Subscription<Message> {
-        Subscription::none()
+    fn is_busy(&self) -> bool {
+        self.is_installing || self.is_uninstalling || self.is_rolling_back || self.is_retrying
     }
-}
 
+    /// A fresh flag for a new operation; what is left of the last one can no longer be retried
+    fn new_cancel_flag(&mut self) -> CancelFlag {
+        self.resume_points.clear();
+        self.cancel_flag = CancelFlag::default();
+        self.cancel_flag.clone()
+    }
 
-impl MyApp {
-    const STARGAZERS_PER_PAGE: usize = 28;
+    /// Makes `events` the running operation; `subscription` forwards its updates from now on
+    fn start_run(&mut self, events: RunEvents) {
+        self.next_run_id += 1;
+        self.active_run = Some((self.next_run_id, events));
+        self.run_progress = Some(RunProgress::default());
+    }
+
+    fn finish_run(&mut self, result: Result<InstallExecutionResult, String>) -> Command<Message> {
+        self.is_installing = false;
+        self.is_uninstalling = false;
+        self.is_rolling_back = false;
+        self.is_retrying = false;
+        self.is_cancelling = false;
+        self.active_run = None;
+        self.run_progress = None;
+        self.refresh_previous_version();
+
+        match result {
+            Ok(execution) => {
+                if execution.success {
+                    self.add_log(&t!("gui-operation-succeeded"), LogLevel::Success);
+                } else if execution.cancelled {
+                    self.add_log(&t!("gui-operation-cancelled"), LogLevel::Error);
+                } else {
+                    self.add_log(&t!("gui-operation-steps-failed"), LogLevel::Error);
+                }
+                self.resume_points = execution.resume;
+            }
+            Err(err) => {
+                self.add_log(&t!("gui-operation-failed", error = err), LogLevel::Error);
+            }
+        }
+
+        Command::batch(vec![
+            Command::perform(check_installation_async(self.runtime.clone()), |is_installed| {
+                Message::InstallationStatus(is_installed)
+            }),
+            Command::perform(load_installations_async(self.runtime.clone()), Message::InstallationsLoaded),
+        ])
+    }
+
+    /// An install of `version` from the loaded releases with the current settings
+    fn install_request(&self, paths: Vec<String>, channel: &str, version: &str) -> Result<InstallRequest, String> {
+        let release = self
+            .releases
+            .iter()
+            .find(|release| release.name == channel)
+            .ok_or_else(|| t!("release-channel-not-found", channel = channel))?;
+        let download_url = release
+            .versions
+            .iter()
+            .find(|candidate| candidate.version == version)
+            .map(|candidate| candidate.download.clone())
+            .ok_or_else(|| t!("release-version-not-in-channel", version = version, channel = channel))?;
+
+        Ok(self.request_for(paths, channel, version, &download_url))
+    }
+
+    fn request_for(&self, paths: Vec<String>, channel: &str, version: &str, download_url: &str) -> InstallRequest {
+        InstallRequest {
+            channel: channel.to_string(),
+            version: version.to_string(),
+            download_url: download_url.to_string(),
+            paths,
+            reinstall_mode: false,
+            repair: false,
+            verify: self.verify_after_install,
+            verify_timeout: self.config.verify_timeout,
+            rollback_on_failure: self.rollback_on_verify_failure,
+            launch_tidal: self.config.launch != LaunchPolicy::Never,
+            cache_dir: self.config.archive_cache_dir(),
+        }
+    }
+
+    fn start_install(&mut self, request: InstallRequest, message: &str) {
+        self.is_installing = true;
+        self.clear_log();
+        self.add_log(message, LogLevel::Step);
+
+        let events = spawn_install(request, self.new_cancel_flag(), &self.runtime);
+        self.start_run(events);
+    }
+
+    /// Fills the channel and version pickers, keeping the saved channel when it is still listed
+    fn apply_releases(&mut self, releases: Vec<AppRelease>) -> Command<Message> {
+        self.releases = releases;
+
+        let channel_options: Vec<String> = self.releases.iter()
+            .map(|r| r.name.clone())
+            .collect();
+        self.channel_pick_list = combo_box::State::new(channel_options);
+
+        let saved_channel = self.config.channel.clone().unwrap_or_default();
+        let default_channel = self
+            .releases
+            .iter()
+            .find(|r| r.name == saved_channel)
+            .or_else(|| self.releases.iter().find(|r| r.name == "stable"))
+            .or_else(|| self.releases.iter().find(|r| r.name == "beta"))
+            .or_else(|| self.releases.iter().find(|r| r.name == "alpha"))
+            .map(|r| r.name.clone())
+            .unwrap_or_default();
+
+        if default_channel.is_empty() {
+            return Command::none();
+        }
+        self.update(Message::ReleaseChannelSelected(default_channel))
+    }
+
+    /// Looks at what an uninstall of `paths` would do; the preview asks before anything is removed
+    fn preview_uninstall(&mut self, paths: Vec<String>) -> Command<Message> {
+        if self.is_loading_uninstall_preview {
+            return Command::none();
+        }
+        self.is_loading_uninstall_preview = true;
+        Command::perform(
+            preview_uninstall_async(self.runtime.clone(), paths),
+            Message::UninstallPreviewLoaded,
+        )
+    }
+
+    fn start_uninstall(&mut self, paths: Vec<String>, choices: UninstallChoices) {
+        self.is_uninstalling = true;
+        self.clear_log();
+        self.add_log(&t!("gui-starting-uninstall"), LogLevel::Step);
+
+        let launch_tidal = self.config.launch != LaunchPolicy::Never;
+        let events = spawn_uninstall(paths, choices, launch_tidal, self.new_cancel_flag(), &self.runtime);
+        self.start_run(events);
+    }
+
+    /// Channel and version to update a card to, when its channel has something newer
+    fn card_update(&self, card: &InstallationCard) -> Option<(String, String)> {
+        let receipt = card.receipt.as_ref()?;
+        let release = self.releases.iter().find(|release| release.name == receipt.channel)?;
+        let parse = |version: &str| Version::parse(version.trim_start_matches('v')).ok();
+        let installed = parse(&receipt.version)?;
+
+        release
+            .versions
+            .iter()
+            .filter_map(|candidate| Some((parse(&candidate.version)?, candidate)))
+            .filter(|(version, _)| *version > installed)
+            .max_by(|(left, _), (right, _)| left.cmp(right))
+            .map(|(_, candidate)| (release.name.clone(), candidate.version.clone()))
+    }
+
+    fn selected_release_version(&self) -> Option<&AppVersionInfo> {
+        self.releases
+            .iter()
+            .find(|release| release.name == self.selected_channel)?
+            .versions
+            .iter()
+            .find(|version| version.version == self.selected_version)
+    }
+
+    /// Luna versions at the targets of the next install, for targets that have Luna;
+    /// the version is `None` for installs made before receipts existed
+    fn installed_luna_versions(&self) -> Vec<(String, Option<String>)> {
+        self.target_paths()
+            .into_iter()
+            .filter_map(|path| {
+                let resources = normalize_tidal_resources_path(PathBuf::from(&path));
+                let card = self.installations.iter().find(|card| {
+                    normalize_tidal_resources_path(PathBuf::from(&card.path)) == resources
+                })?;
+                card.luna_installed
+                    .then(|| (path, card.receipt.as_ref().map(|receipt| receipt.version.clone())))
+            })
+            .collect()
+    }
+
+    /// "Installing... Step 3/10: Download Luna (1.2 / 5.0 MB)"
+    fn progress_label(&self) -> String {
+        let action = if self.is_cancelling {
+            t!("gui-progress-cancelling")
+        } else if self.is_retrying {
+            t!("gui-progress-retrying")
+        } else if self.is_installing {
+            t!("gui-progress-installing")
+        } else if self.is_rolling_back {
+            t!("gui-progress-rolling-back")
+        } else {
+            t!("gui-progress-uninstalling")
+        };
+        let Some(progress) = &self.run_progress else {
+            return action;
+        };
+
+        let mut label = action;
+        if progress.target.1 > 1 {
+            label.push_str(&format!(
+                " {}",
+                t!("gui-progress-target", current = progress.target.0 + 1, total = progress.target.1)
+            ));
+        }
+        if progress.step.1 > 0 {
+            label.push_str(&format!(
+                " {}",
+                t!(
+                    "gui-progress-step",
+                    current = progress.step.0 + 1,
+                    total = progress.step.1,
+                    name = progress.step_name
+                )
+            ));
+        }
+        if let Some((received, total)) = progress.download {
+            let megabytes = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+            label.push_str(&match total {
+                Some(total) => format!(" ({:.1} / {:.1} MB)", megabytes(received), megabytes(total)),
+                None => format!(" ({:.1} MB)", megabytes(received)),
+            });
+        }
+        label
+    }
+
+    /// Looks up the previous slot of the installation the buttons act on
+    fn refresh_previous_version(&mut self) {
+        self.previous_luna_version = match self.target_paths().as_slice() {
+            [path] => previous_install(&normalize_tidal_resources_path(PathBuf::from(path)))
+                .map(|previous| previous.version_label()),
+            _ => None,
+        };
+    }
+
+    /// Saves the custom path for the next start once an install or uninstall uses it,
+    /// so a half-typed or mistyped path is never remembered
+    fn remember_custom_path(&mut self) {
+        let path = self.custom_install_path.trim().to_string();
+        let remembered = if path.is_empty() {
+            None
+        } else if Path::new(&path).exists() {
+            Some(path)
+        } else {
+            return;
+        };
+
+        if self.config.gui_path != remembered {
+            self.config.gui_path = remembered;
+            self.save_config();
+        }
+    }
+
+    fn save_config(&mut self) {
+        if let Err(err) = config::save(&self.config) {
+            self.add_log(&t!("gui-settings-save-failed", error = err), LogLevel::Error);
+        }
+    }
 
     fn add_log(&mut self, message: &str, level: LogLevel) {
         let timestamp = SystemTime::now()
@@ -882,20 +1711,21 @@ impl MyApp {
             .unwrap_or_default()
             .as_millis() as u64;
 
+        self.next_log_id += 1;
         self.log_entries.push(LogEntry {
+            id: self.next_log_id,
             timestamp,
             message: message.to_string(),
             level,
         });
 
-        if self.log_entries.len() > 1000 {
-            self.log_entries.remove(0);
-        }
     }
 
     fn clear_log(&mut self) {
         self.log_entries.clear();
-        self.add_log("Log cleared", LogLevel::Info);
+        self.collapsed_log_steps.clear();
+        self.log_export_status = None;
+        self.add_log(&t!("gui-log-cleared"), LogLevel::Info);
     }
 
     fn stargazer_total_pages(&self) -> usize {
@@ -914,15 +1744,24 @@ impl MyApp {
         let Some(update) = &self.available_installer_update else {
             return Container::new(Row::new()).into();
         };
+        let palette = self.theme.palette();
+
+        let release_options: Vec<_> = self
+            .installer_releases
+            .iter()
+            .filter(|release| self.show_installer_prereleases || !release.prerelease)
+            .filter(|release| release.version != self.current_installer_version.trim_start_matches('v'))
+            .cloned()
+            .collect();
 
         let update_now_button = if self.is_applying_installer_update {
             button(
-                text("Updating...")
-                    .style(iced::theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+                text(t!("gui-updating"))
+                    .style(iced::theme::Text::Color(palette.disabled)),
             )
             .padding([10, 14])
         } else {
-            button(text("Update now"))
+            button(text(t!("gui-install-update", version = update.version)))
                 .on_press(Message::AcceptInstallerUpdate)
                 .padding([10, 14])
                 .style(iced::theme::Button::Primary)
@@ -930,47 +1769,45 @@ impl MyApp {
 
         let skip_button = if self.is_applying_installer_update {
             button(
-                text("Skip")
-                    .style(iced::theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+                text(t!("gui-skip"))
+                    .style(iced::theme::Text::Color(palette.disabled)),
             )
             .padding([10, 14])
         } else {
-            button(text("Skip this version"))
+            button(text(t!("gui-skip-version")))
                 .on_press(Message::DeclineInstallerUpdate)
                 .padding([10, 14])
                 .style(iced::theme::Button::Secondary)
         };
 
-        let warning_style = |_: &Theme| iced::widget::container::Appearance {
-            text_color: None,
-            background: Some(Background::Color(Color::from_rgba(0.26, 0.19, 0.08, 0.95))),
-            border: Border {
-                radius: 8.0.into(),
-                width: 1.0,
-                color: Color::from_rgba(0.95, 0.75, 0.25, 0.28),
-            },
-            shadow: Shadow {
-                color: Color::from_rgba(0.0, 0.0, 0.0, 0.38),
-                offset: Vector::new(0.0, 8.0),
-                blur_radius: 16.0,
-            },
-        };
 
         Container::new(
             Column::new()
                 .spacing(8)
                 .push(
-                    text("Installer Update Available")
+                    text(t!("gui-update-available"))
                         .size(16)
-                        .style(iced::theme::Text::Color(Color::from_rgb(0.98, 0.80, 0.35))),
+                        .style(iced::theme::Text::Color(palette.attention)),
                 )
                 .push(
-                    text(format!(
-                        "A newer installer version (v{}) is available. Update before continuing?",
-                        update.version
-                    ))
+                    text(t!("gui-update-available-body", version = self.current_installer_version))
                     .size(14)
-                    .style(iced::theme::Text::Color(Color::from_rgb(0.9, 0.9, 0.9))),
+                    .style(iced::theme::Text::Color(palette.text)),
+                )
+                .push(
+                    Row::new()
+                        .spacing(10)
+                        .align_items(Alignment::Center)
+                        .push(
+                            pick_list(
+                                release_options,
+                                Some(update.clone()),
+                                Message::InstallerReleaseSelected,
+                            )
+                            .padding([8, 10]),
+                        )
+                        .push(checkbox(t!("gui-show-prereleases"), self.show_installer_prereleases)
+                            .on_toggle(Message::ToggleInstallerPrereleases)),
                 )
                 .push(
                     Row::new()
@@ -982,48 +1819,466 @@ impl MyApp {
         )
         .padding(12)
         .width(Length::Fill)
-        .style(warning_style)
+        .style(palette.warning())
         .into()
     }
 
-    fn view_stargazers(&self) -> Element<'_, Message> {
-        let card_style = |_: &Theme| iced::widget::container::Appearance {
-            text_color: None,
-            background: Some(Background::Color(Color::from_rgba(0.11, 0.12, 0.16, 0.94))),
-            border: Border {
-                radius: 8.0.into(),
-                width: 1.0,
-                color: Color::from_rgba(0.45, 0.55, 0.9, 0.18),
-            },
-            shadow: Shadow {
-                color: Color::from_rgba(0.0, 0.0, 0.0, 0.45),
-                offset: Vector::new(0.0, 8.0),
-                blur_radius: 18.0,
-            },
+    /// Notes, publish date and pre-release flag of the selected version, and whether
+    /// installing it upgrades or downgrades what is installed
+    fn view_release_notes(&self) -> Element<'_, Message> {
+        let Some(info) = self.selected_release_version() else {
+            return Column::new().into();
+        };
+        let palette = self.theme.palette();
+
+        let mut header = Row::new().spacing(10).align_items(Alignment::Center).push(
+            text(t!("gui-release-title", version = info.version))
+                .size(16)
+                .style(iced::theme::Text::Color(palette.heading)),
+        );
+        if info.prerelease {
+            header = header.push(
+                text(t!("gui-release-prerelease"))
+                    .size(12)
+                    .style(iced::theme::Text::Color(palette.attention)),
+            );
+        }
+        if let Some(published) = &info.published_at {
+            // Only the date of the RFC 3339 timestamp
+            let date = published.get(..10).unwrap_or(published);
+            header = header.push(horizontal_space()).push(
+                text(t!("gui-release-published", date = date))
+                    .size(12)
+                    .style(iced::theme::Text::Color(palette.muted)),
+            );
+        }
+
+        let installed = self.installed_luna_versions();
+        let mut comparison = Column::new().spacing(4);
+        for (path, version) in &installed {
+            let (line, color) = match version {
+                None => (t!("gui-release-installed-unknown"), palette.muted),
+                Some(version) => match compare_versions(version, &info.version) {
+                    Some(Ordering::Greater) => (t!("gui-release-upgrade", installed = version), palette.success),
+                    Some(Ordering::Equal) => (t!("gui-release-same", installed = version), palette.muted),
+                    Some(Ordering::Less) => (
+                        t!("gui-release-downgrade", version = info.version, installed = version),
+                        palette.attention,
+                    ),
+                    None => (t!("gui-release-different", installed = version), palette.muted),
+                },
+            };
+            let line = if installed.len() > 1 {
+                format!("{}: {}", path, line)
+            } else {
+                line
+            };
+            comparison = comparison.push(text(line).size(13).style(iced::theme::Text::Color(color)));
+        }
+
+        let notes: Element<'_, Message> = match &info.notes {
+            Some(notes) => Container::new(scrollable(text(notes.trim()).size(13)).width(Length::Fill))
+                .max_height(180.0)
+                .into(),
+            None => text(t!("gui-release-no-notes"))
+                .size(13)
+                .style(iced::theme::Text::Color(palette.muted))
+                .into(),
+        };
+
+        Container::new(
+            Column::new()
+                .spacing(8)
+                .push(header)
+                .push(comparison)
+                .push(notes),
+        )
+        .padding(12)
+        .width(Length::Fill)
+        .style(palette.inset())
+        .into()
+    }
+
+    /// Shown when a refresh failed and the window shows cached data; dated by its oldest part
+    fn view_offline_banner(&self) -> Element<'_, Message> {
+        let saved_at = [self.releases_cached_at, self.stargazers_cached_at].into_iter().flatten().min();
+        let Some(saved_at) = saved_at.filter(|_| self.is_offline) else {
+            return Column::new().into();
+        };
+        let palette = self.theme.palette();
+
+        Container::new(
+            Row::new()
+                .spacing(12)
+                .align_items(Alignment::Center)
+                .push(
+                    Column::new()
+                        .spacing(4)
+                        .push(
+                            text(t!("gui-offline-banner", date = format_timestamp(saved_at)))
+                                .size(14)
+                                .style(iced::theme::Text::Color(palette.attention)),
+                        )
+                        .push(
+                            text(t!("gui-offline-hint"))
+                                .size(12)
+                                .style(iced::theme::Text::Color(palette.muted)),
+                        ),
+                )
+                .push(horizontal_space())
+                .push(
+                    button(text(t!("gui-offline-retry")).size(14))
+                        .on_press(Message::RefreshOnlineData)
+                        .padding([8, 14])
+                        .style(iced::theme::Button::Secondary),
+                ),
+        )
+        .padding([10, 16])
+        .width(Length::Fill)
+        .style(palette.warning())
+        .into()
+    }
+
+    /// What an uninstall removes, restores and stops, with its options and a confirm button
+    fn view_uninstall_preview<'a>(&'a self, preview: &'a UninstallPreview) -> Element<'a, Message> {
+        let palette = self.theme.palette();
+        let megabytes = |bytes: u64| format!("{:.1}", bytes as f64 / (1024.0 * 1024.0));
+        let muted = |content: String| {
+            text(content)
+                .size(12)
+                .style(iced::theme::Text::Color(palette.muted))
+        };
+
+        let mut targets = Column::new().spacing(10);
+        for target in &preview.targets {
+            let mut details = Column::new()
+                .spacing(4)
+                .push(muted(target.path.to_string_lossy().into_owned()));
+
+            details = if target.app_files == 0 {
+                details.push(text(t!("gui-uninstall-preview-no-luna")).size(13))
+            } else {
+                let version = target.luna_version.clone().unwrap_or_else(|| t!("unknown-version"));
+                details.push(
+                    text(t!(
+                        "gui-uninstall-preview-removes",
+                        version = version,
+                        files = target.app_files,
+                        size = megabytes(target.app_size)
+                    ))
+                    .size(13),
+                )
+            };
+
+            details = match (target.has_original_asar, &target.original_version) {
+                (true, Some(version)) => {
+                    details.push(text(t!("gui-uninstall-preview-restores", version = version)).size(13))
+                }
+                (true, None) => details.push(text(t!("gui-uninstall-preview-restores-unknown")).size(13)),
+                (false, _) => details.push(
+                    text(t!("gui-uninstall-preview-no-original"))
+                        .size(13)
+                        .style(iced::theme::Text::Color(palette.attention)),
+                ),
+            };
+
+            if let Some(previous) = &target.previous_version {
+                details = if preview.choices.keep_backup {
+                    details.push(
+                        text(t!("gui-uninstall-preview-replaces-previous", version = previous))
+                            .size(13)
+                            .style(iced::theme::Text::Color(palette.attention)),
+                    )
+                } else {
+                    details.push(muted(t!("gui-uninstall-preview-keeps-previous", version = previous)))
+                };
+            }
+
+            targets = targets.push(
+                Container::new(details)
+                    .padding(12)
+                    .width(Length::Fill)
+                    .style(palette.inset()),
+            );
+        }
+
+        let tidal_status = match preview.tidal_running {
+            Some(true) => text(t!("gui-uninstall-preview-tidal-running"))
+                .size(13)
+                .style(iced::theme::Text::Color(palette.attention)),
+            Some(false) => text(t!("gui-uninstall-preview-tidal-stopped")).size(13),
+            None => text(t!("gui-uninstall-preview-tidal-unknown")).size(13),
+        };
+        let mut tidal = Column::new().spacing(4).push(tidal_status);
+        if self.config.launch != LaunchPolicy::Never {
+            tidal = tidal.push(muted(t!("gui-uninstall-preview-relaunch")));
+        }
+
+        let keep_backup = checkbox(t!("gui-uninstall-keep-backup"), preview.choices.keep_backup)
+            .on_toggle(Message::ToggleUninstallKeepBackup)
+            .size(16);
+
+        let mut wipe = Column::new().spacing(4);
+        if preview.user_data.is_empty() {
+            wipe = wipe
+                .push(checkbox(t!("gui-uninstall-wipe-data"), false).size(16))
+                .push(muted(t!("gui-uninstall-no-user-data")));
+        } else {
+            wipe = wipe.push(
+                checkbox(t!("gui-uninstall-wipe-data"), preview.choices.wipe_user_data)
+                    .on_toggle(Message::ToggleUninstallWipeData)
+                    .size(16),
+            );
+            wipe = wipe.push(muted(t!("gui-uninstall-wipe-data-shared")));
+            for path in &preview.user_data {
+                wipe = wipe.push(muted(path.to_string_lossy().into_owned()));
+            }
+        }
+
+        let buttons = Row::new()
+            .spacing(10)
+            .push(horizontal_space())
+            .push(
+                button(text(t!("gui-cancel")).size(14))
+                    .on_press(Message::CancelUninstall)
+                    .padding([8, 14])
+                    .style(iced::theme::Button::Secondary),
+            )
+            .push(
+                button(
+                    text(t!("gui-uninstall"))
+                        .size(14)
+                        .style(iced::theme::Text::Color(palette.on_primary)),
+                )
+                .on_press_maybe((!self.is_busy()).then_some(Message::ConfirmUninstall))
+                .padding([8, 14])
+                .style(iced::theme::Button::Destructive),
+            );
+
+        Container::new(
+            Column::new()
+                .spacing(14)
+                .push(
+                    text(t!("gui-uninstall-preview-title"))
+                        .size(22)
+                        .style(iced::theme::Text::Color(palette.heading)),
+                )
+                .push(muted(t!("gui-uninstall-preview-intro")))
+                .push(targets)
+                .push(tidal)
+                .push(keep_backup)
+                .push(wipe)
+                .push(buttons),
+        )
+        .padding(20)
+        .max_width(640)
+        .style(palette.card())
+        .into()
+    }
+
+    /// A checked archive from a file drop with what it contains, until it is installed or discarded
+    fn view_dropped_archive(&self) -> Element<'_, Message> {
+        let Some(archive) = &self.dropped_archive else {
+            return Column::new().into();
         };
+        let palette = self.theme.palette();
+        let summary = &archive.summary;
+        let megabytes = |bytes: u64| format!("{:.1}", bytes as f64 / (1024.0 * 1024.0));
+
+        let version = match &summary.version {
+            Some(version) => t!("gui-archive-version", version = version),
+            None => t!("gui-archive-version-unknown"),
+        };
+        let entries = summary.top_level.join(", ");
+        let entries = if summary.more_entries > 0 {
+            t!("gui-archive-entries-more", entries = entries, count = summary.more_entries)
+        } else {
+            t!("gui-archive-entries", entries = entries)
+        };
+
+        let buttons = Row::new()
+            .spacing(10)
+            .push(
+                button(text(t!("gui-archive-install")).size(14))
+                    .on_press_maybe((!self.is_busy()).then_some(Message::InstallDroppedArchive))
+                    .padding([8, 14])
+                    .style(iced::theme::Button::Primary),
+            )
+            .push(
+                button(text(t!("gui-archive-discard")).size(14))
+                    .on_press(Message::DiscardDroppedArchive)
+                    .padding([8, 14])
+                    .style(iced::theme::Button::Secondary),
+            );
+
+        Container::new(
+            Column::new()
+                .spacing(6)
+                .push(
+                    text(t!("gui-archive-title", name = archive.file_name()))
+                        .size(16)
+                        .style(iced::theme::Text::Color(palette.heading)),
+                )
+                .push(
+                    text(archive.path.to_string_lossy())
+                        .size(12)
+                        .style(iced::theme::Text::Color(palette.muted)),
+                )
+                .push(text(version).size(13))
+                .push(
+                    text(t!(
+                        "gui-archive-contents",
+                        files = summary.files,
+                        size = megabytes(summary.unpacked_size),
+                        archive_size = megabytes(summary.size)
+                    ))
+                    .size(13)
+                    .style(iced::theme::Text::Color(palette.secondary)),
+                )
+                .push(text(entries).size(12).style(iced::theme::Text::Color(palette.muted)))
+                .push(buttons),
+        )
+        .padding(14)
+        .width(Length::Fill)
+        .style(palette.section())
+        .into()
+    }
+
+    /// One card per detected installation with what is installed and what can be done there
+    fn view_dashboard(&self) -> Element<'_, Message> {
+        let palette = self.theme.palette();
+
+        let title = text(t!("gui-installations"))
+            .size(16)
+            .style(iced::theme::Text::Color(palette.heading));
+
+        let mut cards = Column::new().spacing(10);
+        if self.installations.is_empty() {
+            cards = cards.push(
+                text(t!("gui-no-installations"))
+                    .size(13)
+                    .style(iced::theme::Text::Color(palette.muted)),
+            );
+        }
+
+        for card in &self.installations {
+            let heading = match &card.tidal_version {
+                Some(version) => format!("{} {}", card.flavor.label(), version),
+                None => card.flavor.label().to_string(),
+            };
+
+            let (luna_label, luna_color) = match (&card.receipt, card.luna_installed) {
+                (_, true) if card.needs_repair => (
+                    t!("gui-card-needs-repair"),
+                    palette.attention,
+                ),
+                (Some(receipt), true) => (
+                    t!("gui-card-luna-version", version = receipt.version, channel = receipt.channel),
+                    palette.success,
+                ),
+                (None, true) => (t!("gui-card-luna-unknown"), palette.success),
+                (_, false) => (t!("gui-card-luna-missing"), palette.text),
+            };
+
+            let mut backup_label = if card.has_backup {
+                t!("gui-card-backup")
+            } else {
+                t!("gui-card-no-backup")
+            };
+            if let Some(previous) = &card.previous_version {
+                backup_label.push_str(&t!("gui-card-previous", version = previous));
+            }
+
+            let action_button = |label: String, action: CardAction, style: iced::theme::Button| {
+                button(text(label).size(13))
+                    .on_press_maybe((!self.is_busy()).then(|| Message::CardAction(card.path.clone(), action)))
+                    .padding([6, 12])
+                    .style(style)
+            };
+
+            let mut actions = Row::new().spacing(8).align_items(Alignment::Center);
+            if !card.luna_installed {
+                actions = actions.push(action_button(
+                    t!("gui-install"),
+                    CardAction::Install,
+                    iced::theme::Button::Primary,
+                ));
+            } else {
+                if card.needs_repair {
+                    actions = actions.push(action_button(
+                        t!("gui-repair"),
+                        CardAction::Repair,
+                        iced::theme::Button::Primary,
+                    ));
+                }
+                if let Some((_, version)) = self.card_update(card) {
+                    actions = actions.push(action_button(
+                        t!("gui-update-to", version = version),
+                        CardAction::Update,
+                        iced::theme::Button::Primary,
+                    ));
+                }
+                actions = actions.push(action_button(
+                    t!("gui-uninstall"),
+                    CardAction::Uninstall,
+                    iced::theme::Button::Destructive,
+                ));
+            }
+
+            let details = Column::new()
+                .spacing(4)
+                .width(Length::Fill)
+                .push(text(heading).size(15))
+                .push(text(&card.path).size(12).style(iced::theme::Text::Color(palette.muted)))
+                .push(text(luna_label).size(13).style(iced::theme::Text::Color(luna_color)))
+                .push(text(backup_label).size(12).style(iced::theme::Text::Color(palette.muted)));
+
+            cards = cards.push(
+                Container::new(
+                    Row::new()
+                        .spacing(12)
+                        .align_items(Alignment::Center)
+                        .push(details)
+                        .push(actions),
+                )
+                .padding(10)
+                .width(Length::Fill)
+                .style(palette.inset()),
+            );
+        }
+
+        Container::new(Column::new().spacing(10).push(title).push(cards))
+            .padding(14)
+            .width(Length::Fill)
+            .style(palette.section())
+            .into()
+    }
+
+    fn view_stargazers(&self) -> Element<'_, Message> {
+        let palette = self.theme.palette();
 
-        let title = text("Stargazers <3")
+        let title = text(t!("gui-stargazers"))
             .size(16)
-            .style(iced::theme::Text::Color(Color::from_rgb(0.80, 0.83, 0.90)));
+            .style(iced::theme::Text::Color(palette.heading));
 
-        let subtitle = text("Thanks to everyone who starred this project.")
+        let subtitle = text(t!("gui-stargazers-thanks"))
             .size(12)
-            .style(iced::theme::Text::Color(Color::from_rgb(0.65, 0.68, 0.75)));
+            .style(iced::theme::Text::Color(palette.muted));
 
         let section_body: Element<'_, Message> = if self.is_loading_stargazers {
-            text("Loading stargazers...")
+            text(t!("gui-stargazers-loading"))
                 .size(13)
-                .style(iced::theme::Text::Color(Color::from_rgb(0.72, 0.74, 0.8)))
+                .style(iced::theme::Text::Color(palette.muted))
                 .into()
         } else if let Some(err) = &self.stargazers_error {
-            text(format!("Could not load stargazers: {}", err))
+            text(t!("gui-stargazers-error", error = err))
                 .size(13)
-                .style(iced::theme::Text::Color(Color::from_rgb(0.78, 0.44, 0.44)))
+                .style(iced::theme::Text::Color(palette.error))
                 .into()
         } else if self.stargazers.is_empty() {
-            text("No stargazers found yet.")
+            text(t!("gui-stargazers-empty"))
                 .size(13)
-                .style(iced::theme::Text::Color(Color::from_rgb(0.72, 0.74, 0.8)))
+                .style(iced::theme::Text::Color(palette.muted))
                 .into()
         } else {
             let per_row = 14;
@@ -1058,27 +2313,27 @@ impl MyApp {
             }
 
             let prev_button = if page > 0 {
-                button(text("Previous").size(12))
+                button(text(t!("gui-previous")).size(12))
                     .on_press(Message::PrevStargazersPage)
                     .padding([6, 10])
             } else {
                 button(
-                    text("Previous")
+                    text(t!("gui-previous"))
                         .size(12)
-                        .style(iced::theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+                        .style(iced::theme::Text::Color(palette.disabled)),
                 )
                 .padding([6, 10])
             };
 
             let next_button = if page + 1 < total_pages {
-                button(text("Next").size(12))
+                button(text(t!("gui-next")).size(12))
                     .on_press(Message::NextStargazersPage)
                     .padding([6, 10])
             } else {
                 button(
-                    text("Next")
+                    text(t!("gui-next"))
                         .size(12)
-                        .style(iced::theme::Text::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+                        .style(iced::theme::Text::Color(palette.disabled)),
                 )
                 .padding([6, 10])
             };
@@ -1094,16 +2349,16 @@ impl MyApp {
                         .push(next_button)
                         .push(horizontal_space())
                         .push(
-                            text(format!(
-                                "Showing {}-{} of {} • Page {}/{}",
-                                start + 1,
-                                end,
-                                self.stargazers.len(),
-                                page + 1,
-                                total_pages
+                            text(t!(
+                                "gui-stargazers-page",
+                                first = start + 1,
+                                last = end,
+                                total = self.stargazers.len(),
+                                page = page + 1,
+                                pages = total_pages
                             ))
                             .size(12)
-                            .style(iced::theme::Text::Color(Color::from_rgb(0.65, 0.68, 0.75))),
+                            .style(iced::theme::Text::Color(palette.muted)),
                         ),
                 )
                 .into()
@@ -1118,7 +2373,7 @@ impl MyApp {
         )
         .padding(14)
         .width(Length::Fill)
-        .style(card_style)
+        .style(palette.card())
         .into()
     }
 }
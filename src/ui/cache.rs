@@ -0,0 +1,103 @@
+use iced::widget::image;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::utils::config;
+use crate::utils::history::{now, write_atomic};
+use crate::utils::invoking_user::invoking_user;
+
+use super::models::{AppRelease, Stargazer};
+
+const RELEASES_FILE: &str = "releases.json";
+const STARGAZERS_FILE: &str = "stargazers.json";
+const AVATARS_DIR: &str = "avatars";
+
+/// Data from the last successful load and when it was saved (Unix seconds)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cached<T> {
+    pub saved_at: u64,
+    pub data: T,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedStargazer {
+    login: String,
+    tooltip_text: String,
+}
+
+fn read<T: DeserializeOwned>(path: &Path) -> Option<Cached<T>> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Under pkexec/sudo the cache is handed back to the user, so unprivileged runs can refresh it
+fn write<T: Serialize>(path: &Path, data: T) -> std::io::Result<()> {
+    let contents = serde_json::to_string(&Cached { saved_at: now(), data }).map_err(std::io::Error::other)?;
+    write_atomic(path, contents)
+}
+
+/// Logins are sanitized before they get here, so they are safe as file names
+fn avatar_path(dir: &Path, login: &str) -> PathBuf {
+    dir.join(AVATARS_DIR).join(login)
+}
+
+pub fn load_releases() -> Option<Cached<Vec<AppRelease>>> {
+    read(&config::gui_cache_dir()?.join(RELEASES_FILE))
+}
+
+pub fn save_releases(releases: &[AppRelease]) -> std::io::Result<()> {
+    let Some(dir) = config::gui_cache_dir() else {
+        return Ok(());
+    };
+    write(&dir.join(RELEASES_FILE), releases)
+}
+
+/// Stargazers whose avatar is missing from the cache are left out
+pub fn load_stargazers() -> Option<Cached<Vec<Stargazer>>> {
+    let dir = config::gui_cache_dir()?;
+    let cached: Cached<Vec<CachedStargazer>> = read(&dir.join(STARGAZERS_FILE))?;
+    let stargazers = cached
+        .data
+        .into_iter()
+        .filter_map(|stargazer| {
+            let avatar = std::fs::read(avatar_path(&dir, &stargazer.login)).ok()?;
+            Some(Stargazer {
+                login: stargazer.login,
+                tooltip_text: stargazer.tooltip_text,
+                avatar: image::Handle::from_memory(avatar),
+            })
+        })
+        .collect();
+
+    Some(Cached {
+        saved_at: cached.saved_at,
+        data: stargazers,
+    })
+}
+
+/// Takes the avatars as downloaded, since an `image::Handle` does not give its bytes back
+pub fn save_stargazers(stargazers: &[(Stargazer, Vec<u8>)]) -> std::io::Result<()> {
+    let Some(dir) = config::gui_cache_dir() else {
+        return Ok(());
+    };
+
+    let list: Vec<CachedStargazer> = stargazers
+        .iter()
+        .map(|(stargazer, _)| CachedStargazer {
+            login: stargazer.login.clone(),
+            tooltip_text: stargazer.tooltip_text.clone(),
+        })
+        .collect();
+    write(&dir.join(STARGAZERS_FILE), list)?;
+
+    let avatars = dir.join(AVATARS_DIR);
+    let _ = std::fs::remove_dir_all(&avatars);
+    std::fs::create_dir_all(&avatars)?;
+    for (stargazer, avatar) in stargazers {
+        std::fs::write(avatar_path(&dir, &stargazer.login), avatar)?;
+    }
+    if let Some(user) = invoking_user() {
+        user.chown_recursive(&avatars)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use iced::widget::{button, checkbox, scrollable, text, text_input, Column, Container, Row};
+use iced::{Alignment, Element, Length};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::t;
+use crate::utils::redact::redact_home_paths;
+
+use super::models::{LogEntry, LogLevel, Message, MyApp};
+use super::theme::Palette;
+
+fn export_dir() -> PathBuf {
+    dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(std::env::temp_dir)
+}
+
+/// Where "Save" writes unless the user picks another path
+pub fn default_log_save_path() -> PathBuf {
+    export_dir().join("tidaluna-installer-log.txt")
+}
+
+/// Where the diagnostic bundle for bug reports is written
+pub fn default_bundle_path() -> PathBuf {
+    export_dir().join("tidaluna-installer-diagnostics.zip")
+}
+
+fn level_prefix(level: LogLevel) -> &'static str {
+    if cfg!(target_os = "windows") {
+        match level {
+            LogLevel::Step => ">> ",
+            LogLevel::SubStep => "  -> ",
+            _ => "* ",
+        }
+    } else {
+        match level {
+            LogLevel::Step => "▶ ",
+            LogLevel::SubStep => "  → ",
+            _ => "• ",
+        }
+    }
+}
+
+/// One log line; `fold` is the collapse toggle of a step with its sub-log count
+pub fn log_entry_row<'a>(
+    entry: &'a LogEntry,
+    fold: Option<(bool, usize)>,
+    palette: Palette,
+) -> Element<'a, Message> {
+    let timestamp_secs = entry.timestamp / 1000;
+    let minutes = (timestamp_secs / 60) % 60;
+    let seconds = timestamp_secs % 60;
+    let millis = entry.timestamp % 1000;
+    let timestamp = format!("{:02}:{:02}.{:03}", minutes, seconds, millis);
+
+    let mut row = Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(
+            text(timestamp)
+                .size(12)
+                .style(iced::theme::Text::Color(palette.muted))
+                .width(80),
+        )
+        .push(
+            text(format!("{}{}", level_prefix(entry.level), entry.message))
+                .size(14)
+                .style(iced::theme::Text::Color(palette.log_color(entry.level))),
+        );
+
+    if let Some((collapsed, count)) = fold {
+        let label = if collapsed {
+            t!("gui-log-show-lines", count = count)
+        } else {
+            t!("gui-log-hide-details")
+        };
+        row = row.push(
+            button(text(label).size(11))
+                .on_press(Message::ToggleLogStep(entry.id))
+                .padding([2, 8])
+                .style(iced::theme::Button::Text),
+        );
+    }
+
+    Container::new(row).padding([6, 8]).width(Length::Fill).into()
+}
+
+/// Sub-log lines belong to the step line before them: entry id -> owning step id
+fn step_owners(entries: &[LogEntry]) -> (HashMap<u64, u64>, HashMap<u64, usize>) {
+    let mut owners = HashMap::new();
+    let mut counts = HashMap::new();
+    let mut current = None;
+
+    for entry in entries {
+        if entry.level == LogLevel::SubStep {
+            if let Some(step) = current {
+                owners.insert(entry.id, step);
+                *counts.entry(step).or_insert(0) += 1;
+            }
+        } else {
+            current = Some(entry.id);
+        }
+    }
+
+    (owners, counts)
+}
+
+/// The whole log as text, with home directories replaced by `~`
+pub fn export_log(entries: &[LogEntry], installer_version: &str) -> String {
+    let mut out = format!("TidaLuna Installer v{} log (times in UTC)\n\n", installer_version);
+
+    for entry in entries {
+        let secs = entry.timestamp / 1000;
+        out.push_str(&format!(
+            "{:02}:{:02}:{:02}.{:03} {:<8} {}\n",
+            (secs / 3600) % 24,
+            (secs / 60) % 60,
+            secs % 60,
+            entry.timestamp % 1000,
+            entry.level.label(),
+            entry.message
+        ));
+    }
+
+    redact_home_paths(&out)
+}
+
+impl MyApp {
+    pub(super) fn view_log_window(&self) -> Element<'_, Message> {
+        let palette = self.theme.palette();
+
+        let search_input = text_input(&t!("gui-log-search"), &self.log_search)
+            .on_input(Message::LogSearchChanged)
+            .padding(8)
+            .width(Length::Fill);
+
+        let filters = LogLevel::ALL.iter().fold(
+            Row::new().spacing(14).align_items(Alignment::Center),
+            |row, level| {
+                let level = *level;
+                row.push(
+                    checkbox(level.display_name(), !self.hidden_log_levels.contains(&level))
+                        .on_toggle(move |shown| Message::ToggleLogLevel(level, shown))
+                        .size(14),
+                )
+            },
+        );
+
+        let save_path = text_input(&t!("gui-log-save-path"), &self.log_save_path)
+            .on_input(Message::LogSavePathChanged)
+            .padding(8)
+            .width(Length::Fill);
+
+        let export_row = Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(save_path)
+            .push(
+                button(text(t!("gui-log-save")).size(14))
+                    .on_press(Message::SaveLog)
+                    .padding([8, 14])
+                    .style(iced::theme::Button::Primary),
+            )
+            .push(
+                button(text(t!("gui-log-copy")).size(14))
+                    .on_press(Message::CopyLog)
+                    .padding([8, 14])
+                    .style(iced::theme::Button::Secondary),
+            )
+            .push(
+                button(text(t!("gui-diagnostics-export")).size(14))
+                    .on_press_maybe((!self.is_exporting_diagnostics).then_some(Message::ExportDiagnostics))
+                    .padding([8, 14])
+                    .style(iced::theme::Button::Secondary),
+            );
+
+        let search = self.log_search.trim().to_lowercase();
+        let (owners, counts) = step_owners(&self.log_entries);
+        let visible: Vec<Element<Message>> = self
+            .log_entries
+            .iter()
+            .filter(|entry| !self.hidden_log_levels.contains(&entry.level))
+            .filter(|entry| search.is_empty() || entry.message.to_lowercase().contains(&search))
+            .filter(|entry| {
+                owners
+                    .get(&entry.id)
+                    .is_none_or(|step| !self.collapsed_log_steps.contains(step))
+            })
+            .map(|entry| {
+                let fold = counts
+                    .get(&entry.id)
+                    .map(|count| (self.collapsed_log_steps.contains(&entry.id), *count));
+                log_entry_row(entry, fold, palette)
+            })
+            .collect();
+
+        let shown = visible.len();
+        let summary = match &self.log_export_status {
+            Some(status) => format!("{}. {}", t!("gui-log-count", shown = shown, total = self.log_entries.len()), status),
+            None => t!("gui-log-count", shown = shown, total = self.log_entries.len()),
+        };
+
+        Container::new(
+            Column::new()
+                .spacing(10)
+                .padding(16)
+                .push(search_input)
+                .push(filters)
+                .push(export_row)
+                .push(text(summary).size(12).style(iced::theme::Text::Color(palette.muted)))
+                .push(scrollable(Column::with_children(visible).spacing(2)).height(Length::Fill)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(palette.window())
+        .into()
+    }
+}
@@ -1,7 +1,22 @@
 use iced::widget::{combo_box, image};
+use iced::window;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::runtime::Runtime;
+use tokio::sync::{mpsc, Mutex};
+use crate::installer::manager::{CancelFlag, InstallManager};
+use crate::installer::steps::local_archive::ArchiveSummary;
+use crate::t;
+use crate::utils::i18n;
+use crate::utils::config::{Config, LaunchPolicy, ThemePreference};
+use crate::utils::fs_helpers::TidalFlavor;
+use crate::utils::receipt::InstallReceipt;
+
+use super::cache::Cached;
+use super::theme::ThemeVariant;
 
 pub type InstallerUpdateInfo = crate::utils::updater::UpdateInfo;
 pub type InstallerUpdateApplyResult = crate::utils::updater::UpdateApplyResult;
@@ -16,30 +31,182 @@ pub struct AppRelease {
 pub struct AppVersionInfo {
     pub version: String,
     pub download: String,
+    pub notes: Option<String>,
+    pub published_at: Option<String>,
+    pub prerelease: bool,
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
     LoadReleases,
     ReleasesLoaded(Result<Vec<AppRelease>, String>),
-    InstallerUpdateChecked(Result<Option<InstallerUpdateInfo>, String>),
+    CachedReleasesLoaded(Option<Cached<Vec<AppRelease>>>),
+    /// Loads releases and stargazers again after an offline start
+    RefreshOnlineData,
+    InstallerReleasesLoaded(Result<Vec<InstallerUpdateInfo>, String>),
+    InstallerReleaseSelected(InstallerUpdateInfo),
+    ToggleInstallerPrereleases(bool),
     AcceptInstallerUpdate,
     DeclineInstallerUpdate,
     InstallerUpdateApplied(Result<InstallerUpdateApplyResult, String>),
     StargazersLoaded(Result<Vec<Stargazer>, String>),
+    CachedStargazersLoaded(Option<Cached<Vec<Stargazer>>>),
     ReleaseChannelSelected(String),
     VersionSelected(String),
     InstallPathChanged(String),
     InstallPathOptionSelected(String),
     TidalPathsDetected(Result<Vec<String>, String>),
+    InstallationsLoaded(Vec<InstallationCard>),
+    /// A file was dropped onto a window
+    FileDropped(PathBuf),
+    ArchiveInspected(PathBuf, Result<ArchiveSummary, String>),
+    InstallDroppedArchive,
+    DiscardDroppedArchive,
+    CardAction(String, CardAction),
     Install,
+    /// Opens the uninstall preview for the target paths
     Uninstall,
-    InstallationComplete(Result<InstallExecutionResult, String>),
+    UninstallPreviewLoaded(Result<UninstallPreview, String>),
+    ToggleUninstallKeepBackup(bool),
+    ToggleUninstallWipeData(bool),
+    ConfirmUninstall,
+    CancelUninstall,
+    Rollback,
+    CancelRun,
+    RetryFailedSteps,
+    /// An update from the run with this id
+    RunUpdate(u64, RunUpdate),
     InstallationStatus(bool),
     ToggleAdvancedOptions(bool),
+    ToggleVerifyAfterInstall(bool),
+    ToggleRollbackOnVerifyFailure(bool),
+    ToggleApplyToAll(bool),
+    LanguageSelected(LanguageChoice),
+    ThemeSelected(ThemePreference),
     PrevStargazersPage,
     NextStargazersPage,
     ClearLog,
+    OpenLogWindow,
+    WindowClosed(window::Id),
+    /// The OS theme may have changed while the installer was in the background
+    WindowFocused,
+    LogSearchChanged(String),
+    ToggleLogLevel(LogLevel, bool),
+    ToggleLogStep(u64),
+    LogSavePathChanged(String),
+    SaveLog,
+    CopyLog,
+    /// Writes the diagnostic bundle for bug reports
+    ExportDiagnostics,
+    DiagnosticsExported(Result<PathBuf, String>),
+}
+
+/// Entry of the language picker; `None` follows the system language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LanguageChoice(pub Option<&'static str>);
+
+impl LanguageChoice {
+    pub fn all() -> Vec<LanguageChoice> {
+        std::iter::once(LanguageChoice(None))
+            .chain(i18n::available_locales().into_iter().map(|locale| LanguageChoice(Some(locale))))
+            .collect()
+    }
+
+    /// The picker entry for a saved `locale` setting
+    pub fn from_setting(locale: Option<&str>) -> LanguageChoice {
+        LanguageChoice(locale.and_then(|locale| {
+            i18n::available_locales()
+                .into_iter()
+                .find(|available| available.eq_ignore_ascii_case(locale))
+        }))
+    }
+}
+
+impl fmt::Display for LanguageChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(locale) => f.write_str(&i18n::language_name(locale)),
+            None => f.write_str(&t!("gui-language-system")),
+        }
+    }
+}
+
+/// A Luna archive dropped onto the window, checked and waiting to be installed
+#[derive(Debug, Clone)]
+pub struct DroppedArchive {
+    pub path: PathBuf,
+    pub summary: ArchiveSummary,
+}
+
+impl DroppedArchive {
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    /// What the receipt records: the archive's own version, else its file name
+    pub fn version(&self) -> String {
+        self.summary.version.clone().unwrap_or_else(|| {
+            self.path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// Options of the uninstall preview
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UninstallChoices {
+    /// Keep Luna in the previous slot so it can be rolled back
+    pub keep_backup: bool,
+    /// Also delete Luna's settings and plugin storage
+    pub wipe_user_data: bool,
+}
+
+/// What uninstalling would change in one installation
+#[derive(Debug, Clone)]
+pub struct UninstallTarget {
+    pub path: PathBuf,
+    /// Files and bytes below `app/`
+    pub app_files: usize,
+    pub app_size: u64,
+    /// From the receipt of the Luna install
+    pub luna_version: Option<String>,
+    pub has_original_asar: bool,
+    /// TIDAL version inside original.asar, which becomes app.asar again
+    pub original_version: Option<String>,
+    /// Version label of the previous slot, which a backup replaces
+    pub previous_version: Option<String>,
+}
+
+/// Shown before an uninstall starts, until it is confirmed or cancelled
+#[derive(Debug, Clone)]
+pub struct UninstallPreview {
+    pub paths: Vec<String>,
+    pub targets: Vec<UninstallTarget>,
+    /// `None` when the OS cannot tell
+    pub tidal_running: Option<bool>,
+    pub user_data: Vec<PathBuf>,
+    pub choices: UninstallChoices,
+}
+
+#[derive(Debug, Clone)]
+pub struct InstallRequest {
+    pub channel: String,
+    pub version: String,
+    pub download_url: String,
+    pub paths: Vec<String>,
+    pub reinstall_mode: bool,
+    /// Reinstall after a TIDAL update replaced app.asar, see `InstallOptions::refresh_asar_backup`
+    pub repair: bool,
+    pub verify: bool,
+    pub verify_timeout: u64,
+    pub rollback_on_failure: bool,
+    pub launch_tidal: bool,
+    pub cache_dir: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
@@ -48,10 +215,114 @@ pub struct InstallExecutionLog {
     pub is_substep: bool,
 }
 
+/// A pipeline of the last run that did not finish. Retrying runs the same manager again
+/// from `step`, so whatever the earlier steps left on disk (the download) is reused.
+#[derive(Clone)]
+pub struct ResumePoint {
+    pub path: PathBuf,
+    /// What the install history calls the run, and `(channel, version)` for installs
+    pub action: &'static str,
+    pub release: Option<(String, String)>,
+    pub manager: Arc<InstallManager>,
+    pub step: usize,
+    pub step_name: String,
+}
+
+impl fmt::Debug for ResumePoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResumePoint")
+            .field("path", &self.path)
+            .field("step", &self.step)
+            .field("step_name", &self.step_name)
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct InstallExecutionResult {
-    pub logs: Vec<InstallExecutionLog>,
     pub success: bool,
+    pub cancelled: bool,
+    /// One entry per installation that failed or was cancelled
+    pub resume: Vec<ResumePoint>,
+}
+
+/// What a running install, uninstall or rollback reports while it works
+#[derive(Debug, Clone)]
+pub enum RunUpdate {
+    Log(InstallExecutionLog),
+    /// Multi-target runs start each installation with this
+    TargetStarted { index: usize, total: usize },
+    StepStarted { index: usize, total: usize, name: String },
+    Download { received: u64, total: Option<u64> },
+    Finished(Result<InstallExecutionResult, String>),
+}
+
+/// The receiving end of a run, shared with the subscription that forwards it
+pub type RunEvents = Arc<Mutex<mpsc::UnboundedReceiver<RunUpdate>>>;
+
+/// Where the running operation is, for the progress bar
+#[derive(Debug, Clone)]
+pub struct RunProgress {
+    pub target: (usize, usize),
+    pub step: (usize, usize),
+    pub step_name: String,
+    pub download: Option<(u64, Option<u64>)>,
+}
+
+impl Default for RunProgress {
+    fn default() -> Self {
+        Self {
+            target: (0, 1),
+            step: (0, 0),
+            step_name: String::new(),
+            download: None,
+        }
+    }
+}
+
+impl RunProgress {
+    /// 0-100 over all targets; the running step counts by its download progress
+    pub fn percent(&self) -> f32 {
+        let (step_index, step_total) = self.step;
+        let step_fraction = match self.download {
+            Some((received, Some(total))) if total > 0 => (received as f32 / total as f32).min(1.0),
+            _ => 0.0,
+        };
+        let target_fraction = if step_total == 0 {
+            0.0
+        } else {
+            (step_index as f32 + step_fraction) / step_total as f32
+        };
+        let (target_index, target_total) = self.target;
+        100.0 * (target_index as f32 + target_fraction) / target_total.max(1) as f32
+    }
+}
+
+/// One detected TIDAL installation on the dashboard
+#[derive(Debug, Clone)]
+pub struct InstallationCard {
+    pub path: String,
+    pub flavor: TidalFlavor,
+    pub tidal_version: Option<String>,
+    pub luna_installed: bool,
+    /// Installed but TIDAL's app.asar is back, so Luna does not load
+    pub needs_repair: bool,
+    /// Missing for installs made before receipts existed
+    pub receipt: Option<InstallReceipt>,
+    /// original.asar is present
+    pub has_backup: bool,
+    pub previous_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardAction {
+    /// The version selected at the top
+    Install,
+    /// The newest version of the installed channel
+    Update,
+    /// The installed version again, after a TIDAL update
+    Repair,
+    Uninstall,
 }
 
 #[derive(Debug, Clone)]
@@ -71,34 +342,80 @@ pub struct MyApp {
     pub is_loading: bool,
     pub is_installing: bool,
     pub is_uninstalling: bool,
+    pub is_rolling_back: bool,
+    /// Version label of the install in the previous slot, if rollback is possible
+    pub previous_luna_version: Option<String>,
     pub is_advanced_open: bool,
+    pub verify_after_install: bool,
+    /// The launch policy verification replaced, restored when it is switched off again
+    pub launch_before_verify: Option<LaunchPolicy>,
+    pub rollback_on_verify_failure: bool,
+    pub apply_to_all: bool,
     pub is_luna_installed: bool,
     pub is_loading_stargazers: bool,
+    /// When the shown releases were cached; `None` once they come from the network
+    pub releases_cached_at: Option<u64>,
+    pub stargazers_cached_at: Option<u64>,
+    /// The last refresh failed, so cached data is all there is
+    pub is_offline: bool,
 
     pub channel_pick_list: combo_box::State<String>,
     pub version_pick_list: combo_box::State<String>,
     pub install_path_pick_list: combo_box::State<String>,
     pub install_path_options: Vec<String>,
+    pub installations: Vec<InstallationCard>,
+    pub dropped_archive: Option<DroppedArchive>,
+    pub uninstall_preview: Option<UninstallPreview>,
+    pub is_loading_uninstall_preview: bool,
     pub stargazers: Vec<Stargazer>,
     pub stargazers_error: Option<String>,
     pub stargazers_page: usize,
     pub current_installer_version: String,
+    /// Installer releases for this platform, newest first
+    pub installer_releases: Vec<InstallerUpdateInfo>,
+    pub show_installer_prereleases: bool,
+    /// The release the update prompt will install; preselected with the newest update
     pub available_installer_update: Option<InstallerUpdateInfo>,
     pub show_installer_update_prompt: bool,
     pub is_applying_installer_update: bool,
 
+    /// Id and events of the running operation; the subscription forwards them
+    pub active_run: Option<(u64, RunEvents)>,
+    pub next_run_id: u64,
+    pub run_progress: Option<RunProgress>,
+    /// Stops the running operation; kept after it ends because a retry reuses it
+    pub cancel_flag: CancelFlag,
+    pub is_cancelling: bool,
+    pub is_retrying: bool,
+    pub resume_points: Vec<ResumePoint>,
+
     pub log_entries: Vec<LogEntry>,
+    pub next_log_id: u64,
+    /// The pop-out log window, while it is open
+    pub log_window: Option<window::Id>,
+    pub log_search: String,
+    pub hidden_log_levels: HashSet<LogLevel>,
+    /// Ids of step entries whose sub-logs are folded away
+    pub collapsed_log_steps: HashSet<u64>,
+    pub log_save_path: String,
+    /// Result of the last save or copy, shown in the log window
+    pub log_export_status: Option<String>,
+    pub is_exporting_diagnostics: bool,
+    /// `config.theme` resolved against the OS setting
+    pub theme: ThemeVariant,
+    pub config: Config,
     pub runtime: Arc<Runtime>,
 }
 
 #[derive(Debug, Clone)]
 pub struct LogEntry {
+    pub id: u64,
     pub timestamp: u64,
     pub message: String,
     pub level: LogLevel,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LogLevel {
     Info,
     Success,
@@ -106,3 +423,34 @@ pub enum LogLevel {
     Step,
     SubStep,
 }
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Info,
+        LogLevel::Success,
+        LogLevel::Error,
+        LogLevel::Step,
+        LogLevel::SubStep,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Info => "Info",
+            LogLevel::Success => "Success",
+            LogLevel::Error => "Error",
+            LogLevel::Step => "Step",
+            LogLevel::SubStep => "Sub-step",
+        }
+    }
+
+    /// `label` in the user's language; exports keep the English label
+    pub fn display_name(&self) -> String {
+        match self {
+            LogLevel::Info => t!("gui-level-info"),
+            LogLevel::Success => t!("gui-level-success"),
+            LogLevel::Error => t!("gui-level-error"),
+            LogLevel::Step => t!("gui-level-step"),
+            LogLevel::SubStep => t!("gui-level-substep"),
+        }
+    }
+}
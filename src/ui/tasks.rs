@@ -1,56 +1,66 @@
+use iced::futures::stream;
 use iced::widget::image;
+use iced::Subscription;
 use serde::Deserialize;
-use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
-use tokio::sync::Semaphore;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::{mpsc, Semaphore};
 use tokio::runtime::Runtime;
 
-use crate::installer::{
-    manager::InstallManager,
-    steps::{
-        copy_asar_install::CopyAsarInstallStep, copy_asar_uninstall::CopyAsarUninstallStep,
-        download_luna::DownloadLunaStep, extract_luna::ExtractLunaStep,
-        insert_luna::InsertLunaStep, kill_tidal::KillTidalStep, launch_tidal::LaunchTidalStep,
-        reinstall_cleanup::ReinstallCleanupStep, setup::SetupStep, sign_tidal::SignTidalStep,
-        uninstall::UninstallStep,
-    },
+use crate::installer::manager::{CancelFlag, InstallEvent, InstallManager, RunOutcome};
+use crate::installer::pipeline::{
+    install_pipeline, kill_pipeline, rollback_pipeline, uninstall_pipeline, InstallOptions, TargetOutcome,
+    TargetOptions, VerifyOptions,
 };
+use crate::installer::steps::download_luna::DownloadProgress;
+use crate::installer::steps::kill_tidal::is_tidal_running;
+use crate::installer::steps::local_archive::{summarize_archive, ArchiveSummary};
+use crate::t;
 use crate::utils::{
-    fs_helpers::{find_tidal_directories, is_luna_installed, normalize_tidal_resources_path},
+    asar,
+    diagnostics,
+    history::RunRecorder,
+    fs_helpers::{
+        detect_tidal_flavor, dir_stats, find_tidal_directories, has_tidal_app_asar, is_luna_installed,
+        luna_user_data_paths, normalize_tidal_resources_path,
+    },
+    config,
+    http,
+    receipt::{previous_install, read_receipt},
     release_loader::ReleaseLoader,
     updater,
 };
 
+use super::cache::{self, Cached};
 use super::models::{
-    AppRelease, AppVersionInfo, InstallExecutionLog, InstallExecutionResult,
-    InstallerUpdateApplyResult, InstallerUpdateInfo, Stargazer,
+    AppRelease, AppVersionInfo, InstallExecutionLog, InstallationCard, InstallExecutionResult, InstallRequest,
+    InstallerUpdateApplyResult, InstallerUpdateInfo, Message, ResumePoint, RunEvents, RunUpdate, Stargazer,
+    UninstallChoices, UninstallPreview, UninstallTarget,
 };
 
-pub async fn check_installer_update_async(
-    runtime: Arc<Runtime>,
-    current_version: String,
-) -> Result<Option<InstallerUpdateInfo>, String> {
-    let result = runtime
-        .spawn(async move { updater::check_for_update(&current_version).await })
-        .await;
+type RunSender = mpsc::UnboundedSender<RunUpdate>;
+
+pub async fn load_installer_releases_async(runtime: Arc<Runtime>) -> Result<Vec<InstallerUpdateInfo>, String> {
+    let result = runtime.spawn(async move { updater::list_releases().await }).await;
 
     match result {
         Ok(inner) => inner,
-        Err(_) => Err("Failed to check for installer updates: task cancelled".to_string()),
+        Err(_) => Err(t!("gui-update-check-cancelled")),
     }
 }
 
 pub async fn apply_installer_update_async(
     runtime: Arc<Runtime>,
-    download_url: String,
+    update: InstallerUpdateInfo,
 ) -> Result<InstallerUpdateApplyResult, String> {
     let result = runtime
-        .spawn(async move { updater::apply_update(&download_url, true).await })
+        .spawn(async move { updater::apply_update(&update, true).await })
         .await;
 
     match result {
         Ok(inner) => inner,
-        Err(_) => Err("Failed to apply installer update: task cancelled".to_string()),
+        Err(_) => Err(t!("gui-update-apply-cancelled")),
     }
 }
 
@@ -95,14 +105,12 @@ fn sanitize_profile_url(url: &str, fallback_login: &str) -> String {
 
 pub async fn load_releases_async(runtime: Arc<Runtime>) -> Result<Vec<AppRelease>, String> {
     let result = runtime.spawn(async move {
-        let mut loader = ReleaseLoader::new(
-            "https://raw.githubusercontent.com/jxnxsdev/TidaLuna-Installer/main/resources/sources.json",
-        );
+        let mut loader = ReleaseLoader::new(config::load_or_default().sources_url());
 
         match loader.load_releases().await {
             Ok(releases) => {
                 let app_releases = releases
-                    .into_iter()
+                    .iter()
                     .map(|release| AppRelease {
                         name: release.name.clone(),
                         versions: release
@@ -111,27 +119,31 @@ pub async fn load_releases_async(runtime: Arc<Runtime>) -> Result<Vec<AppRelease
                             .map(|version| AppVersionInfo {
                                 version: version.version.clone(),
                                 download: version.download.clone(),
+                                notes: version.notes.clone(),
+                                published_at: version.published_at.clone(),
+                                prerelease: version.prerelease,
                             })
                             .collect(),
                     })
-                    .collect();
+                    .collect::<Vec<_>>();
+                // Best effort: without a cache the next offline start just has nothing to show
+                let _ = cache::save_releases(&app_releases);
                 Ok(app_releases)
             }
-            Err(e) => Err(format!("Failed to load releases: {}", e)),
+            Err(e) => Err(t!("releases-load-failed", error = e)),
         }
     }).await;
 
     match result {
         Ok(inner_result) => inner_result,
-        Err(_) => Err("Failed to load releases: task cancelled".to_string()),
+        Err(_) => Err(t!("gui-releases-cancelled")),
     }
 }
 
 pub async fn load_stargazers_async(runtime: Arc<Runtime>) -> Result<Vec<Stargazer>, String> {
     let result = runtime
         .spawn(async move {
-            let client = reqwest::Client::builder()
-                .user_agent("tidaluna-installer")
+            let client = http::client_builder()
                 .timeout(std::time::Duration::from_secs(15))
                 .build()
                 .map_err(|error| format!("failed to create stargazer http client: {}", error))?;
@@ -216,11 +228,12 @@ pub async fn load_stargazers_async(runtime: Arc<Runtime>) -> Result<Vec<Stargaze
                         Err(_) => return None,
                     };
 
-                    Some(Stargazer {
+                    let stargazer = Stargazer {
                         tooltip_text: format!("{}\n{}", login, profile_url),
                         login,
                         avatar: image::Handle::from_memory(avatar_bytes.to_vec()),
-                    })
+                    };
+                    Some((stargazer, avatar_bytes.to_vec()))
                 });
             }
 
@@ -232,18 +245,28 @@ pub async fn load_stargazers_async(runtime: Arc<Runtime>) -> Result<Vec<Stargaze
                 }
             }
 
-            stargazers.sort_by(|a, b| a.login.to_lowercase().cmp(&b.login.to_lowercase()));
+            stargazers.sort_by_key(|(a, _)| a.login.to_lowercase());
+            let _ = cache::save_stargazers(&stargazers);
 
-            Ok(stargazers)
+            Ok(stargazers.into_iter().map(|(stargazer, _)| stargazer).collect())
         })
         .await;
 
     match result {
         Ok(inner_result) => inner_result,
-        Err(_) => Err("Failed to load stargazers: task cancelled".to_string()),
+        Err(_) => Err(t!("gui-stargazers-cancelled")),
     }
 }
 
+/// The release list from the last successful load, for an offline start
+pub async fn load_cached_releases_async(runtime: Arc<Runtime>) -> Option<Cached<Vec<AppRelease>>> {
+    runtime.spawn_blocking(cache::load_releases).await.ok().flatten()
+}
+
+pub async fn load_cached_stargazers_async(runtime: Arc<Runtime>) -> Option<Cached<Vec<Stargazer>>> {
+    runtime.spawn_blocking(cache::load_stargazers).await.ok().flatten()
+}
+
 pub async fn check_installation_async(runtime: Arc<Runtime>) -> bool {
     let result = runtime.spawn(async move {
         is_luna_installed().await.unwrap_or(false)
@@ -264,201 +287,371 @@ pub async fn detect_tidal_paths_async(runtime: Arc<Runtime>) -> Result<Vec<Strin
     match result {
         Ok(Ok(paths)) => Ok(paths),
         Ok(Err(err)) => Err(err.to_string()),
-        Err(_) => Err("Failed to detect TIDAL paths: task cancelled".to_string()),
+        Err(_) => Err(t!("gui-detect-cancelled")),
     }
 }
 
-pub async fn install_async(
-    releases: Vec<AppRelease>,
-    channel: String,
-    version: String,
-    selected_path: String,
-    custom_path: String,
-    reinstall_mode: bool,
-    runtime: Arc<Runtime>,
-) -> Result<InstallExecutionResult, String> {
-    let result = runtime.spawn(async move {
-        let selected_release = releases
-            .iter()
-            .find(|r| r.name == channel)
-            .ok_or_else(|| format!("Release channel '{}' not found", channel))?;
-
-        let selected_version = selected_release
-            .versions
-            .iter()
-            .find(|v| v.version == version)
-            .ok_or_else(|| format!("Version '{}' not found in channel '{}'", version, channel))?;
-
-        let final_path = if !custom_path.trim().is_empty() {
-            normalize_tidal_resources_path(PathBuf::from(custom_path))
-        } else if !selected_path.trim().is_empty() {
-            normalize_tidal_resources_path(PathBuf::from(selected_path))
-        } else {
-            return Err("No TIDAL path selected".to_string());
-        };
-
-        let mut manager = InstallManager::new();
-        let collected_logs = Arc::new(Mutex::new(Vec::<InstallExecutionLog>::new()));
-        let install_success = Arc::new(Mutex::new(true));
-
-        manager.add_step(Box::new(KillTidalStep));
-        if reinstall_mode {
-            manager.add_step(Box::new(ReinstallCleanupStep {
-                overwrite_path: Some(final_path.clone()),
-            }));
-        }
-        manager.add_step(Box::new(SetupStep {
-            overwrite_path: Some(final_path.clone()),
-        }));
-        manager.add_step(Box::new(DownloadLunaStep {
-            download_url: selected_version.download.clone(),
-        }));
-        manager.add_step(Box::new(ExtractLunaStep));
-        manager.add_step(Box::new(CopyAsarInstallStep {
-            overwrite_path: Some(final_path.clone()),
-        }));
-        manager.add_step(Box::new(InsertLunaStep {
-            overwrite_path: Some(final_path.clone()),
-        }));
-        manager.add_step(Box::new(SignTidalStep));
-        manager.add_step(Box::new(LaunchTidalStep {
-            overwrite_path: Some(final_path.clone()),
-            suppress_console_window: true,
-        }));
+/// Reads and checks a dropped archive without blocking the window
+pub async fn inspect_archive_async(runtime: Arc<Runtime>, path: PathBuf) -> Result<ArchiveSummary, String> {
+    let result = runtime
+        .spawn(async move {
+            let bytes = tokio::fs::read(&path)
+                .await
+                .map_err(|err| t!("local-archive-read-failed", error = err))?;
+            summarize_archive(&bytes)
+        })
+        .await;
 
-        let logs_for_sub = Arc::clone(&collected_logs);
-        let logs_for_step = Arc::clone(&collected_logs);
-        let logs_for_start = Arc::clone(&collected_logs);
-        let success_for_end = Arc::clone(&install_success);
-
-        manager
-            .run(
-                |sublog| {
-                    if let Ok(mut logs) = logs_for_sub.lock() {
-                        logs.push(InstallExecutionLog {
-                            message: sublog,
-                            is_substep: true,
-                        });
-                    }
-                },
-                |steplog| {
-                    if let Ok(mut logs) = logs_for_step.lock() {
-                        logs.push(InstallExecutionLog {
-                            message: steplog,
-                            is_substep: false,
-                        });
-                    }
-                },
-                |step_name| {
-                    if let Ok(mut logs) = logs_for_start.lock() {
-                        logs.push(InstallExecutionLog {
-                            message: format!("=== {} ===", step_name),
-                            is_substep: false,
-                        });
-                    }
-                },
-                |success| {
-                    if !success {
-                        if let Ok(mut flag) = success_for_end.lock() {
-                            *flag = false;
-                        }
+    match result {
+        Ok(inner_result) => inner_result,
+        Err(_) => Err(t!("gui-archive-cancelled")),
+    }
+}
+
+/// Collects what uninstalling `paths` would remove and restore, for the preview
+pub async fn preview_uninstall_async(runtime: Arc<Runtime>, paths: Vec<String>) -> Result<UninstallPreview, String> {
+    let result = runtime
+        .spawn_blocking(move || {
+            let targets = resolve_target_paths(paths.clone())?
+                .into_iter()
+                .map(|path| {
+                    let (app_files, app_size) = dir_stats(&path.join("app"));
+                    let original_asar = path.join("original.asar");
+                    UninstallTarget {
+                        app_files,
+                        app_size,
+                        luna_version: read_receipt(&path).map(|receipt| receipt.version),
+                        has_original_asar: original_asar.is_file(),
+                        original_version: asar::package_version(&original_asar),
+                        previous_version: previous_install(&path).map(|previous| previous.version_label()),
+                        path,
                     }
-                },
-            )
-            .await;
+                })
+                .collect();
+
+            Ok(UninstallPreview {
+                paths,
+                targets,
+                tidal_running: is_tidal_running(),
+                user_data: luna_user_data_paths(),
+                choices: UninstallChoices::default(),
+            })
+        })
+        .await;
 
-        let logs = collected_logs.lock().map(|logs| logs.clone()).unwrap_or_default();
-        let success = install_success.lock().map(|flag| *flag).unwrap_or(false);
+    match result {
+        Ok(inner_result) => inner_result,
+        Err(_) => Err(t!("gui-uninstall-preview-failed")),
+    }
+}
 
-        Ok(InstallExecutionResult { logs, success })
-    }).await;
+/// Collects the diagnostic report and writes it as a zip to `path`
+pub async fn export_diagnostics_async(runtime: Arc<Runtime>, path: PathBuf) -> Result<PathBuf, String> {
+    let result = runtime
+        .spawn(async move {
+            let files = diagnostics::collect().await;
+            diagnostics::write_bundle(&path, &files)?;
+            Ok(path)
+        })
+        .await;
 
     match result {
         Ok(inner_result) => inner_result,
-        Err(_) => Err("Installation task cancelled".to_string()),
+        Err(_) => Err(t!("diagnose-write-failed", error = t!("gui-task-stopped"))),
     }
 }
 
-pub async fn uninstall_async(
-    selected_path: String,
-    custom_path: String,
-    runtime: Arc<Runtime>,
-) -> Result<InstallExecutionResult, String> {
-    let result = runtime.spawn(async move {
-        let final_path = if !custom_path.trim().is_empty() {
-            normalize_tidal_resources_path(PathBuf::from(custom_path))
-        } else if !selected_path.trim().is_empty() {
-            normalize_tidal_resources_path(PathBuf::from(selected_path))
-        } else {
-            return Err("No TIDAL path selected".to_string());
-        };
-
-        let mut manager = InstallManager::new();
-        let collected_logs = Arc::new(Mutex::new(Vec::<InstallExecutionLog>::new()));
-        let uninstall_success = Arc::new(Mutex::new(true));
-
-        manager.add_step(Box::new(KillTidalStep));
-        manager.add_step(Box::new(CopyAsarUninstallStep {
-            overwrite_path: Some(final_path.clone()),
-        }));
-        manager.add_step(Box::new(UninstallStep {
-            overwrite_path: Some(final_path.clone()),
-        }));
-        manager.add_step(Box::new(SignTidalStep));
-        manager.add_step(Box::new(LaunchTidalStep {
-            overwrite_path: Some(final_path.clone()),
-            suppress_console_window: true,
-        }));
-
-        let logs_for_sub = Arc::clone(&collected_logs);
-        let logs_for_step = Arc::clone(&collected_logs);
-        let logs_for_start = Arc::clone(&collected_logs);
-        let success_for_end = Arc::clone(&uninstall_success);
-
-        manager
-            .run(
-                |sublog| {
-                    if let Ok(mut logs) = logs_for_sub.lock() {
-                        logs.push(InstallExecutionLog {
-                            message: sublog,
-                            is_substep: true,
-                        });
-                    }
-                },
-                |steplog| {
-                    if let Ok(mut logs) = logs_for_step.lock() {
-                        logs.push(InstallExecutionLog {
-                            message: steplog,
-                            is_substep: false,
-                        });
-                    }
-                },
-                |step_name| {
-                    if let Ok(mut logs) = logs_for_start.lock() {
-                        logs.push(InstallExecutionLog {
-                            message: format!("=== {} ===", step_name),
-                            is_substep: false,
-                        });
-                    }
-                },
-                |success| {
-                    if !success {
-                        if let Ok(mut flag) = success_for_end.lock() {
-                            *flag = false;
-                        }
+pub async fn load_installations_async(runtime: Arc<Runtime>) -> Vec<InstallationCard> {
+    let result = runtime
+        .spawn(async move {
+            let paths = find_tidal_directories().await.unwrap_or_default();
+            paths
+                .into_iter()
+                .map(|path| {
+                    let luna_installed = path.join("app").is_dir();
+                    InstallationCard {
+                        flavor: detect_tidal_flavor(&path),
+                        tidal_version: asar::tidal_version(&path),
+                        luna_installed,
+                        needs_repair: luna_installed && has_tidal_app_asar(&path),
+                        receipt: read_receipt(&path),
+                        has_backup: path.join("original.asar").is_file(),
+                        previous_version: previous_install(&path).map(|previous| previous.version_label()),
+                        path: path.to_string_lossy().to_string(),
                     }
-                },
-            )
-            .await;
+                })
+                .collect()
+        })
+        .await;
 
-        let logs = collected_logs.lock().map(|logs| logs.clone()).unwrap_or_default();
-        let success = uninstall_success.lock().map(|flag| *flag).unwrap_or(false);
+    result.unwrap_or_default()
+}
 
-        Ok(InstallExecutionResult { logs, success })
-    }).await;
+fn execution_logs(event: InstallEvent) -> Vec<RunUpdate> {
+    let log = |message: String, is_substep: bool| RunUpdate::Log(InstallExecutionLog { message, is_substep });
+
+    match event {
+        InstallEvent::StepStarted(step) => vec![
+            RunUpdate::StepStarted {
+                index: step.index,
+                total: step.total,
+                name: step.name.clone(),
+            },
+            log(format!("=== {} ===", step.name), false),
+            log(t!("cli-step-starting", name = step.name), false),
+        ],
+        InstallEvent::SubLog { step, message } => vec![log(format!("[{}] {}", step.name, message), true)],
+        InstallEvent::StepFinished { step, success, message } => vec![log(
+            if success {
+                t!("cli-step-finished", name = step.name, message = message)
+            } else {
+                t!("gui-step-failed", name = step.name, message = message)
+            },
+            false,
+        )],
+    }
+}
 
-    match result {
-        Ok(inner_result) => inner_result,
-        Err(_) => Err("Uninstallation task cancelled".to_string()),
+fn resolve_target_paths(paths: Vec<String>) -> Result<Vec<PathBuf>, String> {
+    let paths: Vec<PathBuf> = paths
+        .into_iter()
+        .filter(|path| !path.trim().is_empty())
+        .map(|path| normalize_tidal_resources_path(PathBuf::from(path)))
+        .collect();
+
+    if paths.is_empty() {
+        return Err(t!("gui-no-path-selected"));
     }
+
+    Ok(paths)
+}
+
+fn send_log(tx: &RunSender, message: String, is_substep: bool) {
+    let _ = tx.send(RunUpdate::Log(InstallExecutionLog { message, is_substep }));
+}
+
+async fn run_pipeline(
+    manager: &InstallManager,
+    start: usize,
+    cancel: &CancelFlag,
+    tx: &RunSender,
+    recorder: &RunRecorder,
+) -> RunOutcome {
+    let outcome = manager
+        .run_from(start, Some(cancel), |event| {
+            recorder.event(&event);
+            for update in execution_logs(event) {
+                let _ = tx.send(update);
+            }
+        })
+        .await;
+    recorder.finish(&outcome);
+    outcome
+}
+
+/// Runs every job from its step. With several jobs TIDAL is stopped once up front
+/// (their pipelines do not), every job is attempted, and a per-target summary is logged.
+async fn run_jobs(
+    jobs: Vec<ResumePoint>,
+    stop_tidal_first: bool,
+    cancel: &CancelFlag,
+    tx: &RunSender,
+) -> InstallExecutionResult {
+    let recorder = RunRecorder::new("gui");
+    if stop_tidal_first {
+        run_pipeline(&kill_pipeline(), 0, cancel, tx, &recorder).await;
+    }
+
+    let total = jobs.len();
+    let mut outcomes = Vec::new();
+    let mut resume = Vec::new();
+    for (index, job) in jobs.into_iter().enumerate() {
+        if total > 1 {
+            let _ = tx.send(RunUpdate::TargetStarted { index, total });
+            send_log(tx, format!("##### {} #####", job.path.to_string_lossy()), false);
+        }
+        let release = job.release.as_ref().map(|(channel, version)| (channel.as_str(), version.as_str()));
+        recorder.start(job.action, &job.path, release);
+        let outcome = run_pipeline(&job.manager, job.step, cancel, tx, &recorder).await;
+        if let Some(failed) = &outcome.failed_step {
+            resume.push(ResumePoint {
+                step: failed.step.index,
+                step_name: failed.step.name.clone(),
+                ..job.clone()
+            });
+        }
+        outcomes.push(TargetOutcome { path: job.path, outcome });
+    }
+
+    if total > 1 {
+        send_log(tx, t!("cli-summary"), false);
+        for target in &outcomes {
+            let message = match &target.outcome.failed_step {
+                None => t!("gui-summary-ok", path = target.path.to_string_lossy()),
+                Some(failed) => t!(
+                    "gui-summary-failed",
+                    path = target.path.to_string_lossy(),
+                    step = failed.step.name,
+                    message = failed.message
+                ),
+            };
+            send_log(tx, message, true);
+        }
+    }
+
+    InstallExecutionResult {
+        success: resume.is_empty(),
+        cancelled: outcomes.iter().any(|target| target.outcome.cancelled),
+        resume,
+    }
+}
+
+/// Runs `build(path, kill_tidal)` for every target, see `run_jobs`
+async fn run_targets(
+    paths: Vec<PathBuf>,
+    action: &'static str,
+    release: Option<(String, String)>,
+    cancel: &CancelFlag,
+    tx: &RunSender,
+    build: impl Fn(&Path, bool) -> InstallManager,
+) -> InstallExecutionResult {
+    let single = paths.len() == 1;
+    let jobs = paths
+        .into_iter()
+        .map(|path| ResumePoint {
+            manager: Arc::new(build(&path, single)),
+            path,
+            action,
+            release: release.clone(),
+            step: 0,
+            step_name: String::new(),
+        })
+        .collect();
+
+    run_jobs(jobs, !single, cancel, tx).await
+}
+
+/// Starts `job` on the runtime and returns its updates; the last one is always `Finished`
+fn spawn_run<F>(runtime: &Runtime, job: impl FnOnce(RunSender) -> F) -> RunEvents
+where
+    F: Future<Output = Result<InstallExecutionResult, String>> + Send + 'static,
+{
+    let (tx, rx) = mpsc::unbounded_channel();
+    let task = runtime.spawn(job(tx.clone()));
+
+    runtime.spawn(async move {
+        let result = task
+            .await
+            .unwrap_or_else(|_| Err(t!("gui-task-stopped")));
+        let _ = tx.send(RunUpdate::Finished(result));
+    });
+
+    Arc::new(tokio::sync::Mutex::new(rx))
+}
+
+/// Forwards the updates of the run `id` to the app until its channel closes
+pub fn run_subscription(id: u64, events: RunEvents) -> Subscription<Message> {
+    let updates = stream::unfold(events, move |events| async move {
+        let update = events.lock().await.recv().await?;
+        Some((Message::RunUpdate(id, update), events))
+    });
+
+    iced::subscription::run_with_id(id, updates)
+}
+
+/// Runs the unfinished pipelines of an earlier run again from the steps they stopped at.
+/// TIDAL is stopped first because a failed verification may have left it running.
+pub fn spawn_retry(resume: Vec<ResumePoint>, cancel: CancelFlag, runtime: &Runtime) -> RunEvents {
+    spawn_run(runtime, |tx| async move { Ok(run_jobs(resume, true, &cancel, &tx).await) })
+}
+
+pub fn spawn_install(request: InstallRequest, cancel: CancelFlag, runtime: &Runtime) -> RunEvents {
+    spawn_run(runtime, |tx| async move {
+        let InstallRequest {
+            channel,
+            version,
+            download_url,
+            paths,
+            reinstall_mode,
+            repair,
+            verify,
+            verify_timeout,
+            rollback_on_failure,
+            launch_tidal,
+            cache_dir,
+        } = request;
+
+        let paths = resolve_target_paths(paths)?;
+
+        let progress_tx = tx.clone();
+        let download_progress = DownloadProgress(Arc::new(move |received, total| {
+            let _ = progress_tx.send(RunUpdate::Download { received, total });
+        }));
+
+        let action = if repair { "repair" } else { "install" };
+        let release = Some((channel.clone(), version.clone()));
+        Ok(run_targets(paths, action, release, &cancel, &tx, |path, kill_tidal| {
+            install_pipeline(&InstallOptions {
+                path: path.to_path_buf(),
+                channel: channel.clone(),
+                version: version.clone(),
+                download_url: download_url.clone(),
+                reinstall: !repair && (reinstall_mode || path.join("app").exists()),
+                refresh_asar_backup: repair,
+                suppress_console_window: true,
+                verify: verify.then_some(VerifyOptions {
+                    timeout_secs: verify_timeout,
+                    rollback_on_failure,
+                }),
+                launch_tidal,
+                kill_tidal,
+                cache_dir: cache_dir.clone(),
+                download_progress: Some(download_progress.clone()),
+                cancel: Some(cancel.clone()),
+            })
+        })
+        .await)
+    })
+}
+
+pub fn spawn_uninstall(
+    paths: Vec<String>,
+    choices: UninstallChoices,
+    launch_tidal: bool,
+    cancel: CancelFlag,
+    runtime: &Runtime,
+) -> RunEvents {
+    spawn_run(runtime, |tx| async move {
+        let paths = resolve_target_paths(paths)?;
+
+        Ok(run_targets(paths, "uninstall", None, &cancel, &tx, |path, kill_tidal| {
+            uninstall_pipeline(&TargetOptions {
+                path: path.to_path_buf(),
+                suppress_console_window: true,
+                launch_tidal,
+                kill_tidal,
+                keep_backup: choices.keep_backup,
+                wipe_user_data: choices.wipe_user_data,
+            })
+        })
+        .await)
+    })
+}
+
+pub fn spawn_rollback(paths: Vec<String>, launch_tidal: bool, cancel: CancelFlag, runtime: &Runtime) -> RunEvents {
+    spawn_run(runtime, |tx| async move {
+        let paths = resolve_target_paths(paths)?;
+
+        Ok(run_targets(paths, "rollback", None, &cancel, &tx, |path, kill_tidal| {
+            rollback_pipeline(&TargetOptions {
+                path: path.to_path_buf(),
+                suppress_console_window: true,
+                launch_tidal,
+                kill_tidal,
+                keep_backup: false,
+                wipe_user_data: false,
+            })
+        })
+        .await)
+    })
 }
@@ -0,0 +1,243 @@
+use iced::widget::container;
+use iced::{Background, Border, Color, Shadow, Theme, Vector};
+use std::fmt;
+
+use crate::t;
+use crate::utils::config::ThemePreference;
+
+use super::models::LogLevel;
+
+/// The look the windows are drawn with, after `ThemePreference::System` is resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeVariant {
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeVariant {
+    /// Asks the OS for `System`; desktops that do not say keep the dark look
+    pub fn resolve(preference: ThemePreference) -> ThemeVariant {
+        match preference {
+            ThemePreference::System => match dark_light::detect() {
+                dark_light::Mode::Light => ThemeVariant::Light,
+                dark_light::Mode::Dark | dark_light::Mode::Default => ThemeVariant::Dark,
+            },
+            ThemePreference::Dark => ThemeVariant::Dark,
+            ThemePreference::Light => ThemeVariant::Light,
+            ThemePreference::HighContrast => ThemeVariant::HighContrast,
+        }
+    }
+
+    /// iced's theme for the built-in widgets: inputs, pick lists and button backgrounds
+    pub fn iced_theme(self) -> Theme {
+        match self {
+            ThemeVariant::Dark => Theme::Dark,
+            ThemeVariant::Light => Theme::Light,
+            ThemeVariant::HighContrast => Theme::custom(
+                "High contrast".to_string(),
+                iced::theme::Palette {
+                    background: Color::BLACK,
+                    text: Color::WHITE,
+                    primary: Color::from_rgb(0.45, 0.85, 1.0),
+                    success: Color::from_rgb(0.4, 1.0, 0.4),
+                    danger: Color::from_rgb(1.0, 0.45, 0.45),
+                },
+            ),
+        }
+    }
+
+    pub fn palette(self) -> Palette {
+        match self {
+            ThemeVariant::Dark => Palette::DARK,
+            ThemeVariant::Light => Palette::LIGHT,
+            ThemeVariant::HighContrast => Palette::HIGH_CONTRAST,
+        }
+    }
+}
+
+/// Colors of everything the views style themselves
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    pub background: Color,
+    pub card: Color,
+    /// Cards inside cards, like one installation on the dashboard
+    pub inset: Color,
+    pub border: Color,
+    pub border_width: f32,
+    pub shadow: Color,
+    pub warning_background: Color,
+    pub warning_border: Color,
+    /// The window title
+    pub accent: Color,
+    pub heading: Color,
+    pub text: Color,
+    pub secondary: Color,
+    pub muted: Color,
+    /// Labels of buttons that cannot be pressed
+    pub disabled: Color,
+    /// Labels on primary and destructive buttons
+    pub on_primary: Color,
+    pub attention: Color,
+    pub success: Color,
+    pub error: Color,
+}
+
+impl Palette {
+    pub const DARK: Palette = Palette {
+        background: Color::from_rgb(0.06, 0.07, 0.10),
+        card: Color::from_rgba(0.11, 0.12, 0.16, 0.94),
+        inset: Color::from_rgba(0.16, 0.17, 0.22, 0.94),
+        border: Color::from_rgba(0.45, 0.55, 0.9, 0.18),
+        border_width: 1.0,
+        shadow: Color::from_rgba(0.0, 0.0, 0.0, 0.45),
+        warning_background: Color::from_rgba(0.26, 0.19, 0.08, 0.95),
+        warning_border: Color::from_rgba(0.95, 0.75, 0.25, 0.28),
+        accent: Color::from_rgb(0.55, 0.76, 0.96),
+        heading: Color::from_rgb(0.80, 0.83, 0.90),
+        text: Color::from_rgb(0.9, 0.9, 0.9),
+        secondary: Color::from_rgb(0.74, 0.76, 0.84),
+        muted: Color::from_rgb(0.65, 0.68, 0.75),
+        disabled: Color::from_rgb(0.5, 0.5, 0.5),
+        on_primary: Color::WHITE,
+        attention: Color::from_rgb(0.98, 0.80, 0.35),
+        success: Color::from_rgb(0.64, 0.95, 0.68),
+        error: Color::from_rgb(0.92, 0.48, 0.48),
+    };
+
+    pub const LIGHT: Palette = Palette {
+        background: Color::from_rgb(0.94, 0.95, 0.97),
+        card: Color::WHITE,
+        inset: Color::from_rgb(0.93, 0.94, 0.97),
+        border: Color::from_rgba(0.25, 0.35, 0.7, 0.22),
+        border_width: 1.0,
+        shadow: Color::from_rgba(0.0, 0.0, 0.0, 0.12),
+        warning_background: Color::from_rgb(1.0, 0.95, 0.82),
+        warning_border: Color::from_rgba(0.8, 0.55, 0.05, 0.45),
+        accent: Color::from_rgb(0.10, 0.38, 0.70),
+        heading: Color::from_rgb(0.15, 0.17, 0.22),
+        text: Color::from_rgb(0.10, 0.11, 0.14),
+        secondary: Color::from_rgb(0.30, 0.33, 0.40),
+        muted: Color::from_rgb(0.40, 0.43, 0.50),
+        disabled: Color::from_rgb(0.62, 0.64, 0.68),
+        on_primary: Color::WHITE,
+        attention: Color::from_rgb(0.60, 0.36, 0.0),
+        success: Color::from_rgb(0.10, 0.50, 0.18),
+        error: Color::from_rgb(0.75, 0.15, 0.15),
+    };
+
+    /// White on black with thick borders and no translucency, for low-vision users
+    pub const HIGH_CONTRAST: Palette = Palette {
+        background: Color::BLACK,
+        card: Color::BLACK,
+        inset: Color::from_rgb(0.08, 0.08, 0.08),
+        border: Color::WHITE,
+        border_width: 2.0,
+        shadow: Color::TRANSPARENT,
+        warning_background: Color::BLACK,
+        warning_border: Color::from_rgb(1.0, 0.88, 0.0),
+        accent: Color::from_rgb(0.45, 0.85, 1.0),
+        heading: Color::WHITE,
+        text: Color::WHITE,
+        secondary: Color::WHITE,
+        muted: Color::from_rgb(0.85, 0.85, 0.85),
+        disabled: Color::from_rgb(0.6, 0.6, 0.6),
+        on_primary: Color::BLACK,
+        attention: Color::from_rgb(1.0, 0.88, 0.0),
+        success: Color::from_rgb(0.4, 1.0, 0.4),
+        error: Color::from_rgb(1.0, 0.45, 0.45),
+    };
+
+    pub fn log_color(&self, level: LogLevel) -> Color {
+        match level {
+            LogLevel::Info => self.secondary,
+            LogLevel::Success => self.success,
+            LogLevel::Error => self.error,
+            LogLevel::Step => self.accent,
+            LogLevel::SubStep => self.muted,
+        }
+    }
+
+    /// Raised card with a drop shadow
+    pub fn card(self) -> impl Fn(&Theme) -> container::Appearance {
+        move |_: &Theme| container::Appearance {
+            text_color: None,
+            background: Some(Background::Color(self.card)),
+            border: Border {
+                radius: 8.0.into(),
+                width: self.border_width,
+                color: self.border,
+            },
+            shadow: Shadow {
+                color: self.shadow,
+                offset: Vector::new(0.0, 8.0),
+                blur_radius: 18.0,
+            },
+        }
+    }
+
+    /// Card without a shadow
+    pub fn section(self) -> impl Fn(&Theme) -> container::Appearance {
+        move |_: &Theme| container::Appearance {
+            text_color: None,
+            background: Some(Background::Color(self.card)),
+            border: Border {
+                radius: 8.0.into(),
+                width: self.border_width,
+                color: self.border,
+            },
+            shadow: Shadow::default(),
+        }
+    }
+
+    pub fn inset(self) -> impl Fn(&Theme) -> container::Appearance {
+        move |_: &Theme| container::Appearance {
+            text_color: None,
+            background: Some(Background::Color(self.inset)),
+            border: Border {
+                radius: 6.0.into(),
+                width: self.border_width,
+                color: self.border,
+            },
+            shadow: Shadow::default(),
+        }
+    }
+
+    pub fn warning(self) -> impl Fn(&Theme) -> container::Appearance {
+        move |_: &Theme| container::Appearance {
+            text_color: None,
+            background: Some(Background::Color(self.warning_background)),
+            border: Border {
+                radius: 8.0.into(),
+                width: self.border_width,
+                color: self.warning_border,
+            },
+            shadow: Shadow {
+                color: self.shadow,
+                offset: Vector::new(0.0, 8.0),
+                blur_radius: 16.0,
+            },
+        }
+    }
+
+    /// The window background; `text_color` covers text without a style of its own
+    pub fn window(self) -> impl Fn(&Theme) -> container::Appearance {
+        move |_: &Theme| container::Appearance {
+            text_color: Some(self.text),
+            background: Some(Background::Color(self.background)),
+            border: Border::default(),
+            shadow: Shadow::default(),
+        }
+    }
+}
+
+impl fmt::Display for ThemePreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            ThemePreference::System => t!("gui-theme-system"),
+            ThemePreference::Dark => t!("gui-theme-dark"),
+            ThemePreference::Light => t!("gui-theme-light"),
+            ThemePreference::HighContrast => t!("gui-theme-high-contrast"),
+        })
+    }
+}
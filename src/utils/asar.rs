@@ -0,0 +1,79 @@
+use serde_json::Value;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Headers of real app archives are a few hundred KB; anything far larger is not an asar
+const MAX_HEADER_SIZE: u32 = 64 * 1024 * 1024;
+
+fn read_u32(file: &mut File) -> Option<u32> {
+    let mut buf = [0u8; 4];
+    file.read_exact(&mut buf).ok()?;
+    Some(u32::from_le_bytes(buf))
+}
+
+/// Reads a file stored at the top level of an asar archive
+///
+/// Layout: a pickle with the header size, then a pickle holding the JSON file index.
+/// File offsets in the index are relative to the end of the header.
+pub fn read_top_level_file(asar: &Path, name: &str) -> Option<Vec<u8>> {
+    let mut file = File::open(asar).ok()?;
+
+    // Size pickle: payload length (always 4), then the header pickle size
+    read_u32(&mut file)?;
+    let header_size = read_u32(&mut file)?;
+    if header_size > MAX_HEADER_SIZE {
+        return None;
+    }
+    // Header pickle: payload length, then the string length
+    read_u32(&mut file)?;
+    let json_len = read_u32(&mut file)?;
+    if json_len > header_size {
+        return None;
+    }
+
+    let mut json = vec![0u8; json_len as usize];
+    file.read_exact(&mut json).ok()?;
+    let index: Value = serde_json::from_slice(&json).ok()?;
+
+    let entry = index.get("files")?.get(name)?;
+    // Unpacked files live next to the archive instead of inside it
+    if entry.get("unpacked").and_then(Value::as_bool) == Some(true) {
+        return None;
+    }
+    let size = entry.get("size")?.as_u64()?;
+    let offset: u64 = entry.get("offset")?.as_str()?.parse().ok()?;
+
+    file.seek(SeekFrom::Start(8 + header_size as u64 + offset)).ok()?;
+    let mut contents = Vec::new();
+    file.take(size).read_to_end(&mut contents).ok()?;
+    (contents.len() as u64 == size).then_some(contents)
+}
+
+/// The `version` from the archive's package.json
+pub fn package_version(asar: &Path) -> Option<String> {
+    let contents = read_top_level_file(asar, "package.json")?;
+    let package: Value = serde_json::from_slice(&contents).ok()?;
+    package.get("version")?.as_str().map(str::to_string)
+}
+
+/// Version of the TIDAL client a resources directory belongs to. Clients that install
+/// side by side carry it in the `app-x.y.z` directory name; otherwise it is read from
+/// TIDAL's own archive, which is `original.asar` while Luna is installed.
+pub fn tidal_version(resources: &Path) -> Option<String> {
+    let from_dir = resources
+        .parent()
+        .and_then(|dir| dir.file_name())
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_prefix("app-"))
+        .filter(|version| semver::Version::parse(version).is_ok());
+    if let Some(version) = from_dir {
+        return Some(version.to_string());
+    }
+
+    ["original.asar", "app.asar"]
+        .iter()
+        .map(|name| resources.join(name))
+        .find(|asar| asar.is_file())
+        .and_then(|asar| package_version(&asar))
+}
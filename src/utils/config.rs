@@ -0,0 +1,319 @@
+use crate::t;
+use crate::utils::{i18n, invoking_user::invoking_user};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub const DEFAULT_SOURCES_URL: &str =
+    "https://raw.githubusercontent.com/jxnxsdev/TidaLuna-Installer/main/resources/sources.json";
+
+/// Keys accepted by `config get` / `config set`, in `config list` order
+pub const CONFIG_KEYS: &[&str] = &[
+    "channel",
+    "version",
+    "target_paths",
+    "gui_path",
+    "launch",
+    "verify_timeout",
+    "rollback_on_verify_failure",
+    "sources_url",
+    "proxy",
+    "cache.enabled",
+    "cache.dir",
+    "locale",
+    "theme",
+];
+
+/// What to do with TIDAL once Luna is installed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LaunchPolicy {
+    #[default]
+    Always,
+    Never,
+    /// Launch and check that Luna loaded, like `install --verify`
+    Verify,
+}
+
+impl LaunchPolicy {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LaunchPolicy::Always => "always",
+            LaunchPolicy::Never => "never",
+            LaunchPolicy::Verify => "verify",
+        }
+    }
+}
+
+/// Look of the GUI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemePreference {
+    /// Dark or light, whichever the OS is set to
+    #[default]
+    System,
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemePreference {
+    pub const ALL: [ThemePreference; 4] = [
+        ThemePreference::System,
+        ThemePreference::Dark,
+        ThemePreference::Light,
+        ThemePreference::HighContrast,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            ThemePreference::System => "system",
+            ThemePreference::Dark => "dark",
+            ThemePreference::Light => "light",
+            ThemePreference::HighContrast => "high-contrast",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Keep downloaded Luna archives so reinstalls of the same version skip the download
+    pub enabled: bool,
+    /// Overrides the platform cache directory
+    pub dir: Option<PathBuf>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            dir: None,
+        }
+    }
+}
+
+/// User preferences shared by the CLI and the GUI
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub channel: Option<String>,
+    /// Pinned version; empty means the newest in `channel`
+    pub version: Option<String>,
+    /// Installations used when no path or target is given
+    pub target_paths: Vec<String>,
+    /// Custom path last used by the GUI; the CLI never reads it
+    pub gui_path: Option<String>,
+    pub launch: LaunchPolicy,
+    pub verify_timeout: u64,
+    pub rollback_on_verify_failure: bool,
+    pub sources_url: Option<String>,
+    /// Proxy URL for every request, e.g. http://proxy:3128 or socks5://127.0.0.1:1080
+    pub proxy: Option<String>,
+    pub cache: CacheConfig,
+    /// Language of the GUI and CLI, e.g. `de`; empty follows the environment
+    pub locale: Option<String>,
+    pub theme: ThemePreference,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            channel: None,
+            version: None,
+            target_paths: Vec::new(),
+            gui_path: None,
+            launch: LaunchPolicy::default(),
+            verify_timeout: 20,
+            rollback_on_verify_failure: true,
+            sources_url: None,
+            proxy: None,
+            cache: CacheConfig::default(),
+            locale: None,
+            theme: ThemePreference::default(),
+        }
+    }
+}
+
+fn optional(value: &Option<String>) -> String {
+    value.clone().unwrap_or_default()
+}
+
+fn parse_optional(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Ok(true),
+        "false" | "no" | "off" | "0" => Ok(false),
+        _ => Err(t!("config-not-bool", key = key, value = value)),
+    }
+}
+
+impl Config {
+    pub fn sources_url(&self) -> &str {
+        self.sources_url.as_deref().unwrap_or(DEFAULT_SOURCES_URL)
+    }
+
+    /// Directory for cached Luna archives, or `None` when caching is off
+    pub fn archive_cache_dir(&self) -> Option<PathBuf> {
+        if !self.cache.enabled {
+            return None;
+        }
+
+        self.cache
+            .dir
+            .clone()
+            .or_else(|| user_dir(dirs::cache_dir(), ".cache"))
+            .map(|dir| dir.join("tidaluna-installer").join("archives"))
+    }
+
+    pub fn get(&self, key: &str) -> Result<String, String> {
+        Ok(match key {
+            "channel" => optional(&self.channel),
+            "version" => optional(&self.version),
+            "target_paths" => self.target_paths.join(","),
+            "gui_path" => optional(&self.gui_path),
+            "launch" => self.launch.as_str().to_string(),
+            "verify_timeout" => self.verify_timeout.to_string(),
+            "rollback_on_verify_failure" => self.rollback_on_verify_failure.to_string(),
+            "sources_url" => optional(&self.sources_url),
+            "proxy" => optional(&self.proxy),
+            "cache.enabled" => self.cache.enabled.to_string(),
+            "cache.dir" => self
+                .cache
+                .dir
+                .as_ref()
+                .map(|dir| dir.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            "locale" => optional(&self.locale),
+            "theme" => self.theme.as_str().to_string(),
+            _ => return Err(unknown_key(key)),
+        })
+    }
+
+    /// Sets `key` from its string form; an empty value clears optional keys
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "channel" => self.channel = parse_optional(value),
+            "version" => self.version = parse_optional(value),
+            "target_paths" => {
+                self.target_paths = value
+                    .split(',')
+                    .filter_map(parse_optional)
+                    .collect()
+            }
+            "gui_path" => self.gui_path = parse_optional(value),
+            "launch" => {
+                self.launch = match value.trim().to_ascii_lowercase().as_str() {
+                    "always" => LaunchPolicy::Always,
+                    "never" => LaunchPolicy::Never,
+                    "verify" => LaunchPolicy::Verify,
+                    _ => return Err(t!("config-bad-launch", value = value)),
+                }
+            }
+            "verify_timeout" => {
+                self.verify_timeout = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| t!("config-bad-timeout", value = value))?
+            }
+            "rollback_on_verify_failure" => self.rollback_on_verify_failure = parse_bool(key, value)?,
+            "sources_url" => self.sources_url = parse_optional(value),
+            "proxy" => {
+                if let Some(proxy) = parse_optional(value) {
+                    reqwest::Proxy::all(&proxy).map_err(|err| t!("config-bad-proxy", proxy = proxy, error = err))?;
+                }
+                self.proxy = parse_optional(value)
+            }
+            "cache.enabled" => self.cache.enabled = parse_bool(key, value)?,
+            "cache.dir" => self.cache.dir = parse_optional(value).map(PathBuf::from),
+            "locale" => {
+                let locales = i18n::available_locales();
+                if let Some(locale) = parse_optional(value)
+                    && !locales.contains(&locale.as_str())
+                {
+                    return Err(t!("config-bad-locale", locales = locales.join(", "), value = locale));
+                }
+                self.locale = parse_optional(value)
+            }
+            "theme" => {
+                self.theme = ThemePreference::ALL
+                    .into_iter()
+                    .find(|theme| theme.as_str() == value.trim().to_ascii_lowercase())
+                    .ok_or_else(|| t!("config-bad-theme", value = value))?
+            }
+            _ => return Err(unknown_key(key)),
+        }
+        Ok(())
+    }
+}
+
+fn unknown_key(key: &str) -> String {
+    t!("config-unknown-key", key = key, keys = CONFIG_KEYS.join(", "))
+}
+
+/// Platform directory, except under sudo/pkexec where the invoking user's home is used
+/// so root does not end up with its own copy of the settings
+fn user_dir(platform_dir: Option<PathBuf>, home_relative: &str) -> Option<PathBuf> {
+    match invoking_user() {
+        Some(user) => Some(user.home.join(home_relative)),
+        None => platform_dir,
+    }
+}
+
+/// Where runs are recorded: the install history and the last run's log
+pub fn state_dir() -> Option<PathBuf> {
+    user_dir(dirs::data_local_dir(), ".local/share").map(|dir| dir.join("tidaluna-installer"))
+}
+
+/// Where the GUI keeps the last release list and stargazers, so it can start offline
+pub fn gui_cache_dir() -> Option<PathBuf> {
+    user_dir(dirs::cache_dir(), ".cache").map(|dir| dir.join("tidaluna-installer").join("gui"))
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    user_dir(dirs::config_dir(), ".config")
+        .map(|dir| dir.join("tidaluna-installer").join("config.toml"))
+}
+
+/// Reads the config file; a missing file yields the defaults
+pub fn load() -> Result<Config, String> {
+    let Some(path) = config_path() else {
+        return Ok(Config::default());
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => toml::from_str(&contents)
+            .map_err(|err| t!("config-invalid-file", path = format!("{:?}", path), error = err)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+        Err(err) => Err(t!("config-read-failed", path = format!("{:?}", path), error = err)),
+    }
+}
+
+/// Like `load`, but falls back to the defaults after reporting a broken file on stderr
+pub fn load_or_default() -> Config {
+    load().unwrap_or_else(|err| {
+        eprintln!("{}", t!("config-using-defaults", error = err));
+        Config::default()
+    })
+}
+
+pub fn save(config: &Config) -> Result<(), String> {
+    let path = config_path().ok_or_else(|| t!("config-no-dir"))?;
+    let contents = toml::to_string_pretty(config).map_err(|err| t!("config-serialize-failed", error = err))?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| t!("config-create-dir-failed", path = format!("{:?}", dir), error = err))?;
+    }
+    std::fs::write(&path, contents)
+        .map_err(|err| t!("config-write-failed", path = format!("{:?}", path), error = err))?;
+
+    if let (Some(user), Some(dir)) = (invoking_user(), path.parent()) {
+        let _ = user.chown_recursive(dir);
+    }
+
+    Ok(())
+}
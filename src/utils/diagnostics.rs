@@ -0,0 +1,238 @@
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::Path;
+use std::process::Command;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::t;
+use crate::utils::config::{self, CONFIG_KEYS};
+use crate::utils::fs_helpers::{
+    detect_tidal_flavor, dir_stats, find_tidal_directories, luna_user_data_paths,
+};
+use crate::utils::history::{self, format_timestamp};
+use crate::utils::receipt::{previous_install, read_receipt};
+use crate::utils::redact::redact_personal_info;
+use crate::utils::{asar, updater};
+
+/// How much of the install history goes into a report
+const HISTORY_ENTRIES: usize = 50;
+
+/// One text file of a diagnostic report
+#[derive(Debug, Clone)]
+pub struct ReportFile {
+    pub name: &'static str,
+    pub contents: String,
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn os_version() -> Option<String> {
+    match std::env::consts::OS {
+        "linux" => std::fs::read_to_string("/etc/os-release")
+            .ok()?
+            .lines()
+            .find_map(|line| line.strip_prefix("PRETTY_NAME="))
+            .map(|name| name.trim_matches('"').to_string()),
+        "macos" => command_output("sw_vers", &["-productVersion"]).map(|version| format!("macOS {}", version)),
+        "windows" => command_output("cmd", &["/C", "ver"]),
+        _ => None,
+    }
+}
+
+fn environment() -> String {
+    let mut out = format!(
+        "TidaLuna Installer v{} diagnostics, created {} UTC\n\n",
+        updater::current_installer_version(),
+        format_timestamp(history::now())
+    );
+
+    let root = if cfg!(windows) {
+        "unknown".to_string()
+    } else {
+        command_output("id", &["-u"])
+            .map(|uid| yes_no(uid == "0").to_string())
+            .unwrap_or_else(|| "unknown".to_string())
+    };
+    let elevated_by = if std::env::var_os("PKEXEC_UID").is_some() {
+        "pkexec"
+    } else if std::env::var_os("SUDO_USER").is_some() {
+        "sudo"
+    } else {
+        "none"
+    };
+
+    let _ = writeln!(out, "os: {}", std::env::consts::OS);
+    let _ = writeln!(out, "os_version: {}", os_version().unwrap_or_else(|| "unknown".to_string()));
+    let _ = writeln!(out, "arch: {}", std::env::consts::ARCH);
+    let _ = writeln!(out, "root: {}", root);
+    let _ = writeln!(out, "elevated_by: {}", elevated_by);
+
+    match config::config_path() {
+        Some(path) => {
+            let _ = writeln!(out, "config_file: {:?} (exists: {})", path, yes_no(path.is_file()));
+        }
+        None => out.push_str("config_file: none\n"),
+    }
+    match config::load() {
+        Ok(config) => {
+            out.push_str("config:\n");
+            for key in CONFIG_KEYS {
+                let value = config.get(key).unwrap_or_default();
+                // Proxy URLs may carry credentials
+                let value = if *key == "proxy" && !value.is_empty() { "(set)".to_string() } else { value };
+                let _ = writeln!(out, "  {} = {}", key, value);
+            }
+        }
+        Err(err) => {
+            let _ = writeln!(out, "config: unreadable ({})", err);
+        }
+    }
+
+    out
+}
+
+fn asar_summary(path: &Path) -> String {
+    match std::fs::read(path) {
+        Ok(bytes) => format!(
+            "{} bytes, sha256 {}, version {}",
+            bytes.len(),
+            updater::sha256_hex(&bytes),
+            asar::package_version(path).unwrap_or_else(|| "unknown".to_string())
+        ),
+        Err(_) => "missing".to_string(),
+    }
+}
+
+fn resources_listing(resources: &Path) -> String {
+    let Ok(entries) = std::fs::read_dir(resources) else {
+        return "  (not readable)\n".to_string();
+    };
+    let mut entries: Vec<_> = entries.flatten().collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let mut out = String::new();
+    for entry in entries {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Ok(metadata) = entry.path().symlink_metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            let (files, bytes) = dir_stats(&entry.path());
+            let _ = writeln!(out, "  {}/  {} files, {} bytes", name, files, bytes);
+        } else if metadata.file_type().is_symlink() {
+            let _ = writeln!(out, "  {}  symlink", name);
+        } else {
+            let _ = writeln!(out, "  {}  {} bytes", name, metadata.len());
+        }
+    }
+    out
+}
+
+async fn installations() -> String {
+    let paths = match find_tidal_directories().await {
+        Ok(paths) => paths,
+        Err(err) => return format!("No TIDAL installation detected: {}\n", err),
+    };
+
+    let mut out = String::new();
+    for path in paths {
+        let _ = writeln!(out, "## {:?}", path);
+        let _ = writeln!(out, "client: {}", detect_tidal_flavor(&path).label());
+        let _ = writeln!(
+            out,
+            "tidal_version: {}",
+            asar::tidal_version(&path).unwrap_or_else(|| "unknown".to_string())
+        );
+        let _ = writeln!(out, "luna_installed: {}", yes_no(path.join("app").is_dir()));
+        let _ = writeln!(out, "app.asar: {}", asar_summary(&path.join("app.asar")));
+        let _ = writeln!(out, "original.asar: {}", asar_summary(&path.join("original.asar")));
+
+        match read_receipt(&path).and_then(|receipt| serde_json::to_string(&receipt).ok()) {
+            Some(receipt) => {
+                let _ = writeln!(out, "receipt: {}", receipt);
+            }
+            None => out.push_str("receipt: none\n"),
+        }
+        match previous_install(&path) {
+            Some(previous) => {
+                let receipt = previous
+                    .receipt
+                    .and_then(|receipt| serde_json::to_string(&receipt).ok())
+                    .unwrap_or_else(|| "no receipt".to_string());
+                let _ = writeln!(out, "previous_slot: {}", receipt);
+            }
+            None => out.push_str("previous_slot: empty\n"),
+        }
+
+        out.push_str("resources/:\n");
+        out.push_str(&resources_listing(&path));
+        out.push('\n');
+    }
+
+    out.push_str("## Luna user data\n");
+    let user_data = luna_user_data_paths();
+    if user_data.is_empty() {
+        out.push_str("none found\n");
+    }
+    for path in user_data {
+        let _ = writeln!(out, "{:?}", path);
+    }
+
+    out
+}
+
+fn install_history() -> String {
+    history::recent(HISTORY_ENTRIES)
+        .iter()
+        .filter_map(|entry| serde_json::to_string(entry).ok())
+        .map(|line| line + "\n")
+        .collect()
+}
+
+/// Everything a bug report needs, with home paths and account names redacted
+pub async fn collect() -> Vec<ReportFile> {
+    let files = [
+        ("environment.txt", environment()),
+        ("installations.txt", installations().await),
+        ("history.jsonl", install_history()),
+        (
+            "last-run.log",
+            history::last_run_log().unwrap_or_else(|| "No install, uninstall or rollback recorded yet\n".to_string()),
+        ),
+    ];
+
+    files
+        .into_iter()
+        .map(|(name, contents)| ReportFile {
+            name,
+            contents: redact_personal_info(&contents),
+        })
+        .collect()
+}
+
+/// Writes the report files into a zip archive at `path`
+pub fn write_bundle(path: &Path, files: &[ReportFile]) -> Result<(), String> {
+    let file = std::fs::File::create(path).map_err(|err| t!("diagnose-write-failed", error = err))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for report in files {
+        zip.start_file(report.name, options)
+            .map_err(|err| t!("diagnose-write-failed", error = err))?;
+        zip.write_all(report.contents.as_bytes())
+            .map_err(|err| t!("diagnose-write-failed", error = err))?;
+    }
+    zip.finish().map_err(|err| t!("diagnose-write-failed", error = err))?;
+    Ok(())
+}
@@ -2,6 +2,7 @@ use semver::Version;
 use std::path::{Path, PathBuf};
 use tokio::io;
 use std::env;
+use crate::utils::invoking_user::invoking_user;
 
 fn os_str_eq_ignore_ascii_case(value: Option<&std::ffi::OsStr>, expected: &str) -> bool {
     value
@@ -88,12 +89,11 @@ pub fn normalize_tidal_resources_path(input: PathBuf) -> PathBuf {
         return input;
     }
 
-    if os_str_eq_ignore_ascii_case(input.file_name(), "app.asar")
-        || os_str_eq_ignore_ascii_case(input.file_name(), "original.asar")
+    if (os_str_eq_ignore_ascii_case(input.file_name(), "app.asar")
+        || os_str_eq_ignore_ascii_case(input.file_name(), "original.asar"))
+        && let Some(parent) = input.parent()
     {
-        if let Some(parent) = input.parent() {
-            return parent.to_path_buf();
-        }
+        return parent.to_path_buf();
     }
 
     if os_str_eq_ignore_ascii_case(input.file_name(), "resources") {
@@ -124,6 +124,39 @@ pub fn normalize_tidal_resources_path(input: PathBuf) -> PathBuf {
     input.join("resources")
 }
 
+/// Which TIDAL client a resources directory belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TidalFlavor {
+    /// The official TIDAL desktop app
+    Official,
+    /// tidal-hifi installed from a package or tarball
+    TidalHifi,
+    /// tidal-hifi installed through Flatpak
+    Flatpak,
+}
+
+impl TidalFlavor {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TidalFlavor::Official => "TIDAL",
+            TidalFlavor::TidalHifi => "tidal-hifi",
+            TidalFlavor::Flatpak => "tidal-hifi (Flatpak)",
+        }
+    }
+}
+
+pub fn detect_tidal_flavor(path: &Path) -> TidalFlavor {
+    let path = path.to_string_lossy().to_ascii_lowercase();
+    if path.contains("flatpak") || path.starts_with("/app/") {
+        TidalFlavor::Flatpak
+    } else if path.contains("tidal-hifi") {
+        TidalFlavor::TidalHifi
+    } else {
+        TidalFlavor::Official
+    }
+}
+
 pub fn is_tidal_resources_directory(path: &Path) -> bool {
     !path.as_os_str().is_empty()
         && path.is_dir()
@@ -216,7 +249,13 @@ pub async fn find_tidal_directories() -> io::Result<Vec<PathBuf>> {
                 PathBuf::from("/app/extra/tidal-hifi/resources"),
             ];
 
-            if let Some(home) = dirs::home_dir() {
+            // Under sudo HOME usually points at /root, so also look in the invoking user's home
+            let mut homes: Vec<PathBuf> = dirs::home_dir().into_iter().collect();
+            if let Some(user) = invoking_user() {
+                homes.push(user.home);
+            }
+
+            for home in dedup_paths(homes) {
                 paths.push(
                     home.join(".local")
                         .join("share")
@@ -295,3 +334,150 @@ pub async fn is_luna_installed() -> io::Result<bool> {
     Ok(tidal_dirs.iter().any(|path| path.join("app").exists()))
 }
 
+
+/// Number of files below `path` and their total size; symlinks are counted, not followed
+pub fn dir_stats(path: &Path) -> (usize, u64) {
+    let mut files = 0;
+    let mut bytes = 0;
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(metadata) = entry.path().symlink_metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                files += 1;
+                bytes += metadata.len();
+            }
+        }
+    }
+    (files, bytes)
+}
+
+/// TIDAL's Electron user data directories for the desktop user
+fn tidal_user_data_dirs() -> Vec<PathBuf> {
+    // Under sudo HOME usually points at /root, so also look in the invoking user's home
+    let mut homes: Vec<PathBuf> = dirs::home_dir().into_iter().collect();
+    if let Some(user) = invoking_user() {
+        homes.push(user.home);
+    }
+
+    let mut dirs = Vec::new();
+    match std::env::consts::OS {
+        "windows" => {
+            if let Some(appdata) = env::var_os("APPDATA") {
+                dirs.push(Path::new(&appdata).join("TIDAL"));
+            }
+        }
+        "macos" => {
+            for home in dedup_paths(homes) {
+                dirs.push(home.join("Library").join("Application Support").join("TIDAL"));
+            }
+        }
+        _ => {
+            for home in dedup_paths(homes) {
+                dirs.push(home.join(".config").join("tidal-hifi"));
+                dirs.push(home.join(".config").join("TIDAL"));
+                dirs.push(
+                    home.join(".var")
+                        .join("app")
+                        .join("com.mastermindzh.tidal-hifi")
+                        .join("config")
+                        .join("tidal-hifi"),
+                );
+            }
+        }
+    }
+    dirs.into_iter().filter(|dir| dir.is_dir()).collect()
+}
+
+/// Prefix of the IndexedDB databases Luna's stores and plugins open
+const LUNA_DATABASE_PREFIX: &str = "@luna/";
+
+/// Luna's storage in TIDAL's user data, see docs/luna-user-data.md. Luna keeps its settings
+/// and plugins in IndexedDB databases of TIDAL's page. Chromium stores every database of an
+/// origin in one LevelDB, so the store of each origin that holds a Luna database is returned
+/// whole, along with its blob directory. Local Storage is shared with TIDAL's login and is
+/// never returned.
+pub fn luna_user_data_paths() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    for dir in tidal_user_data_dirs() {
+        // Files Neptune and early Luna builds wrote next to TIDAL's own data
+        if let Ok(entries) = std::fs::read_dir(&dir) {
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_ascii_lowercase();
+                if name.starts_with("luna") || name.starts_with("neptune") {
+                    paths.push(entry.path());
+                }
+            }
+        }
+
+        let mut storage_roots = vec![dir.clone()];
+        if let Ok(partitions) = std::fs::read_dir(dir.join("Partitions")) {
+            storage_roots.extend(partitions.flatten().map(|entry| entry.path()));
+        }
+        for root in storage_roots {
+            paths.extend(luna_indexeddb_stores(&root.join("IndexedDB")));
+        }
+    }
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// `<origin>.indexeddb.leveldb` stores below `dir` holding a Luna database, plus their
+/// `<origin>.indexeddb.blob` directories
+fn luna_indexeddb_stores(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut stores = Vec::new();
+    for entry in entries.flatten() {
+        let store = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some(origin) = name.strip_suffix(".indexeddb.leveldb") else {
+            continue;
+        };
+        if !store.is_dir() || !leveldb_mentions_luna(&store) {
+            continue;
+        }
+        let blobs = dir.join(format!("{}.indexeddb.blob", origin));
+        stores.push(store);
+        if blobs.exists() {
+            stores.push(blobs);
+        }
+    }
+    stores
+}
+
+/// Whether a LevelDB directory holds one of Luna's databases. IndexedDB keeps database names
+/// as UTF-16BE in its metadata; UTF-8 is searched too for names written inside values.
+fn leveldb_mentions_luna(store: &Path) -> bool {
+    let utf16: Vec<u8> = LUNA_DATABASE_PREFIX
+        .encode_utf16()
+        .flat_map(u16::to_be_bytes)
+        .collect();
+    let needles = [LUNA_DATABASE_PREFIX.as_bytes().to_vec(), utf16];
+
+    let Ok(entries) = std::fs::read_dir(store) else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !(name.ends_with(".ldb") || name.ends_with(".log")) {
+            return false;
+        }
+        let Ok(bytes) = std::fs::read(entry.path()) else {
+            return false;
+        };
+        needles
+            .iter()
+            .any(|needle| bytes.windows(needle.len()).any(|window| window == needle.as_slice()))
+    })
+}
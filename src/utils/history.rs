@@ -0,0 +1,225 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use crate::installer::manager::{InstallEvent, RunOutcome};
+use crate::utils::config;
+use crate::utils::invoking_user::invoking_user;
+
+const HISTORY_FILE: &str = "history.jsonl";
+const LAST_RUN_FILE: &str = "last-run.log";
+
+/// Older entries are dropped once the history is this long
+const MAX_HISTORY_ENTRIES: usize = 100;
+
+/// One pipeline run on one installation, a line of history.jsonl
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Unix timestamp in seconds
+    pub finished_at: u64,
+    /// `cli` or `gui`
+    pub source: String,
+    /// `install`, `uninstall`, `rollback` or `repair`
+    pub action: String,
+    pub path: String,
+    pub channel: Option<String>,
+    pub version: Option<String>,
+    pub success: bool,
+    pub cancelled: bool,
+    /// Id of the step that failed or was next when the run was cancelled
+    pub failed_step: Option<String>,
+    pub message: Option<String>,
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// `YYYY-MM-DD HH:MM` in UTC for a Unix timestamp
+pub fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let minutes = secs % 86_400 / 60;
+
+    // Days since 1970-01-01 to a civil date (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, minutes / 60, minutes % 60)
+}
+
+/// Writes next to `path` and renames, so a crash never leaves half a file behind.
+/// Under sudo/pkexec the directory is handed back to the invoking user, like the config.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+    // Under sudo every directory created here is handed back too, not just the last one
+    let created = path
+        .parent()
+        .and_then(|dir| dir.ancestors().take_while(|dir| !dir.exists()).last())
+        .map(Path::to_path_buf);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let part = path.with_extension("part");
+    std::fs::write(&part, contents)?;
+    std::fs::rename(&part, path)?;
+
+    if let (Some(user), Some(dir)) = (invoking_user(), created.as_deref().or(path.parent())) {
+        let _ = user.chown_recursive(dir);
+    }
+    Ok(())
+}
+
+/// The newest `limit` entries, oldest first
+pub fn recent(limit: usize) -> Vec<HistoryEntry> {
+    let Some(path) = config::state_dir().map(|dir| dir.join(HISTORY_FILE)) else {
+        return Vec::new();
+    };
+    let entries: Vec<HistoryEntry> = std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    entries[entries.len().saturating_sub(limit)..].to_vec()
+}
+
+fn append(entry: HistoryEntry) -> std::io::Result<()> {
+    let Some(path) = config::state_dir().map(|dir| dir.join(HISTORY_FILE)) else {
+        return Ok(());
+    };
+    let mut entries = recent(MAX_HISTORY_ENTRIES - 1);
+    entries.push(entry);
+
+    let mut contents = String::new();
+    for entry in &entries {
+        contents.push_str(&serde_json::to_string(entry).map_err(std::io::Error::other)?);
+        contents.push('\n');
+    }
+    write_atomic(&path, &contents)
+}
+
+/// The full event log of the most recent install, uninstall or rollback
+pub fn last_run_log() -> Option<String> {
+    std::fs::read_to_string(config::state_dir()?.join(LAST_RUN_FILE)).ok()
+}
+
+struct Target {
+    action: String,
+    path: PathBuf,
+    release: Option<(String, String)>,
+}
+
+#[derive(Default)]
+struct RecorderState {
+    lines: Vec<String>,
+    target: Option<Target>,
+}
+
+/// Keeps the pipeline events of one command or GUI run. Each finished target adds a
+/// history entry and rewrites last-run.log with everything so far; both are best effort.
+pub struct RunRecorder {
+    source: &'static str,
+    started_at: u64,
+    started: Instant,
+    state: Mutex<RecorderState>,
+}
+
+impl RunRecorder {
+    pub fn new(source: &'static str) -> Self {
+        Self {
+            source,
+            started_at: now(),
+            started: Instant::now(),
+            state: Mutex::new(RecorderState::default()),
+        }
+    }
+
+    fn push(&self, line: String) {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        if let Ok(mut state) = self.state.lock() {
+            state.lines.push(format!("[{:>7.1}s] {}", elapsed, line));
+        }
+    }
+
+    /// Begins the run of one installation; `release` is `(channel, version)` for installs
+    pub fn start(&self, action: &str, path: &Path, release: Option<(&str, &str)>) {
+        let mut line = format!("# {} {:?}", action, path);
+        if let Some((channel, version)) = release {
+            line.push_str(&format!(" {} {}", channel, version));
+        }
+        if let Ok(mut state) = self.state.lock() {
+            state.target = Some(Target {
+                action: action.to_string(),
+                path: path.to_path_buf(),
+                release: release.map(|(channel, version)| (channel.to_string(), version.to_string())),
+            });
+        }
+        self.push(line);
+    }
+
+    pub fn event(&self, event: &InstallEvent) {
+        self.push(match event {
+            InstallEvent::StepStarted(step) => {
+                format!("== [{}/{}] {}: {}", step.index + 1, step.total, step.id, step.name)
+            }
+            InstallEvent::SubLog { message, .. } => format!("   {}", message),
+            InstallEvent::StepFinished { step, success: true, message } => format!("-> {} ok: {}", step.id, message),
+            InstallEvent::StepFinished { step, success: false, message } => {
+                format!("-> {} FAILED: {}", step.id, message)
+            }
+        });
+    }
+
+    /// Ends the current installation's run. Runs without `start`, like stopping TIDAL
+    /// before a multi-target run, only go into the log.
+    pub fn finish(&self, outcome: &RunOutcome) {
+        self.push(match &outcome.failed_step {
+            None => "# success".to_string(),
+            Some(failed) if outcome.cancelled => format!("# cancelled before {}", failed.step.id),
+            Some(failed) => format!("# failed at {}: {}", failed.step.id, failed.message),
+        });
+
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if let Some(target) = state.target.take() {
+            let (channel, version) = target.release.unzip();
+            let _ = append(HistoryEntry {
+                finished_at: now(),
+                source: self.source.to_string(),
+                action: target.action,
+                path: target.path.to_string_lossy().into_owned(),
+                channel,
+                version,
+                success: outcome.success(),
+                cancelled: outcome.cancelled,
+                failed_step: outcome.failed_step.as_ref().map(|failed| failed.step.id.clone()),
+                message: outcome.failed_step.as_ref().map(|failed| failed.message.clone()),
+            });
+        }
+
+        let Some(dir) = config::state_dir() else {
+            return;
+        };
+        let mut log = format!(
+            "TidaLuna Installer v{} ({}), started {} UTC\n",
+            crate::utils::updater::current_installer_version(),
+            self.source,
+            format_timestamp(self.started_at)
+        );
+        for line in &state.lines {
+            log.push_str(line);
+            log.push('\n');
+        }
+        let _ = write_atomic(&dir.join(LAST_RUN_FILE), &log);
+    }
+}
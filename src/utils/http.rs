@@ -0,0 +1,18 @@
+use crate::t;
+use crate::utils::config;
+
+/// reqwest client builder with the user agent and the proxy from the config file applied
+pub fn client_builder() -> reqwest::ClientBuilder {
+    let builder = reqwest::Client::builder().user_agent("tidaluna-installer");
+
+    match config::load_or_default().proxy {
+        Some(proxy) => match reqwest::Proxy::all(&proxy) {
+            Ok(proxy) => builder.proxy(proxy),
+            Err(err) => {
+                eprintln!("{}", t!("http-invalid-proxy", proxy = proxy, error = err));
+                builder
+            }
+        },
+        None => builder,
+    }
+}
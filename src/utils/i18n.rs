@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+/// Catalogs compiled into the binary, as locale tag and Fluent source
+const CATALOGS: &[(&str, &str)] = &[
+    ("en", include_str!("../../locales/en.ftl")),
+    ("de", include_str!("../../locales/de.ftl")),
+];
+
+/// Last link of every fallback chain; it has to define every key
+pub const FALLBACK_LOCALE: &str = "en";
+
+type Messages = HashMap<String, String>;
+
+/// Parses the subset of Fluent the catalogs use: `key = value` messages and `-term = value`
+/// terms, indented continuation lines, `#` comments, `{ $var }`, `{ -term }` and
+/// `{ "literal" }` placeables, and `{ $var -> [one] ... *[other] ... }` selects with one
+/// variant per line. Attributes and functions are not supported.
+fn parse_catalog(source: &str) -> Result<Messages, String> {
+    let mut messages = Messages::new();
+    let mut current: Option<String> = None;
+
+    for (number, line) in source.lines().enumerate() {
+        if line.trim().is_empty() {
+            current = None;
+            continue;
+        }
+        if line.starts_with('#') {
+            current = None;
+            continue;
+        }
+
+        if line.starts_with([' ', '\t']) {
+            let Some(key) = &current else {
+                return Err(format!("line {}: indented text outside of a message", number + 1));
+            };
+            if let Some(value) = messages.get_mut(key) {
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!("line {}: expected `key = value`", number + 1));
+        };
+        let key = key.trim();
+        let id = key.strip_prefix('-').unwrap_or(key);
+        let valid = id.starts_with(|c: char| c.is_ascii_alphabetic())
+            && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(format!("line {}: invalid message id '{}'", number + 1, key));
+        }
+        if messages.insert(key.to_string(), value.trim().to_string()).is_some() {
+            return Err(format!("line {}: '{}' is defined twice", number + 1, key));
+        }
+        current = Some(key.to_string());
+    }
+
+    for (key, pattern) in &messages {
+        check_pattern(pattern).map_err(|err| format!("'{}': {}", key, err))?;
+    }
+    Ok(messages)
+}
+
+/// Byte offset of the `}` that closes the placeable `text` starts with
+fn closing_brace(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_literal = false;
+    for (index, c) in text.char_indices() {
+        match c {
+            '"' if depth > 0 => in_literal = !in_literal,
+            '{' if !in_literal => depth += 1,
+            '}' if !in_literal => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+struct Variant<'a> {
+    key: &'a str,
+    default: bool,
+    pattern: &'a str,
+}
+
+/// `$var -> [key] pattern ... *[key] pattern`, the inside of a select placeable
+fn parse_select(inner: &str) -> Option<(&str, Vec<Variant<'_>>)> {
+    let (selector, variants) = inner.split_once("->")?;
+    let name = selector.trim().strip_prefix('$')?;
+    let variants = variants
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let default = line.starts_with('*');
+            let (key, pattern) = line.trim_start_matches('*').strip_prefix('[')?.split_once(']')?;
+            Some(Variant {
+                key: key.trim(),
+                default,
+                pattern: pattern.trim(),
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some((name, variants))
+}
+
+/// Braces are balanced and every select has variants, exactly one of them the default
+fn check_pattern(pattern: &str) -> Result<(), String> {
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        let end = closing_brace(&rest[start..]).ok_or("unclosed `{`")?;
+        let inner = &rest[start + 1..start + end];
+        if inner.contains("->") {
+            let (_, variants) = parse_select(inner).ok_or("malformed select")?;
+            if variants.iter().filter(|variant| variant.default).count() != 1 {
+                return Err("a select needs exactly one `*[default]` variant".to_string());
+            }
+            for variant in &variants {
+                check_pattern(variant.pattern)?;
+            }
+        }
+        rest = &rest[start + end + 1..];
+    }
+    Ok(())
+}
+
+/// CLDR plural category of a number in `locale`. Only the languages that have a catalog are
+/// known; a new catalog whose language has more forms than one/other needs its rule here.
+fn plural_category(locale: &str, value: &str) -> Option<&'static str> {
+    value.parse::<f64>().ok()?;
+    Some(match locale.split('-').next() {
+        Some("en" | "de") if value == "1" => "one",
+        _ => "other",
+    })
+}
+
+fn catalogs() -> &'static HashMap<&'static str, Messages> {
+    static PARSED: OnceLock<HashMap<&'static str, Messages>> = OnceLock::new();
+    PARSED.get_or_init(|| {
+        CATALOGS
+            .iter()
+            .map(|(locale, source)| {
+                // A broken catalog is a build mistake the tests catch; at runtime it falls back
+                (*locale, parse_catalog(source).unwrap_or_default())
+            })
+            .collect()
+    })
+}
+
+/// `de_AT.UTF-8` -> `de-at`
+fn normalize_tag(tag: &str) -> String {
+    let tag = tag.split(['.', '@']).next().unwrap_or_default();
+    tag.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+/// Catalogs to try for a requested locale, most specific first: `de-at`, `de`, then English
+pub fn fallback_chain(requested: Option<&str>) -> Vec<&'static str> {
+    let mut chain = Vec::new();
+
+    if let Some(tag) = requested.map(normalize_tag).filter(|tag| !tag.is_empty()) {
+        let language = tag.split('-').next().unwrap_or_default().to_string();
+        for candidate in [tag, language] {
+            if let Some((locale, _)) = CATALOGS.iter().find(|(locale, _)| *locale == candidate)
+                && !chain.contains(locale)
+            {
+                chain.push(*locale);
+            }
+        }
+    }
+
+    if !chain.contains(&FALLBACK_LOCALE) {
+        chain.push(FALLBACK_LOCALE);
+    }
+    chain
+}
+
+/// The user's language: LC_ALL, LC_MESSAGES and LANG as on POSIX, then what the OS reports
+pub fn detect_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty() && value != "C" && value != "POSIX" && !value.starts_with("C."))
+        .or_else(sys_locale::get_locale)
+}
+
+fn active() -> &'static RwLock<Vec<&'static str>> {
+    static ACTIVE: OnceLock<RwLock<Vec<&'static str>>> = OnceLock::new();
+    ACTIVE.get_or_init(|| RwLock::new(fallback_chain(detect_locale().as_deref())))
+}
+
+/// Switches the language; `None` follows the environment again
+pub fn set_locale(requested: Option<&str>) {
+    let chain = match requested {
+        Some(tag) => fallback_chain(Some(tag)),
+        None => fallback_chain(detect_locale().as_deref()),
+    };
+    if let Ok(mut active) = active().write() {
+        *active = chain;
+    }
+}
+
+/// Tags of the embedded catalogs
+pub fn available_locales() -> Vec<&'static str> {
+    CATALOGS.iter().map(|(locale, _)| *locale).collect()
+}
+
+/// The language's own name for itself, e.g. "Deutsch"
+pub fn language_name(locale: &str) -> String {
+    catalogs()
+        .get(locale)
+        .and_then(|messages| messages.get("language-name"))
+        .cloned()
+        .unwrap_or_else(|| locale.to_string())
+}
+
+/// The message and the locale of the catalog it came from, which decides plural forms
+fn lookup(chain: &[&'static str], key: &str) -> Option<(&'static str, &'static String)> {
+    chain
+        .iter()
+        .find_map(|locale| Some((*locale, catalogs().get(locale)?.get(key)?)))
+}
+
+fn format_pattern(pattern: &str, args: &[(&str, String)], locale: &str, chain: &[&'static str]) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(end) = closing_brace(&rest[start..]) else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let placeable = &rest[start + 1..start + end];
+        let inner = placeable.trim();
+
+        if let Some((name, variants)) = inner.contains("->").then(|| parse_select(inner)).flatten() {
+            // An exact key like `[0]` first, then the plural category, then the default
+            let value = args.iter().find(|(arg, _)| *arg == name).map(|(_, value)| value.as_str());
+            let chosen = value
+                .and_then(|value| {
+                    let category = plural_category(locale, value);
+                    variants
+                        .iter()
+                        .find(|variant| variant.key == value)
+                        .or_else(|| variants.iter().find(|variant| Some(variant.key) == category))
+                })
+                .or_else(|| variants.iter().find(|variant| variant.default));
+            if let Some(variant) = chosen {
+                out.push_str(&format_pattern(variant.pattern, args, locale, chain));
+            }
+        } else if let Some(name) = inner.strip_prefix('$') {
+            match args.iter().find(|(arg, _)| *arg == name) {
+                Some((_, value)) => out.push_str(value),
+                None => out.push_str(&format!("{{${}}}", name)),
+            }
+        } else if inner.len() >= 2 && inner.starts_with('"') && inner.ends_with('"') {
+            out.push_str(&inner[1..inner.len() - 1]);
+        } else if inner.starts_with('-') {
+            match lookup(chain, inner) {
+                Some((term_locale, term)) => out.push_str(&format_pattern(term, args, term_locale, chain)),
+                None => out.push_str(inner),
+            }
+        } else {
+            out.push_str(&rest[start..start + end + 1]);
+        }
+        rest = &rest[start + end + 1..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Looks `key` up along the active fallback chain; unknown keys come back as the key itself
+pub fn tr(key: &str, args: &[(&str, String)]) -> String {
+    let chain = active().read().map(|chain| chain.clone()).unwrap_or_default();
+    match lookup(&chain, key) {
+        Some((locale, pattern)) => format_pattern(pattern, args, locale, &chain),
+        None => key.to_string(),
+    }
+}
+
+/// `t!("key")` or `t!("key", name = value, ...)`: the message in the user's language
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::utils::i18n::tr($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::utils::i18n::tr($key, &[$((stringify!($name), ($value).to_string())),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn variables(pattern: &str) -> BTreeSet<String> {
+        pattern
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split('}').next())
+            .filter_map(|inner| inner.trim().strip_prefix('$'))
+            .map(|name| name.chars().take_while(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-').collect())
+            .collect()
+    }
+
+    #[test]
+    fn every_catalog_parses() {
+        for (locale, source) in CATALOGS {
+            if let Err(err) = parse_catalog(source) {
+                panic!("{}.ftl: {}", locale, err);
+            }
+        }
+    }
+
+    #[test]
+    fn every_key_exists_in_every_catalog() {
+        let fallback = parse_catalog(CATALOGS[0].1).unwrap();
+        assert_eq!(CATALOGS[0].0, FALLBACK_LOCALE);
+
+        for (locale, source) in &CATALOGS[1..] {
+            let messages = parse_catalog(source).unwrap();
+            let missing: BTreeSet<_> = fallback.keys().filter(|key| !messages.contains_key(*key)).collect();
+            let extra: BTreeSet<_> = messages.keys().filter(|key| !fallback.contains_key(*key)).collect();
+            assert!(missing.is_empty(), "{}.ftl is missing {:?}", locale, missing);
+            assert!(extra.is_empty(), "{}.ftl has keys English lacks: {:?}", locale, extra);
+
+            for (key, pattern) in &messages {
+                assert_eq!(
+                    variables(pattern),
+                    variables(&fallback[key]),
+                    "{}.ftl: '{}' uses different variables than English",
+                    locale,
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn selects_pick_the_plural_form() {
+        let pattern = "{ $count ->\n[0] No files\n[one] One file\n*[other] { $count } files\n}";
+        let format = |count: &str| format_pattern(pattern, &[("count", count.to_string())], "en", &["en"]);
+        assert!(check_pattern(pattern).is_ok());
+        assert_eq!(format("0"), "No files");
+        assert_eq!(format("1"), "One file");
+        assert_eq!(format("2"), "2 files");
+        assert_eq!(format("1.5"), "1.5 files");
+    }
+
+    #[test]
+    fn fallback_chain_goes_from_region_to_language_to_english() {
+        assert_eq!(fallback_chain(Some("de_AT.UTF-8")), vec!["de", "en"]);
+        assert_eq!(fallback_chain(Some("de")), vec!["de", "en"]);
+        assert_eq!(fallback_chain(Some("fr-FR")), vec!["en"]);
+        assert_eq!(fallback_chain(None), vec!["en"]);
+    }
+}
@@ -0,0 +1,259 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The desktop user who started the installer through sudo or pkexec
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub struct InvokingUser {
+    pub name: String,
+    pub uid: u32,
+    pub gid: u32,
+    pub home: PathBuf,
+}
+
+#[cfg(target_os = "linux")]
+struct PasswdEntry {
+    name: String,
+    uid: u32,
+    gid: u32,
+    home: PathBuf,
+}
+
+#[cfg(target_os = "linux")]
+fn parse_passwd_line(line: &str) -> Option<PasswdEntry> {
+    let fields: Vec<&str> = line.trim().split(':').collect();
+    if fields.len() < 7 {
+        return None;
+    }
+
+    Some(PasswdEntry {
+        name: fields[0].to_string(),
+        uid: fields[2].parse().ok()?,
+        gid: fields[3].parse().ok()?,
+        home: PathBuf::from(fields[5]),
+    })
+}
+
+/// Looks up a user by name or uid, using getent first so NSS sources like LDAP are honoured
+#[cfg(target_os = "linux")]
+fn lookup_passwd(key: &str, matches: impl Fn(&PasswdEntry) -> bool) -> Option<PasswdEntry> {
+    if let Ok(output) = Command::new("getent").args(["passwd", key]).output()
+        && output.status.success()
+    {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if let Some(entry) = stdout.lines().filter_map(parse_passwd_line).find(&matches) {
+            return Some(entry);
+        }
+    }
+
+    std::fs::read_to_string("/etc/passwd")
+        .ok()?
+        .lines()
+        .filter_map(parse_passwd_line)
+        .find(matches)
+}
+
+#[cfg(target_os = "linux")]
+fn effective_uid() -> Option<u32> {
+    let output = Command::new("id").arg("-u").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+/// Returns the user behind `sudo`/`pkexec` when the installer runs as root on Linux
+#[cfg(target_os = "linux")]
+pub fn invoking_user() -> Option<InvokingUser> {
+    if effective_uid()? != 0 {
+        return None;
+    }
+
+    let entry = if let Ok(uid) = std::env::var("PKEXEC_UID") {
+        let uid: u32 = uid.trim().parse().ok()?;
+        lookup_passwd(&uid.to_string(), |entry| entry.uid == uid)?
+    } else if let Ok(name) = std::env::var("SUDO_USER") {
+        let name = name.trim().to_string();
+        lookup_passwd(&name, |entry| entry.name == name)?
+    } else {
+        return None;
+    };
+
+    if entry.uid == 0 {
+        return None;
+    }
+
+    Some(InvokingUser {
+        name: entry.name,
+        uid: entry.uid,
+        gid: entry.gid,
+        home: entry.home,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn invoking_user() -> Option<InvokingUser> {
+    None
+}
+
+impl InvokingUser {
+    pub fn runtime_dir(&self) -> PathBuf {
+        PathBuf::from(format!("/run/user/{}", self.uid))
+    }
+
+    /// Session variables a GUI app needs to reach the user's display server and session bus
+    pub fn session_environment(&self) -> Vec<(String, String)> {
+        let runtime_dir = self.runtime_dir();
+        let mut vars = vec![
+            ("HOME".to_string(), self.home.to_string_lossy().to_string()),
+            ("USER".to_string(), self.name.clone()),
+            ("LOGNAME".to_string(), self.name.clone()),
+        ];
+
+        if runtime_dir.is_dir() {
+            vars.push((
+                "XDG_RUNTIME_DIR".to_string(),
+                runtime_dir.to_string_lossy().to_string(),
+            ));
+        }
+
+        let display = std::env::var("DISPLAY").ok().or_else(|| {
+            Path::new("/tmp/.X11-unix/X0")
+                .exists()
+                .then(|| ":0".to_string())
+        });
+        if let Some(display) = display {
+            vars.push(("DISPLAY".to_string(), display));
+        }
+
+        let xauthority = std::env::var("XAUTHORITY").ok().or_else(|| {
+            let candidate = self.home.join(".Xauthority");
+            candidate
+                .exists()
+                .then(|| candidate.to_string_lossy().to_string())
+        });
+        if let Some(xauthority) = xauthority {
+            vars.push(("XAUTHORITY".to_string(), xauthority));
+        }
+
+        let wayland_display = std::env::var("WAYLAND_DISPLAY").ok().or_else(|| {
+            std::fs::read_dir(&runtime_dir)
+                .ok()?
+                .filter_map(Result::ok)
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .find(|name| name.starts_with("wayland-") && !name.ends_with(".lock"))
+        });
+        if let Some(wayland_display) = wayland_display {
+            vars.push(("WAYLAND_DISPLAY".to_string(), wayland_display));
+        }
+
+        let bus = runtime_dir.join("bus");
+        if bus.exists() {
+            vars.push((
+                "DBUS_SESSION_BUS_ADDRESS".to_string(),
+                format!("unix:path={}", bus.to_string_lossy()),
+            ));
+        } else if let Ok(address) = std::env::var("DBUS_SESSION_BUS_ADDRESS") {
+            vars.push(("DBUS_SESSION_BUS_ADDRESS".to_string(), address));
+        }
+
+        vars
+    }
+
+    /// Every group the user is in, as `initgroups` would set them
+    #[cfg(unix)]
+    fn groups(&self) -> Vec<libc::gid_t> {
+        let Ok(name) = std::ffi::CString::new(self.name.as_str()) else {
+            return vec![self.gid];
+        };
+        let mut groups: Vec<libc::gid_t> = vec![0; 64];
+        while groups.len() <= 65_536 {
+            let mut count = groups.len() as libc::c_int;
+            // SAFETY: `groups` has room for `count` entries and `name` is NUL-terminated
+            let found = unsafe {
+                libc::getgrouplist(name.as_ptr(), self.gid as _, groups.as_mut_ptr() as _, &mut count)
+            };
+            if found >= 0 {
+                groups.truncate(count.max(0) as usize);
+                return groups;
+            }
+            // Linux reports the size it needs, other systems leave it to us
+            let needed = (count.max(0) as usize).max(groups.len() * 2);
+            groups.resize(needed, 0);
+        }
+        vec![self.gid]
+    }
+
+    /// Makes `cmd` run as this user with their session environment instead of as root.
+    /// `Command::uid` would drop every supplementary group (audio, video, render, ...), so
+    /// the credentials are switched by hand, like `initgroups` followed by `setuid`.
+    #[cfg(unix)]
+    pub fn apply_to_command(&self, cmd: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        for key in ["SUDO_USER", "SUDO_UID", "SUDO_GID", "SUDO_COMMAND", "PKEXEC_UID"] {
+            cmd.env_remove(key);
+        }
+        cmd.envs(self.session_environment());
+        cmd.current_dir(&self.home);
+
+        let (uid, gid, groups) = (self.uid, self.gid, self.groups());
+        // SAFETY: the closure only makes the setgroups, setgid and setuid syscalls, which are
+        // async-signal-safe, and allocates nothing
+        unsafe {
+            cmd.pre_exec(move || {
+                if libc::setgroups(groups.len() as _, groups.as_ptr()) != 0
+                    || libc::setgid(gid) != 0
+                    || libc::setuid(uid) != 0
+                {
+                    return Err(std::io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+    }
+
+    #[cfg(not(unix))]
+    pub fn apply_to_command(&self, _cmd: &mut Command) {}
+
+    /// Whether `path` lives somewhere this user owns, i.e. a place they could have written to themselves
+    #[cfg(unix)]
+    pub fn owns(&self, path: &Path) -> bool {
+        use std::os::unix::fs::MetadataExt;
+
+        std::fs::metadata(path)
+            .map(|metadata| metadata.uid() == self.uid)
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(unix))]
+    pub fn owns(&self, _path: &Path) -> bool {
+        false
+    }
+
+    /// Recursively hands `path` back to this user; symlinks are changed themselves, never followed
+    #[cfg(unix)]
+    pub fn chown_recursive(&self, path: &Path) -> std::io::Result<usize> {
+        let mut changed = 0;
+        let mut pending = vec![path.to_path_buf()];
+
+        while let Some(current) = pending.pop() {
+            let metadata = std::fs::symlink_metadata(&current)?;
+            std::os::unix::fs::lchown(&current, Some(self.uid), Some(self.gid))?;
+            changed += 1;
+
+            if metadata.is_dir() {
+                for entry in std::fs::read_dir(&current)? {
+                    pending.push(entry?.path());
+                }
+            }
+        }
+
+        Ok(changed)
+    }
+
+    #[cfg(not(unix))]
+    pub fn chown_recursive(&self, _path: &Path) -> std::io::Result<usize> {
+        Ok(0)
+    }
+}
@@ -1,3 +1,13 @@
 pub mod release_loader;
 pub mod fs_helpers;
 pub mod updater;
+pub mod invoking_user;
+pub mod config;
+pub mod http;
+pub mod receipt;
+pub mod systemd;
+pub mod asar;
+pub mod redact;
+pub mod i18n;
+pub mod history;
+pub mod diagnostics;
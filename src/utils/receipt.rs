@@ -0,0 +1,157 @@
+use crate::t;
+use crate::utils::config;
+use crate::utils::history::write_atomic;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Written next to `app/` in the TIDAL resources directory
+pub const RECEIPT_FILE: &str = "luna-receipt.json";
+
+/// Holds the Luna install replaced by the last reinstall: `app/` plus its receipt
+pub const PREVIOUS_DIR: &str = "luna-previous";
+
+/// Every patched installation with its receipt, in the state dir: client updates that replace
+/// the resources directory (a new Flatpak commit, an AppImage update) delete the receipt with it
+const PATCHED_FILE: &str = "patched.json";
+
+/// What the installer put into a TIDAL installation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstallReceipt {
+    pub channel: String,
+    pub version: String,
+    pub download_url: String,
+    /// Unix timestamp in seconds
+    pub installed_at: u64,
+    pub installer_version: String,
+}
+
+impl InstallReceipt {
+    pub fn new(channel: &str, version: &str, download_url: &str) -> Self {
+        Self {
+            channel: channel.to_string(),
+            version: version.to_string(),
+            download_url: download_url.to_string(),
+            installed_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            installer_version: crate::utils::updater::current_installer_version(),
+        }
+    }
+}
+
+/// A Luna install kept in the previous slot
+#[derive(Debug, Clone)]
+pub struct PreviousInstall {
+    pub dir: PathBuf,
+    /// Missing for installs made before receipts existed
+    pub receipt: Option<InstallReceipt>,
+}
+
+impl PreviousInstall {
+    pub fn version_label(&self) -> String {
+        match &self.receipt {
+            Some(receipt) => format!("{} ({})", receipt.version, receipt.channel),
+            None => t!("unknown-version"),
+        }
+    }
+}
+
+/// A resources directory the installer put Luna into, as recorded in the state dir
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchedInstall {
+    pub path: PathBuf,
+    /// Missing for installs made before receipts existed
+    pub receipt: Option<InstallReceipt>,
+}
+
+/// Installations that had Luna the last time the installer touched them
+pub fn patched_installs() -> Vec<PatchedInstall> {
+    config::state_dir()
+        .and_then(|dir| std::fs::read_to_string(dir.join(PATCHED_FILE)).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn patched_install(resources: &Path) -> Option<PatchedInstall> {
+    patched_installs().into_iter().find(|install| install.path == resources)
+}
+
+fn save_patched_installs(installs: &[PatchedInstall]) -> std::io::Result<()> {
+    let Some(dir) = config::state_dir() else {
+        return Ok(());
+    };
+    let contents = serde_json::to_string_pretty(installs).map_err(std::io::Error::other)?;
+    write_atomic(&dir.join(PATCHED_FILE), &contents)
+}
+
+/// Remembers that `resources` has Luna, replacing what was recorded for it
+pub fn record_patched(resources: &Path, receipt: Option<InstallReceipt>) -> std::io::Result<()> {
+    let install = PatchedInstall {
+        path: resources.to_path_buf(),
+        receipt,
+    };
+    let mut installs = patched_installs();
+    if installs.contains(&install) {
+        return Ok(());
+    }
+    installs.retain(|recorded| recorded.path != resources);
+    installs.push(install);
+    save_patched_installs(&installs)
+}
+
+/// Drops `resources` after an uninstall, so `watch` does not bring Luna back
+pub fn forget_patched(resources: &Path) -> std::io::Result<()> {
+    let mut installs = patched_installs();
+    let before = installs.len();
+    installs.retain(|install| install.path != resources);
+    if installs.len() == before {
+        return Ok(());
+    }
+    save_patched_installs(&installs)
+}
+
+/// Reads `luna-receipt.json` from a resources directory or previous slot
+pub fn read_receipt(dir: &Path) -> Option<InstallReceipt> {
+    let contents = std::fs::read_to_string(dir.join(RECEIPT_FILE)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+pub fn write_receipt(dir: &Path, receipt: &InstallReceipt) -> std::io::Result<()> {
+    let contents = serde_json::to_string_pretty(receipt).map_err(std::io::Error::other)?;
+    std::fs::write(dir.join(RECEIPT_FILE), contents)
+}
+
+pub fn previous_install(resources: &Path) -> Option<PreviousInstall> {
+    let dir = resources.join(PREVIOUS_DIR);
+    if !dir.join("app").is_dir() {
+        return None;
+    }
+
+    Some(PreviousInstall {
+        receipt: read_receipt(&dir),
+        dir,
+    })
+}
+
+/// Moves `app/` and its receipt into the previous slot, replacing what was kept there
+pub fn keep_as_previous(resources: &Path) -> std::io::Result<()> {
+    let previous_dir = resources.join(PREVIOUS_DIR);
+    if previous_dir.exists() {
+        let _ = std::fs::remove_dir_all(&previous_dir);
+    }
+
+    let moved = std::fs::create_dir_all(&previous_dir)
+        .and_then(|()| std::fs::rename(resources.join("app"), previous_dir.join("app")));
+    if let Err(err) = moved {
+        let _ = std::fs::remove_dir_all(&previous_dir);
+        return Err(err);
+    }
+
+    let receipt = resources.join(RECEIPT_FILE);
+    if receipt.exists() {
+        let _ = std::fs::rename(&receipt, previous_dir.join(RECEIPT_FILE));
+    }
+    Ok(())
+}
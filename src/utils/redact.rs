@@ -0,0 +1,57 @@
+use crate::utils::invoking_user::invoking_user;
+
+/// Home directories that can show up in logs: ours and, under sudo or pkexec, the desktop user's
+fn home_dirs() -> Vec<String> {
+    let mut homes: Vec<String> = dirs::home_dir()
+        .into_iter()
+        .chain(invoking_user().map(|user| user.home))
+        .map(|home| home.to_string_lossy().trim_end_matches(['/', '\\']).to_string())
+        // "/" as a home (some service users) would redact every path
+        .filter(|home| home.len() > 1)
+        .collect();
+
+    // Paths are often logged with {:?}, which doubles Windows backslashes
+    let escaped: Vec<String> = homes
+        .iter()
+        .filter(|home| home.contains('\\'))
+        .flat_map(|home| [home.replace('\\', "\\\\"), home.replace('\\', "/")])
+        .collect();
+    homes.extend(escaped);
+
+    // Longest first, so /home/user/sub-home style nesting is replaced as a whole
+    homes.sort_by_key(|home| std::cmp::Reverse(home.len()));
+    homes.dedup();
+    homes
+}
+
+/// Replaces home directories with `~` so logs can be shared without the account name in every path
+pub fn redact_home_paths(text: &str) -> String {
+    home_dirs()
+        .iter()
+        .fold(text.to_string(), |text, home| text.replace(home.as_str(), "~"))
+}
+
+/// Account names that show up outside home paths too, e.g. in process or owner listings
+fn user_names() -> Vec<String> {
+    let mut names: Vec<String> = ["USER", "USERNAME", "LOGNAME", "SUDO_USER"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .chain(invoking_user().map(|user| user.name))
+        .map(|name| name.trim().to_string())
+        // Very short names would match inside ordinary words
+        .filter(|name| name.len() >= 3 && name != "root")
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// `redact_home_paths`, then every remaining account name as a whole word becomes `<user>`
+pub fn redact_personal_info(text: &str) -> String {
+    user_names()
+        .iter()
+        .filter_map(|name| regex::Regex::new(&format!(r"\b{}\b", regex::escape(name))).ok())
+        .fold(redact_home_paths(text), |text, pattern| {
+            pattern.replace_all(&text, "<user>").into_owned()
+        })
+}
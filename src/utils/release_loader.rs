@@ -1,4 +1,5 @@
 use crate::types::types::{Release, ReleaseVersion, ReleaseSource, ReleaseSourceType};
+use crate::utils::http;
 use reqwest::Client;
 use semver::Version;
 use uuid::Uuid;
@@ -19,7 +20,7 @@ impl ReleaseLoader {
             sources: Vec::new(),
             release_sources_url: release_sources_url.to_string(),
             releases_loaded: false,
-            client: Client::new(),
+            client: http::client_builder().build().unwrap_or_default(),
         }
     }
 
@@ -56,12 +57,11 @@ impl ReleaseLoader {
             return "stable".to_string();
         }
 
-        if let Ok(regex) = regex::Regex::new(r"^([a-zA-Z]+)[-_]\d") {
-            if let Some(caps) = regex.captures(clean) {
-                if let Some(channel) = caps.get(1) {
-                    return channel.as_str().to_string();
-                }
-            }
+        if let Ok(regex) = regex::Regex::new(r"^([a-zA-Z]+)[-_]\d")
+            && let Some(caps) = regex.captures(clean)
+            && let Some(channel) = caps.get(1)
+        {
+            return channel.as_str().to_string();
         }
 
         clean.to_string()
@@ -109,9 +109,19 @@ impl ReleaseLoader {
                         });
 
                     let channel_name = Self::extract_channel_name(tag);
+                    let text = |key: &str| {
+                        release
+                            .get(key)
+                            .and_then(|v| v.as_str())
+                            .filter(|s| !s.trim().is_empty())
+                            .map(|s| s.to_string())
+                    };
                     let version = ReleaseVersion {
                         version: tag.to_string(),
                         download: download_url,
+                        notes: text("body"),
+                        published_at: text("published_at"),
+                        prerelease: release.get("prerelease").and_then(|v| v.as_bool()).unwrap_or(false),
                     };
 
                     grouped.entry(channel_name.clone())
@@ -0,0 +1,176 @@
+use crate::t;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub const SERVICE_UNIT: &str = "tidaluna-installer.service";
+pub const TIMER_UNIT: &str = "tidaluna-installer.timer";
+
+/// First line of every generated unit, so foreign files with the same name are never touched
+const MARKER: &str = "# Generated by tidaluna-installer";
+
+/// What `service install` puts into the units
+pub struct ServiceOptions {
+    pub exe: PathBuf,
+    /// systemd time span between runs, e.g. `6h`
+    pub interval: String,
+    pub update: bool,
+    /// Extra `watch` arguments selecting the installations
+    pub target_args: Vec<String>,
+}
+
+/// What happened to a unit file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitFileState {
+    Created,
+    Updated,
+    Unchanged,
+    Removed,
+    Missing,
+}
+
+impl UnitFileState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnitFileState::Created => "created",
+            UnitFileState::Updated => "updated",
+            UnitFileState::Unchanged => "unchanged",
+            UnitFileState::Removed => "removed",
+            UnitFileState::Missing => "missing",
+        }
+    }
+}
+
+/// `~/.config/systemd/user`
+pub fn unit_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("systemd").join("user"))
+}
+
+/// The binary the timer should run; an AppImage must be referenced by its file, not its mount
+pub fn installer_exe() -> Result<PathBuf, String> {
+    if let Some(appimage) = std::env::var_os("APPIMAGE") {
+        return Ok(PathBuf::from(appimage));
+    }
+    let exe = std::env::current_exe().map_err(|e| t!("service-exe-unknown", error = e))?;
+    exe.canonicalize()
+        .map_err(|e| t!("service-exe-unresolved", path = format!("{:?}", exe), error = e))
+}
+
+/// Quotes one ExecStart argument: systemd expands `%` specifiers and `$` variables otherwise
+fn exec_arg(arg: &str) -> String {
+    let escaped = arg
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('%', "%%")
+        .replace('$', "$$");
+    format!("\"{}\"", escaped)
+}
+
+pub fn service_unit(options: &ServiceOptions) -> String {
+    let mut command = vec![
+        options.exe.to_string_lossy().into_owned(),
+        "--non-interactive".to_string(),
+        "watch".to_string(),
+        "--once".to_string(),
+    ];
+    if options.update {
+        command.push("--update".to_string());
+    }
+    command.extend(options.target_args.iter().cloned());
+    let exec_start: Vec<String> = command.iter().map(|arg| exec_arg(arg)).collect();
+
+    format!(
+        "{MARKER}; removed by `tidaluna-installer service uninstall`\n\
+         [Unit]\n\
+         Description=Repair TidaLuna after TIDAL updates\n\
+         \n\
+         [Service]\n\
+         Type=oneshot\n\
+         ExecStart={}\n",
+        exec_start.join(" ")
+    )
+}
+
+pub fn timer_unit(options: &ServiceOptions) -> String {
+    format!(
+        "{MARKER}; removed by `tidaluna-installer service uninstall`\n\
+         [Unit]\n\
+         Description=Periodically repair TidaLuna\n\
+         \n\
+         [Timer]\n\
+         OnStartupSec=2min\n\
+         OnUnitActiveSec={}\n\
+         Unit={SERVICE_UNIT}\n\
+         \n\
+         [Install]\n\
+         WantedBy=timers.target\n",
+        options.interval
+    )
+}
+
+/// Checks the interval with `systemd-analyze timespan` before it goes into the timer.
+/// Without systemd-analyze only characters that could break the unit file are rejected.
+pub fn validate_interval(interval: &str) -> Result<(), String> {
+    let invalid = || t!("service-bad-interval", interval = interval);
+    let safe = !interval.trim().is_empty()
+        && interval
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '.');
+    if !safe {
+        return Err(invalid());
+    }
+
+    match Command::new("systemd-analyze").args(["timespan", interval]).output() {
+        Ok(output) if !output.status.success() => Err(invalid()),
+        _ => Ok(()),
+    }
+}
+
+fn is_generated(path: &Path) -> bool {
+    std::fs::read_to_string(path).is_ok_and(|contents| contents.starts_with(MARKER))
+}
+
+/// Writes a unit unless it already has these contents; refuses to replace units it did not create
+pub fn write_unit(path: &Path, contents: &str) -> Result<UnitFileState, String> {
+    let state = match std::fs::read_to_string(path) {
+        Ok(existing) if existing == contents => return Ok(UnitFileState::Unchanged),
+        Ok(_) if !is_generated(path) => {
+            return Err(t!("service-foreign-unit", path = format!("{:?}", path)));
+        }
+        Ok(_) => UnitFileState::Updated,
+        Err(_) => UnitFileState::Created,
+    };
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| t!("service-create-dir-failed", path = format!("{:?}", dir), error = e))?;
+    }
+    std::fs::write(path, contents).map_err(|e| t!("service-write-failed", path = format!("{:?}", path), error = e))?;
+    Ok(state)
+}
+
+pub fn remove_unit(path: &Path) -> Result<UnitFileState, String> {
+    if !path.exists() {
+        return Ok(UnitFileState::Missing);
+    }
+    if !is_generated(path) {
+        return Err(t!("service-foreign-unit-kept", path = format!("{:?}", path)));
+    }
+    std::fs::remove_file(path).map_err(|e| t!("service-remove-failed", path = format!("{:?}", path), error = e))?;
+    Ok(UnitFileState::Removed)
+}
+
+/// Runs `systemctl --user`, returning trimmed stdout or the error systemd printed
+pub fn systemctl(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("systemctl")
+        .arg("--user")
+        .args(args)
+        .output()
+        .map_err(|e| t!("service-systemctl-failed", error = e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if output.status.success() {
+        Ok(stdout)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        Err(if stderr.is_empty() { stdout } else { stderr })
+    }
+}
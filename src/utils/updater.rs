@@ -1,11 +1,25 @@
+use crate::t;
+use crate::utils::http;
 use semver::Version;
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use std::ffi::OsStr;
+use std::fmt;
 use std::fs;
-use std::io::Write;
-use std::path::PathBuf;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
 
-const INSTALLER_RELEASES_API: &str = "https://api.github.com/repos/jxnxsdev/TidaLuna-Installer/releases/latest";
+const INSTALLER_RELEASES_API: &str = "https://api.github.com/repos/jxnxsdev/TidaLuna-Installer/releases?per_page=50";
+
+/// One release by tag, for `self-update --to` versions older than the list above reaches
+const INSTALLER_RELEASE_TAG_API: &str = "https://api.github.com/repos/jxnxsdev/TidaLuna-Installer/releases/tags";
+
+/// `sha256sum` output for every release asset, published by the release workflow
+const CHECKSUM_ASSET: &str = "SHA256SUMS";
+
+const SMOKE_TEST_TIMEOUT: Duration = Duration::from_secs(15);
 
 pub fn current_installer_version() -> String {
     option_env!("TIDALUNA_INSTALLER_VERSION")
@@ -13,15 +27,40 @@ pub fn current_installer_version() -> String {
         .to_string()
 }
 
-#[derive(Debug, Clone)]
+/// Which installer releases `self-update` considers
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateChannel {
+    Stable,
+    /// Stable releases plus prereleases
+    Prerelease,
+}
+
+impl UpdateChannel {
+    /// Prerelease builds keep following prereleases; everyone else stays on stable
+    pub fn for_version(current_version: &str) -> Self {
+        match parse_version(current_version) {
+            Some(version) if !version.pre.is_empty() => UpdateChannel::Prerelease,
+            _ => UpdateChannel::Stable,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UpdateInfo {
     pub version: String,
+    pub prerelease: bool,
     pub download_url: String,
+    /// File name the checksum list refers to
+    pub asset_name: String,
+    /// Missing for releases made before checksums were published
+    pub checksum_url: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct UpdateApplyResult {
     pub message: String,
+    /// False when the update has to go through a package manager instead
+    pub applied: bool,
     pub should_exit: bool,
 }
 
@@ -32,8 +71,12 @@ struct GitHubReleaseAsset {
 }
 
 #[derive(Debug, Deserialize)]
-struct GitHubLatestRelease {
+struct GitHubRelease {
     tag_name: String,
+    #[serde(default)]
+    prerelease: bool,
+    #[serde(default)]
+    draft: bool,
     assets: Vec<GitHubReleaseAsset>,
 }
 
@@ -48,68 +91,221 @@ fn is_linux_appimage_path(path: &std::path::Path) -> bool {
         .unwrap_or(false)
 }
 
-fn pick_update_asset(assets: &[GitHubReleaseAsset]) -> Option<String> {
-    match std::env::consts::OS {
-        "windows" => assets
+/// How the running installer was installed, which decides what an update replaces
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallMethod {
+    /// The .AppImage file itself, not the mounted binary inside it
+    AppImage(PathBuf),
+    Binary(PathBuf),
+    /// Owned by a dpkg package; replacing the file would leave dpkg's database out of sync
+    Dpkg { package: String },
+}
+
+/// Name of the dpkg package that owns `path`, if any
+fn dpkg_owner(path: &Path) -> Option<String> {
+    let output = Command::new("dpkg-query")
+        .arg("-S")
+        .arg(path)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // `package[:arch]: /path`, possibly several packages separated by ", "
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (packages, _) = stdout.lines().next()?.split_once(": ")?;
+    let package = packages.split(", ").next()?.split(':').next()?.trim();
+    (!package.is_empty()).then(|| package.to_string())
+}
+
+pub fn detect_install_method() -> Result<InstallMethod, String> {
+    if let Some(appimage) = std::env::var_os("APPIMAGE") {
+        return Ok(InstallMethod::AppImage(PathBuf::from(appimage)));
+    }
+
+    let exe = std::env::current_exe()
+        .map_err(|error| t!("updater-exe-unresolved", error = error))?;
+    let exe = exe.canonicalize().unwrap_or(exe);
+    if is_linux_appimage_path(&exe) {
+        return Ok(InstallMethod::AppImage(exe));
+    }
+    if std::env::consts::OS == "linux"
+        && let Some(package) = dpkg_owner(&exe)
+    {
+        return Ok(InstallMethod::Dpkg { package });
+    }
+    Ok(InstallMethod::Binary(exe))
+}
+
+/// Asset names spell the architecture like Rust does: `x86_64`, `aarch64`
+fn pick_update_asset<'a>(assets: &'a [GitHubReleaseAsset], method: &InstallMethod) -> Option<&'a GitHubReleaseAsset> {
+    let arch = std::env::consts::ARCH;
+    let find = |prefix: String, matches: &dyn Fn(&str) -> bool| {
+        assets
             .iter()
-            .find(|asset| {
-                asset.name.starts_with("installer-windows-x86_64-v") && asset.name.ends_with(".exe")
-            })
-            .map(|asset| asset.browser_download_url.clone()),
-        "macos" => {
-            let prefix = if std::env::consts::ARCH == "aarch64" {
-                "installer-macOS-aarch64-v"
-            } else {
-                "installer-macOS-x86_64-v"
-            };
-
-            assets
-                .iter()
-                .find(|asset| asset.name.starts_with(prefix) && !asset.name.ends_with(".app.zip"))
-                .map(|asset| asset.browser_download_url.clone())
-        }
+            .find(|asset| asset.name.starts_with(&prefix) && matches(&asset.name))
+    };
+
+    match std::env::consts::OS {
+        "windows" => find(format!("installer-windows-{}-v", arch), &|name| name.ends_with(".exe")),
+        "macos" => find(format!("installer-macOS-{}-v", arch), &|name| !name.ends_with(".app.zip")),
         "linux" => {
-            let exe = std::env::current_exe().ok();
-            let appimage_mode = exe
-                .as_ref()
-                .map(|path| is_linux_appimage_path(path))
-                .unwrap_or(false);
-
-            if appimage_mode {
-                assets
-                    .iter()
-                    .find(|asset| {
-                        asset.name.starts_with("installer-linux-x86_64-v")
-                            && asset.name.ends_with(".AppImage")
-                    })
-                    .map(|asset| asset.browser_download_url.clone())
-            } else {
-                assets
-                    .iter()
-                    .find(|asset| {
-                        asset.name.starts_with("installer-linux-x86_64-v")
-                            && !asset.name.ends_with(".deb")
-                            && !asset.name.ends_with(".AppImage")
-                    })
-                    .map(|asset| asset.browser_download_url.clone())
+            let prefix = format!("installer-linux-{}-v", arch);
+            match method {
+                InstallMethod::AppImage(_) => find(prefix, &|name| name.ends_with(".AppImage")),
+                InstallMethod::Dpkg { .. } => find(prefix, &|name| name.ends_with(".deb")),
+                InstallMethod::Binary(_) => find(prefix, &|name| {
+                    !name.ends_with(".deb") && !name.ends_with(".AppImage")
+                }),
             }
         }
         _ => None,
     }
 }
 
+/// What to run instead of a self-update when dpkg owns the installer
+fn dpkg_guidance(package: &str, update: &UpdateInfo) -> String {
+    format!(
+        "{}\n  curl -LO {}\n  sudo apt install ./{}",
+        t!("updater-dpkg-guidance", package = package),
+        update.download_url,
+        update.asset_name,
+    )
+}
+
 fn make_temp_update_path(target: &std::path::Path) -> PathBuf {
     let file_name = target
         .file_name()
         .and_then(OsStr::to_str)
         .unwrap_or("tidaluna-installer");
-    target.with_file_name(format!("{}.update", file_name))
+    // Windows only runs files ending in .exe
+    if cfg!(target_os = "windows") {
+        target.with_file_name(format!("{}.update.exe", file_name.trim_end_matches(".exe")))
+    } else {
+        target.with_file_name(format!("{}.update", file_name))
+    }
+}
+
+/// The previous binary, kept next to the new one after an update
+fn make_backup_path(target: &Path) -> PathBuf {
+    let file_name = target
+        .file_name()
+        .and_then(OsStr::to_str)
+        .unwrap_or("tidaluna-installer");
+    target.with_file_name(format!("{}.old", file_name))
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Finds `asset_name` in `sha256sum` output (`<hex>  <name>`, binary mode `<hex> *<name>`)
+fn find_checksum(sums: &str, asset_name: &str) -> Option<String> {
+    sums.lines().find_map(|line| {
+        let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+        let name = name.trim_start().trim_start_matches('*');
+        (name == asset_name).then(|| hash.to_ascii_lowercase())
+    })
+}
+
+async fn verify_checksum(client: &reqwest::Client, update: &UpdateInfo, bytes: &[u8]) -> Result<(), String> {
+    let Some(checksum_url) = &update.checksum_url else {
+        return Err(t!("updater-no-checksums", version = update.version, file = CHECKSUM_ASSET));
+    };
+
+    let response = client
+        .get(checksum_url)
+        .send()
+        .await
+        .map_err(|error| t!("updater-checksums-download-failed", file = CHECKSUM_ASSET, error = error))?;
+    if !response.status().is_success() {
+        return Err(t!("updater-checksums-status", file = CHECKSUM_ASSET, status = response.status()));
+    }
+    let sums = response
+        .text()
+        .await
+        .map_err(|error| t!("updater-checksums-read-failed", file = CHECKSUM_ASSET, error = error))?;
+
+    let expected = find_checksum(&sums, &update.asset_name)
+        .ok_or_else(|| t!("updater-checksum-missing", file = CHECKSUM_ASSET, asset = update.asset_name))?;
+    let actual = sha256_hex(bytes);
+    if actual != expected {
+        return Err(t!(
+            "updater-checksum-mismatch",
+            asset = update.asset_name,
+            expected = expected,
+            actual = actual
+        ));
+    }
+    Ok(())
+}
+
+/// Runs `<binary> <arg>` and returns what it printed, or an error unless it exits cleanly
+fn run_new_binary(binary: &Path, arg: &str) -> Result<String, String> {
+    let mut child = Command::new(binary)
+        .arg(arg)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|error| t!("updater-binary-start-failed", error = error))?;
+
+    let deadline = Instant::now() + SMOKE_TEST_TIMEOUT;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(100)),
+            Ok(None) => {
+                let _ = child.kill();
+                return Err(t!("updater-binary-timeout", seconds = SMOKE_TEST_TIMEOUT.as_secs()));
+            }
+            Err(error) => return Err(t!("updater-binary-wait-failed", error = error)),
+        }
+    };
+    if !status.success() {
+        return Err(t!("updater-smoke-test-failed", status = status));
+    }
+
+    let mut output = String::new();
+    if let Some(mut stdout) = child.stdout.take() {
+        let _ = stdout.read_to_string(&mut output);
+    }
+    Ok(output)
+}
+
+/// Runs `<binary> --version` and expects a clean exit that reports `expected_version`.
+/// Catches truncated, wrong-architecture or otherwise unrunnable binaries, and assets
+/// that belong to a different release than the one being installed.
+fn smoke_test(binary: &Path, expected_version: &str) -> Result<(), String> {
+    let output = match run_new_binary(binary, "--version") {
+        Ok(output) => output,
+        // Releases before `--version` existed reject it; only a downgrade can install one
+        Err(_) if parse_version(expected_version) < parse_version(&current_installer_version()) => {
+            return run_new_binary(binary, "--help").map(|_| ());
+        }
+        Err(error) => return Err(error),
+    };
+
+    let reported = output.split_whitespace().last().and_then(parse_version);
+    if reported.is_none() || reported != parse_version(expected_version) {
+        return Err(t!(
+            "updater-wrong-version",
+            reported = output.trim(),
+            expected = expected_version
+        ));
+    }
+    Ok(())
 }
 
 #[cfg(not(target_os = "windows"))]
-fn replace_binary_unix(target: &std::path::Path, bytes: &[u8]) -> Result<(), String> {
+fn replace_binary_unix(target: &std::path::Path, bytes: &[u8], version: &str) -> Result<(), String> {
     let tmp_path = make_temp_update_path(target);
-    fs::write(&tmp_path, bytes).map_err(|error| format!("failed to write update file: {}", error))?;
+    fs::write(&tmp_path, bytes).map_err(|error| t!("updater-write-failed", error = error))?;
 
     #[cfg(unix)]
     {
@@ -119,11 +315,32 @@ fn replace_binary_unix(target: &std::path::Path, bytes: &[u8]) -> Result<(), Str
             .unwrap_or_else(|_| fs::Permissions::from_mode(0o755));
         permissions.set_mode(0o755);
         fs::set_permissions(&tmp_path, permissions)
-            .map_err(|error| format!("failed to set executable permissions: {}", error))?;
+            .map_err(|error| t!("updater-permissions-failed", error = error))?;
+    }
+
+    let backup_path = make_backup_path(target);
+    let _ = fs::remove_file(&backup_path);
+    fs::rename(target, &backup_path)
+        .map_err(|error| t!("updater-backup-failed", error = error))?;
+    if let Err(error) = fs::rename(&tmp_path, target) {
+        let _ = fs::rename(&backup_path, target);
+        let _ = fs::remove_file(&tmp_path);
+        return Err(t!("updater-replace-failed", error = error));
     }
 
-    fs::rename(&tmp_path, target)
-        .map_err(|error| format!("failed to replace executable: {}", error))?;
+    if let Err(error) = smoke_test(target, version) {
+        // Put the working binary back; keep the broken one out of the way
+        let _ = fs::remove_file(target);
+        fs::rename(&backup_path, target).map_err(|restore_error| {
+            t!(
+                "updater-restore-failed",
+                error = error,
+                restore_error = restore_error,
+                path = format!("{:?}", backup_path)
+            )
+        })?;
+        return Err(t!("updater-restored", error = error));
+    }
     Ok(())
 }
 
@@ -131,17 +348,28 @@ fn replace_binary_unix(target: &std::path::Path, bytes: &[u8]) -> Result<(), Str
 fn replace_binary_windows(
     target: &std::path::Path,
     bytes: &[u8],
+    version: &str,
     relaunch_after: bool,
 ) -> Result<UpdateApplyResult, String> {
     let update_path = make_temp_update_path(target);
-    fs::write(&update_path, bytes).map_err(|error| format!("failed to write update file: {}", error))?;
+    fs::write(&update_path, bytes).map_err(|error| t!("updater-write-failed", error = error))?;
+
+    // The running executable cannot be replaced from here, so test the new one before handing over
+    if let Err(error) = smoke_test(&update_path, version) {
+        let _ = fs::remove_file(&update_path);
+        return Err(error);
+    }
+
+    use std::io::Write;
 
     let script_path = std::env::temp_dir().join("tidaluna-self-update.cmd");
     let mut script = fs::File::create(&script_path)
-        .map_err(|error| format!("failed to create updater script: {}", error))?;
+        .map_err(|error| t!("updater-script-create-failed", error = error))?;
 
     let target_str = target.to_string_lossy();
     let update_str = update_path.to_string_lossy();
+    let backup_path = make_backup_path(target);
+    let backup_str = backup_path.to_string_lossy();
 
     let relaunch_command = if relaunch_after {
         format!("start \"\" \"{}\"\n", target_str)
@@ -149,116 +377,232 @@ fn replace_binary_windows(
         String::new()
     };
 
+    // Waits for the installer to exit, keeps it as .old, swaps in the update and
+    // restores .old if the update cannot be moved into place or fails to start
     let script_contents = format!(
-        "@echo off\nsetlocal\n:retry\nmove /Y \"{update}\" \"{target}\" >nul 2>nul\nif errorlevel 1 (\n  timeout /t 1 /nobreak >nul\n  goto retry\n)\n{relaunch}",
+        "@echo off\nsetlocal\n:retry\nmove /Y \"{target}\" \"{backup}\" >nul 2>nul\nif errorlevel 1 (\n  timeout /t 1 /nobreak >nul\n  goto retry\n)\nmove /Y \"{update}\" \"{target}\" >nul 2>nul\nif errorlevel 1 goto restore\n\"{target}\" --help >nul 2>nul\nif errorlevel 1 goto restore\n{relaunch}exit /b 0\n:restore\nmove /Y \"{backup}\" \"{target}\" >nul 2>nul\n{relaunch}exit /b 1\n",
         update = update_str,
         target = target_str,
+        backup = backup_str,
         relaunch = relaunch_command,
     );
 
     script
         .write_all(script_contents.as_bytes())
-        .map_err(|error| format!("failed to write updater script: {}", error))?;
+        .map_err(|error| t!("updater-script-write-failed", error = error))?;
 
     std::process::Command::new("cmd")
         .args(["/C", script_path.to_string_lossy().as_ref()])
         .spawn()
-        .map_err(|error| format!("failed to start updater script: {}", error))?;
+        .map_err(|error| t!("updater-script-start-failed", error = error))?;
 
     Ok(UpdateApplyResult {
         message: if relaunch_after {
-            "Update downloaded. The installer will now close and relaunch updated.".to_string()
+            t!("updater-relaunching")
         } else {
-            "Update downloaded. Exit and reopen the installer to use the new version.".to_string()
+            t!("updater-reopen")
         },
+        applied: true,
         should_exit: true,
     })
 }
 
-pub async fn check_for_update(current_version: &str) -> Result<Option<UpdateInfo>, String> {
-    let current = parse_version(current_version)
-        .ok_or_else(|| format!("invalid current installer version: {}", current_version))?;
+impl fmt::Display for UpdateInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.prerelease {
+            write!(f, "{}", t!("updater-prerelease", version = self.version))
+        } else {
+            write!(f, "v{}", self.version)
+        }
+    }
+}
 
-    let client = reqwest::Client::builder()
-        .user_agent("tidaluna-installer")
+fn release_list_client() -> Result<reqwest::Client, String> {
+    http::client_builder()
         .timeout(std::time::Duration::from_secs(20))
         .build()
-        .map_err(|error| format!("failed to create updater http client: {}", error))?;
+        .map_err(|error| t!("updater-client-failed", error = error))
+}
+
+/// `release` as an update for this platform, if it has a download for it
+fn update_info(release: &GitHubRelease, method: &InstallMethod) -> Option<(Version, UpdateInfo)> {
+    let version = parse_version(&release.tag_name)?;
+    let asset = pick_update_asset(&release.assets, method)?;
+    let checksum_url = release
+        .assets
+        .iter()
+        .find(|asset| asset.name == CHECKSUM_ASSET)
+        .map(|asset| asset.browser_download_url.clone());
+
+    Some((
+        version.clone(),
+        UpdateInfo {
+            version: version.to_string(),
+            prerelease: release.prerelease || !version.pre.is_empty(),
+            download_url: asset.browser_download_url.clone(),
+            asset_name: asset.name.clone(),
+            checksum_url,
+        },
+    ))
+}
+
+/// Installer releases that have a download for this platform, newest first
+pub async fn list_releases() -> Result<Vec<UpdateInfo>, String> {
+    let method = detect_install_method()?;
+    let client = release_list_client()?;
 
     let response = client
         .get(INSTALLER_RELEASES_API)
         .send()
         .await
-        .map_err(|error| format!("failed to check latest installer release: {}", error))?;
+        .map_err(|error| t!("updater-list-failed", error = error))?;
 
     if !response.status().is_success() {
-        return Err(format!("update check request returned {}", response.status()));
+        return Err(t!("updater-list-status", status = response.status()));
     }
 
-    let release: GitHubLatestRelease = response
+    let releases: Vec<GitHubRelease> = response
         .json()
         .await
-        .map_err(|error| format!("failed to parse latest release response: {}", error))?;
+        .map_err(|error| t!("updater-list-parse-failed", error = error))?;
+
+    let mut updates: Vec<(Version, UpdateInfo)> = releases
+        .iter()
+        .filter(|release| !release.draft)
+        .filter_map(|release| update_info(release, &method))
+        .collect();
+
+    // Otherwise every check would report "up to date" on a platform nothing is built for
+    if updates.is_empty() && releases.iter().any(|release| !release.draft) {
+        return Err(t!(
+            "updater-no-build",
+            os = std::env::consts::OS,
+            arch = std::env::consts::ARCH
+        ));
+    }
 
-    let latest = match parse_version(&release.tag_name) {
-        Some(version) => version,
-        None => return Ok(None),
+    updates.sort_by(|a, b| b.0.cmp(&a.0));
+    Ok(updates.into_iter().map(|(_, update)| update).collect())
+}
+
+/// Looks up one installer version by its `v<version>` tag; `None` when there is no such
+/// release or it has no download for this platform
+pub async fn fetch_release(version: &str) -> Result<Option<UpdateInfo>, String> {
+    let Some(wanted) = parse_version(version) else {
+        return Ok(None);
     };
+    let method = detect_install_method()?;
+    let client = release_list_client()?;
+
+    let response = client
+        .get(format!("{}/v{}", INSTALLER_RELEASE_TAG_API, wanted))
+        .send()
+        .await
+        .map_err(|error| t!("updater-lookup-failed", version = wanted, error = error))?;
 
-    if latest <= current {
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
         return Ok(None);
     }
+    if !response.status().is_success() {
+        return Err(t!("updater-release-status", status = response.status()));
+    }
 
-    let Some(download_url) = pick_update_asset(&release.assets) else {
-        return Ok(None);
-    };
+    let release: GitHubRelease = response
+        .json()
+        .await
+        .map_err(|error| t!("updater-release-parse-failed", error = error))?;
+
+    Ok((!release.draft)
+        .then(|| update_info(&release, &method))
+        .flatten()
+        .map(|(_, update)| update))
+}
 
-    Ok(Some(UpdateInfo {
-        version: latest.to_string(),
-        download_url,
-    }))
+/// The newest release in `channel` that is newer than `current_version`
+pub fn newest_update<'a>(
+    releases: &'a [UpdateInfo],
+    current_version: &str,
+    channel: UpdateChannel,
+) -> Option<&'a UpdateInfo> {
+    let current = parse_version(current_version)?;
+    releases
+        .iter()
+        .filter(|release| channel == UpdateChannel::Prerelease || !release.prerelease)
+        .filter_map(|release| parse_version(&release.version).map(|version| (version, release)))
+        .filter(|(version, _)| *version > current)
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .map(|(_, release)| release)
 }
 
-pub async fn apply_update(download_url: &str, relaunch_after: bool) -> Result<UpdateApplyResult, String> {
-    let current_exe = std::env::current_exe()
-        .map_err(|error| format!("failed to resolve current executable path: {}", error))?;
+/// An exact installer version, for pinning or downgrading
+pub fn find_release<'a>(releases: &'a [UpdateInfo], version: &str) -> Option<&'a UpdateInfo> {
+    let wanted = parse_version(version)?;
+    releases
+        .iter()
+        .find(|release| parse_version(&release.version).as_ref() == Some(&wanted))
+}
+
+pub async fn check_for_update(current_version: &str, channel: UpdateChannel) -> Result<Option<UpdateInfo>, String> {
+    if parse_version(current_version).is_none() {
+        return Err(t!("updater-invalid-version", version = current_version));
+    }
 
-    let client = reqwest::Client::builder()
-        .user_agent("tidaluna-installer")
+    let releases = list_releases().await?;
+    Ok(newest_update(&releases, current_version, channel).cloned())
+}
+
+/// Downloads `update`, checks it against the release's SHA256SUMS and swaps it in,
+/// keeping the current binary as `<name>.old`
+pub async fn apply_update(update: &UpdateInfo, relaunch_after: bool) -> Result<UpdateApplyResult, String> {
+    let target = match detect_install_method()? {
+        InstallMethod::Dpkg { package } => {
+            return Ok(UpdateApplyResult {
+                message: dpkg_guidance(&package, update),
+                applied: false,
+                should_exit: false,
+            });
+        }
+        InstallMethod::AppImage(path) | InstallMethod::Binary(path) => path,
+    };
+
+    let client = http::client_builder()
         .timeout(std::time::Duration::from_secs(90))
         .build()
-        .map_err(|error| format!("failed to create updater http client: {}", error))?;
+        .map_err(|error| t!("updater-client-failed", error = error))?;
 
     let response = client
-        .get(download_url)
+        .get(&update.download_url)
         .send()
         .await
-        .map_err(|error| format!("failed to download installer update: {}", error))?;
+        .map_err(|error| t!("updater-download-failed", error = error))?;
 
     if !response.status().is_success() {
-        return Err(format!("installer update download returned {}", response.status()));
+        return Err(t!("updater-download-status", status = response.status()));
     }
 
     let bytes = response
         .bytes()
         .await
-        .map_err(|error| format!("failed reading installer update bytes: {}", error))?;
+        .map_err(|error| t!("updater-download-read-failed", error = error))?;
 
     if bytes.is_empty() {
-        return Err("installer update download is empty".to_string());
+        return Err(t!("updater-download-empty"));
     }
 
+    verify_checksum(&client, update, &bytes).await?;
+
     #[cfg(target_os = "windows")]
     {
-        replace_binary_windows(&current_exe, &bytes, relaunch_after)
+        replace_binary_windows(&target, &bytes, &update.version, relaunch_after)
     }
 
     #[cfg(not(target_os = "windows"))]
     {
         let _ = relaunch_after;
-        replace_binary_unix(&current_exe, &bytes)?;
+        replace_binary_unix(&target, &bytes, &update.version)?;
         Ok(UpdateApplyResult {
-            message: "Installer updated successfully. Please restart the installer.".to_string(),
+            message: t!("updater-updated", path = format!("{:?}", make_backup_path(&target))),
+            applied: true,
             should_exit: false,
         })
     }
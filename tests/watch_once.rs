@@ -0,0 +1,112 @@
+//! `watch --once`, as run by the systemd timer, after a client update replaced the resources
+//! directory of a patched installation (a new Flatpak commit, an AppImage update)
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+struct Sandbox {
+    root: tempfile::TempDir,
+}
+
+impl Sandbox {
+    fn new() -> Self {
+        let sandbox = Self {
+            root: tempfile::tempdir().unwrap(),
+        };
+        for dir in ["home", "config", "data", "cache", "tmp", "bin"] {
+            std::fs::create_dir_all(sandbox.path(dir)).unwrap();
+        }
+        // Never launch TIDAL, and fail the installer update check at once instead of going online
+        let config_dir = sandbox.path("config").join("tidaluna-installer");
+        std::fs::create_dir_all(&config_dir).unwrap();
+        std::fs::write(
+            config_dir.join("config.toml"),
+            "launch = \"never\"\nproxy = \"http://127.0.0.1:9\"\n",
+        )
+        .unwrap();
+        sandbox
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.root.path().join(name)
+    }
+
+    /// Runs the installer with every user directory inside the sandbox. `PATH` is empty, so
+    /// `kill_tidal` finds no `pgrep` and cannot stop TIDAL clients running on this machine.
+    fn run(&self, args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_tidaluna-installer"))
+            .args(["--output", "json", "--non-interactive"])
+            .args(args)
+            .env("HOME", self.path("home"))
+            .env("XDG_CONFIG_HOME", self.path("config"))
+            .env("XDG_DATA_HOME", self.path("data"))
+            .env("XDG_CACHE_HOME", self.path("cache"))
+            .env("TMPDIR", self.path("tmp"))
+            .env("PATH", self.path("bin"))
+            .env_remove("SUDO_USER")
+            .env_remove("PKEXEC_UID")
+            .output()
+            .unwrap()
+    }
+}
+
+fn luna_archive(path: &Path) {
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    let options = zip::write::SimpleFileOptions::default();
+    zip.start_file("package.json", options).unwrap();
+    zip.write_all(br#"{"name": "luna", "version": "1.2.3"}"#).unwrap();
+    zip.start_file("index.js", options).unwrap();
+    zip.write_all(b"console.log('[Luna] ready');").unwrap();
+    zip.finish().unwrap();
+}
+
+/// TIDAL's resources directory as a fresh client install or update leaves it
+fn fresh_client(resources: &Path, asar: &[u8]) {
+    if resources.exists() {
+        std::fs::remove_dir_all(resources).unwrap();
+    }
+    std::fs::create_dir_all(resources).unwrap();
+    std::fs::write(resources.join("app.asar"), asar).unwrap();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn replaced_resources_dir_is_repaired_by_one_watch_once() {
+    let sandbox = Sandbox::new();
+    let archive = sandbox.path("luna.zip");
+    luna_archive(&archive);
+    let archive_url = reqwest::Url::from_file_path(&archive).unwrap().to_string();
+
+    // Luna as an earlier install left it
+    let resources = sandbox.path("tidal-hifi").join("resources");
+    fresh_client(&resources, b"tidal 1");
+    std::fs::rename(resources.join("app.asar"), resources.join("original.asar")).unwrap();
+    std::fs::create_dir(resources.join("app")).unwrap();
+    std::fs::write(
+        resources.join("luna-receipt.json"),
+        format!(
+            r#"{{"channel": "local", "version": "1.2.3", "download_url": "{}", "installed_at": 0, "installer_version": "0.0.0"}}"#,
+            archive_url
+        ),
+    )
+    .unwrap();
+
+    let resources_arg = resources.to_str().unwrap();
+    let first = sandbox.run(&["watch", "--once", "--path", resources_arg]);
+    assert!(first.status.success(), "{}", String::from_utf8_lossy(&first.stdout));
+
+    // The update swaps in a new directory: no app/, no receipt, TIDAL's new app.asar
+    fresh_client(&resources, b"tidal 2");
+
+    let repair = sandbox.run(&["watch", "--once", "--path", resources_arg]);
+    let stdout = String::from_utf8_lossy(&repair.stdout);
+    assert!(repair.status.success(), "{}", stdout);
+    assert!(stdout.contains("\"injection_lost\""), "{}", stdout);
+
+    assert!(resources.join("app").join("index.js").is_file(), "{}", stdout);
+    assert!(!resources.join("app.asar").exists(), "{}", stdout);
+    assert_eq!(std::fs::read(resources.join("original.asar")).unwrap(), b"tidal 2");
+    let receipt = std::fs::read_to_string(resources.join("luna-receipt.json")).unwrap();
+    assert!(receipt.contains("1.2.3"), "{}", receipt);
+}